@@ -0,0 +1,239 @@
+//  LIB.rs
+//    by Lut99
+//
+//  Created:
+//    16 Oct 2026, 22:06:48
+//  Last edited:
+//    17 Oct 2026, 02:06:18
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Provides the `#[derive(Serializable)]`-macro for the
+//!   [`serializable`](https://github.com/Lut99/serializable-rs) crate.
+//!
+//!   Typically, you don't depend on this crate directly, but instead enable
+//!   the `derive`-feature of `serializable`, which re-exports the macro.
+//
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::spanned::Spanned as _;
+use syn::{parse_macro_input, DeriveInput, Expr, Ident, LitBool, LitStr, Path, Token};
+
+
+/***** HELPERS *****/
+/// Defines the options that can be given to a backend.
+#[derive(Clone, Default)]
+struct Options {
+    /// Whether to use the pretty backend by default.
+    pretty: Option<LitBool>,
+    /// The preferred file extension.
+    extension: Option<LitStr>,
+    /// The options to pretty-print with.
+    pretty_options: Option<Expr>,
+}
+impl Options {
+    /// Attempts to parse the given meta as one of the options.
+    ///
+    /// # Arguments
+    /// - `meta`: The [`syn::meta::ParseNestedMeta`] to parse.
+    ///
+    /// # Returns
+    /// True if the meta was an option (and was parsed as such), or false if it wasn't an option.
+    ///
+    /// # Errors
+    /// This function errors if the meta was an option, but its value was invalid.
+    fn parse(&mut self, meta: &syn::meta::ParseNestedMeta) -> syn::Result<bool> {
+        if meta.path.is_ident("pretty") {
+            // Either a flag or explicitly given
+            self.pretty = Some(if meta.input.peek(Token![=]) { meta.value()?.parse()? } else { LitBool::new(true, meta.path.span()) });
+            Ok(true)
+        } else if meta.path.is_ident("extension") {
+            let ext: LitStr = meta.value()?.parse()?;
+            if ext.value().starts_with('.') {
+                return Err(syn::Error::new(ext.span(), "Extension must be given without leading dot"));
+            }
+            self.extension = Some(ext);
+            Ok(true)
+        } else if meta.path.is_ident("pretty_options") {
+            self.pretty_options = Some(meta.value()?.parse()?);
+            Ok(true)
+        } else {
+            Ok(false)
+        }
+    }
+
+    /// Returns these options overridden by the given ones.
+    ///
+    /// # Arguments
+    /// - `other`: The [`Options`] to override these with.
+    ///
+    /// # Returns
+    /// A new [`Options`] with the values of `other` where given, and those of `self` otherwise.
+    fn overridden_by(&self, other: Options) -> Self {
+        Self {
+            pretty: other.pretty.or_else(|| self.pretty.clone()),
+            extension: other.extension.or_else(|| self.extension.clone()),
+            pretty_options: other.pretty_options.or_else(|| self.pretty_options.clone()),
+        }
+    }
+}
+
+
+
+/// Defines everything parsed from the `#[serializable(...)]`-attributes.
+struct Attributes {
+    /// The path to the `serializable` crate.
+    krate: Path,
+    /// The backends (and their options) to implement `Serializable` for.
+    backends: Vec<(Ident, Options)>,
+}
+impl Attributes {
+    /// Parses the `#[serializable(...)]`-attributes of the given input.
+    ///
+    /// # Arguments
+    /// - `input`: The [`DeriveInput`] to parse the attributes of.
+    ///
+    /// # Returns
+    /// The parsed [`Attributes`], with the toplevel options already applied to every backend.
+    ///
+    /// # Errors
+    /// This function errors if any of the attributes was invalid, or if no backends were given.
+    fn parse(input: &DeriveInput) -> syn::Result<Self> {
+        let mut krate: Path = syn::parse_quote!(::serializable);
+        let mut defaults: Options = Options::default();
+        let mut backends: Vec<(Ident, Options)> = Vec::new();
+        for attr in input.attrs.iter().filter(|attr| attr.path().is_ident("serializable")) {
+            attr.parse_nested_meta(|meta| {
+                // Parse the toplevel options first
+                if defaults.parse(&meta)? {
+                    return Ok(());
+                }
+                if meta.path.is_ident("crate") {
+                    krate = meta.value()?.parse::<LitStr>()?.parse()?;
+                    return Ok(());
+                }
+
+                // Otherwise, it's a backend (with optional options)
+                let Some(backend) = meta.path.get_ident().cloned() else {
+                    return Err(meta.error("Expected a backend name (e.g., `json`), `pretty`, `extension`, `pretty_options` or `crate`"));
+                };
+                if backends.iter().any(|(b, _)| *b == backend) {
+                    return Err(meta.error(format!("Duplicate backend `{backend}`")));
+                }
+                let mut options: Options = Options::default();
+                if meta.input.peek(syn::token::Paren) {
+                    meta.parse_nested_meta(|meta| {
+                        if options.parse(&meta)? { Ok(()) } else { Err(meta.error("Expected `pretty`, `extension` or `pretty_options`")) }
+                    })?;
+                }
+                backends.push((backend, options));
+                Ok(())
+            })?;
+        }
+        if backends.is_empty() {
+            return Err(syn::Error::new(
+                input.ident.span(),
+                "Expected at least one backend to derive `Serializable` for (e.g., `#[serializable(json)]`)",
+            ));
+        }
+
+        // Apply the defaults
+        let backends: Vec<(Ident, Options)> = backends.into_iter().map(|(backend, options)| (backend, defaults.overridden_by(options))).collect();
+        Ok(Self { krate, backends })
+    }
+}
+
+
+
+
+
+/***** LIBRARY *****/
+/// Derives `Serializable` for one or more of the backends in the `serializable` crate.
+///
+/// The backends are given as a list of module names in `#[serializable(...)]` (e.g., `json` for
+/// `serializable::json::Serializer`), for each of which an
+/// `impl Serializable<serializable::<backend>::Serializer<Self>> for Self {}` is generated. Note
+/// that the backend's feature has to be enabled in `serializable`.
+///
+/// Additionally, the following options may be given, either at the toplevel (for all backends) or
+/// per backend (e.g., `json(pretty)`), where the latter take precedence:
+/// - `pretty` or `pretty = <bool>`: Sets `Serializable::PRETTY`, i.e., whether the
+///   `*_preferred()`-functions use the pretty backend.
+/// - `extension = "<ext>"`: Sets `Serializable::EXTENSION`, i.e., the preferred file extension
+///   (without leading dot).
+/// - `pretty_options = <expr>`: Sets `Serializable::PRETTY_OPTIONS`, i.e., the `PrettyOptions` with
+///   which the `*_pretty()`-functions pretty-print. Must be a constant expression.
+///
+/// Finally, `crate = "<path>"` can be given at the toplevel to change the path to the
+/// `serializable` crate (defaults to `::serializable`).
+///
+/// # Examples
+/// ```rust
+/// use serde::{Deserialize, Serialize};
+/// use serializable::json::Json;
+/// use serializable::yaml::Yaml;
+/// use serializable::{PrettyOptions, Serializable, SerializableAs as _};
+///
+/// #[derive(Debug, Deserialize, Eq, PartialEq, Serialize, Serializable)]
+/// #[serializable(
+///     json(extension = "json", pretty_options = PrettyOptions { indent: Some("    "), ..PrettyOptions::DEFAULT }),
+///     yaml(pretty),
+///     extension = "yml"
+/// )]
+/// struct HelloWorld {
+///     hello: String,
+///     world: String,
+/// }
+///
+/// let hello_world = HelloWorld { hello: "Hello".into(), world: "World".into() };
+/// assert_eq!(hello_world.to_string_as::<Json>().unwrap(), "{\"hello\":\"Hello\",\"world\":\"World\"}");
+/// assert_eq!(hello_world.to_string_as::<Yaml>().unwrap(), "hello: Hello\nworld: World\n");
+/// assert_eq!(hello_world.to_string_pretty_as::<Json>().unwrap(), "{\n    \"hello\": \"Hello\",\n    \"world\": \"World\"\n}");
+///
+/// assert!(!<HelloWorld as Serializable<serializable::json::Serializer<_>>>::PRETTY);
+/// assert!(<HelloWorld as Serializable<serializable::yaml::Serializer<_>>>::PRETTY);
+/// assert_eq!(<HelloWorld as Serializable<serializable::json::Serializer<_>>>::EXTENSION, Some("json"));
+/// assert_eq!(<HelloWorld as Serializable<serializable::yaml::Serializer<_>>>::EXTENSION, Some("yml"));
+/// ```
+#[proc_macro_derive(Serializable, attributes(serializable))]
+pub fn derive_serializable(input: TokenStream) -> TokenStream {
+    let input: DeriveInput = parse_macro_input!(input as DeriveInput);
+    match derive(&input) {
+        Ok(res) => res.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
+/// Generates the `impl Serializable`s for the given input.
+///
+/// # Arguments
+/// - `input`: The [`DeriveInput`] to generate the impls for.
+///
+/// # Returns
+/// A [`TokenStream2`] with one impl for every backend in the input's attributes.
+///
+/// # Errors
+/// This function errors if the input's attributes were invalid.
+fn derive(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let Attributes { krate, backends } = Attributes::parse(input)?;
+
+    // Generate the impls
+    let name: &Ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let impls = backends.into_iter().map(|(backend, Options { pretty, extension, pretty_options })| {
+        let pretty: Option<TokenStream2> = pretty.map(|pretty| quote! { const PRETTY: bool = #pretty; });
+        let extension: Option<TokenStream2> = extension.map(|ext| quote! { const EXTENSION: ::std::option::Option<&'static str> = ::std::option::Option::Some(#ext); });
+        let pretty_options: Option<TokenStream2> = pretty_options.map(|opts| quote! { const PRETTY_OPTIONS: #krate::PrettyOptions = #opts; });
+        quote! {
+            impl #impl_generics #krate::Serializable<#krate::#backend::Serializer<#name #ty_generics>> for #name #ty_generics #where_clause {
+                #pretty
+                #extension
+                #pretty_options
+            }
+        }
+    });
+    Ok(quote! { #(#impls)* })
+}
@@ -0,0 +1,219 @@
+//  BINCODE.rs
+//    by Lut99
+//
+//  Created:
+//    16 Oct 2026, 21:12:15
+//  Last edited:
+//    17 Oct 2026, 11:52:40
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Implements [`serializer::Serializer`] and cohorts for [`bincode`].
+//
+
+use std::error;
+use std::fmt::{Display, Formatter, Result as FResult};
+use std::io::{Read, Write};
+use std::marker::PhantomData;
+
+use serde::{Deserialize, Serialize};
+
+use crate::serializer;
+
+
+/***** ERRORS *****/
+/// Defines errors that occur when using the bincode [`Serializer`].
+#[derive(Debug)]
+pub enum Error {
+    /// Failed to write to the given writer.
+    #[cfg(feature = "async-tokio")]
+    Write { err: std::io::Error },
+    /// Failed to read from the given reader.
+    #[cfg(feature = "async-tokio")]
+    Read { err: std::io::Error },
+    /// Failed to flush the given writer.
+    #[cfg(feature = "async-tokio")]
+    Flush { err: std::io::Error },
+    /// bincode is a binary format, and cannot be represented as a string.
+    NotText,
+    /// Failed to serialize the object to bincode.
+    Serialize { err: bincode::Error },
+    /// Failed to deserialize the object from bincode.
+    Deserialize { err: bincode::Error },
+}
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FResult {
+        use Error::*;
+        match self {
+            #[cfg(feature = "async-tokio")]
+            Write { .. } => write!(f, "Failed to write to given writer"),
+            #[cfg(feature = "async-tokio")]
+            Read { .. } => write!(f, "Failed to read from given reader"),
+            #[cfg(feature = "async-tokio")]
+            Flush { .. } => write!(f, "Failed to flush the given writer"),
+            NotText => write!(f, "bincode is a binary format and cannot be serialized to or deserialized from a string"),
+            Serialize { .. } => write!(f, "Failed to serialize to bincode"),
+            Deserialize { .. } => write!(f, "Failed to deserialize from bincode"),
+        }
+    }
+}
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        use Error::*;
+        match self {
+            #[cfg(feature = "async-tokio")]
+            Write { err } => Some(err),
+            #[cfg(feature = "async-tokio")]
+            Read { err } => Some(err),
+            #[cfg(feature = "async-tokio")]
+            Flush { err } => Some(err),
+            NotText => None,
+            Serialize { err } => Some(err),
+            Deserialize { err } => Some(err),
+        }
+    }
+}
+
+
+
+
+
+/***** LIBRARY *****/
+/// Implements a [`serializer::Serializer`] for [`bincode`].
+///
+/// This format is compact and fast, but not self-describing; it is mostly useful for data that is
+/// only ever read back by the same program (e.g., caches).
+///
+/// Note that bincode is a binary format. As such, [`serializer::Serializer::to_string()`] and
+/// [`serializer::Serializer::from_str()`] always fail with an [`Error::NotText`]; use the byte-,
+/// writer- and reader-functions instead. Further, this serializer has no pretty version available,
+/// so [`serializer::Serializer::to_bytes_pretty()`] and [`serializer::Serializer::to_writer_pretty()`]
+/// are simply aliases for [`serializer::Serializer::to_bytes()`] and
+/// [`serializer::Serializer::to_writer()`], respectively.
+///
+/// # Examples
+/// ```rust
+/// use serde::{Deserialize, Serialize};
+/// use serializable::bincode::Serializer;
+/// use serializable::Serializable;
+///
+/// #[derive(Debug, Deserialize, Eq, PartialEq, Serialize)]
+/// struct HelloWorld {
+///     hello: String,
+///     world: String,
+/// }
+/// impl Serializable<Serializer<HelloWorld>> for HelloWorld {}
+///
+/// assert_eq!(
+///     HelloWorld { hello: "Hello".into(), world: "World".into() }.to_bytes().unwrap(),
+///     b"\x05\0\0\0\0\0\0\0Hello\x05\0\0\0\0\0\0\0World"
+/// );
+///
+/// assert_eq!(
+///     HelloWorld::from_bytes(b"\x07\0\0\0\0\0\0\0Goodbye\x06\0\0\0\0\0\0\0Planet").unwrap(),
+///     HelloWorld { hello: "Goodbye".into(), world: "Planet".into() }
+/// );
+/// ```
+///
+/// Files are only decompressed if their extension says so, so output that happens to start with
+/// the magic bytes of a [`Compression`](crate::Compression) codec is still read as-is:
+/// ```rust
+/// use std::path::PathBuf;
+///
+/// use serde::{Deserialize, Serialize};
+/// use serializable::bincode::Serializer;
+/// use serializable::Serializable;
+///
+/// #[derive(Debug, Deserialize, Eq, PartialEq, Serialize)]
+/// struct Packet {
+///     kind: u16,
+///     body: String,
+/// }
+/// impl Serializable<Serializer<Packet>> for Packet {}
+///
+/// // `0x8b1f` is written as `1f 8b`, i.e., the magic bytes of gzip
+/// let packet = Packet { kind: 0x8b1f, body: "Hello, world!".into() };
+/// assert!(packet.to_bytes().unwrap().starts_with(b"\x1f\x8b"));
+///
+/// let path: PathBuf = std::env::temp_dir().join("test_gzip_magic.bin");
+/// packet.to_path(&path).unwrap();
+/// assert_eq!(Packet::from_path(&path).unwrap(), packet);
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct Serializer<T>(PhantomData<T>);
+
+/// Marks the bincode [`Serializer`] as a [`Backend`](crate::Backend).
+///
+/// Use it to select this backend in [`SerializableAs`](crate::SerializableAs) (e.g.,
+/// `value.to_string_as::<Bincode>()`).
+#[derive(Clone, Copy, Debug)]
+pub struct Bincode;
+impl serializer::Backend for Bincode {
+    type Serializer<T> = Serializer<T>;
+}
+
+impl<T: for<'de> Deserialize<'de> + Serialize> serializer::Serializer for Serializer<T> {
+    type Error = Error;
+    type Target = T;
+
+    #[inline]
+    fn to_string(_value: &Self::Target) -> Result<String, Self::Error> { Err(Error::NotText) }
+
+    #[inline]
+    fn to_bytes(value: &Self::Target) -> Result<Vec<u8>, Self::Error> { bincode::serialize(value).map_err(|err| Error::Serialize { err }) }
+
+    #[inline]
+    fn to_writer(value: &Self::Target, writer: impl Write) -> Result<(), Self::Error> {
+        bincode::serialize_into(writer, value).map_err(|err| Error::Serialize { err })
+    }
+
+    #[inline]
+    fn from_str(_raw: impl AsRef<str>) -> Result<Self::Target, Self::Error> { Err(Error::NotText) }
+
+    #[inline]
+    fn from_bytes(raw: impl AsRef<[u8]>) -> Result<Self::Target, Self::Error> {
+        bincode::deserialize(raw.as_ref()).map_err(|err| Error::Deserialize { err })
+    }
+
+    #[inline]
+    fn from_reader(reader: impl Read) -> Result<Self::Target, Self::Error> {
+        bincode::deserialize_from(reader).map_err(|err| Error::Deserialize { err })
+    }
+}
+
+#[cfg(feature = "async-tokio")]
+#[async_trait::async_trait]
+impl<T: Send + Sync + for<'de> Deserialize<'de> + Serialize> serializer::SerializerAsync for Serializer<T> {
+    #[inline]
+    async fn to_writer_async(value: &Self::Target, mut writer: impl Send + std::marker::Unpin + tokio::io::AsyncWrite) -> Result<(), Self::Error> {
+        use tokio::io::AsyncWriteExt;
+
+        // Serialize ourselves to a buffer first
+        let raw: Vec<u8> = <Self as serializer::Serializer>::to_bytes(value)?;
+
+        // Now write to the writer
+        if let Err(err) = writer.write_all(&raw).await {
+            return Err(Error::Write { err });
+        }
+        // ...making sure its flushed
+        match writer.flush().await {
+            Ok(_) => Ok(()),
+            Err(err) => Err(Error::Flush { err }),
+        }
+    }
+
+    #[inline]
+    async fn from_reader_async(mut reader: impl Send + std::marker::Unpin + tokio::io::AsyncRead) -> Result<T, Self::Error> {
+        use tokio::io::AsyncReadExt;
+
+        // Read the entire buffer first
+        let mut raw: Vec<u8> = Vec::new();
+        if let Err(err) = reader.read_to_end(&mut raw).await {
+            return Err(Error::Read { err });
+        }
+
+        // Then deserialize as bytes
+        <Self as serializer::Serializer>::from_bytes(&raw)
+    }
+}
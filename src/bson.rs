@@ -0,0 +1,229 @@
+//  BSON.rs
+//    by Lut99
+//
+//  Created:
+//    16 Oct 2026, 21:34:12
+//  Last edited:
+//    16 Oct 2026, 21:57:14
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Implements [`serializer::Serializer`] and cohorts for [`bson`].
+//
+
+use std::error;
+use std::fmt::{Display, Formatter, Result as FResult};
+use std::io::{Read, Write};
+use std::marker::PhantomData;
+
+use bson::Document;
+use serde::{Deserialize, Serialize};
+
+use crate::serializer;
+
+
+/***** ERRORS *****/
+/// Defines errors that occur when using the BSON [`Serializer`].
+#[derive(Debug)]
+pub enum Error {
+    /// Failed to write to the given writer.
+    #[cfg(feature = "async-tokio")]
+    Write { err: std::io::Error },
+    /// Failed to read from the given reader.
+    #[cfg(feature = "async-tokio")]
+    Read { err: std::io::Error },
+    /// Failed to flush the given writer.
+    #[cfg(feature = "async-tokio")]
+    Flush { err: std::io::Error },
+    /// Failed to serialize extended JSON to a string.
+    ExtJsonSerialize { err: serde_json::Error },
+    /// Failed to parse the given string as JSON.
+    ExtJsonParse { err: serde_json::Error },
+    /// The given JSON was not valid extended JSON.
+    ExtJsonDeserialize { err: bson::extjson::de::Error },
+    /// Failed to serialize the object to BSON.
+    Serialize { err: bson::ser::Error },
+    /// Failed to deserialize the object from BSON.
+    Deserialize { err: bson::de::Error },
+}
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FResult {
+        use Error::*;
+        match self {
+            #[cfg(feature = "async-tokio")]
+            Write { .. } => write!(f, "Failed to write to given writer"),
+            #[cfg(feature = "async-tokio")]
+            Read { .. } => write!(f, "Failed to read from given reader"),
+            #[cfg(feature = "async-tokio")]
+            Flush { .. } => write!(f, "Failed to flush the given writer"),
+            ExtJsonSerialize { .. } => write!(f, "Failed to serialize to extended JSON"),
+            ExtJsonParse { .. } => write!(f, "Failed to parse extended JSON"),
+            ExtJsonDeserialize { .. } => write!(f, "Failed to deserialize from extended JSON"),
+            Serialize { .. } => write!(f, "Failed to serialize to BSON"),
+            Deserialize { .. } => write!(f, "Failed to deserialize from BSON"),
+        }
+    }
+}
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        use Error::*;
+        match self {
+            #[cfg(feature = "async-tokio")]
+            Write { err } => Some(err),
+            #[cfg(feature = "async-tokio")]
+            Read { err } => Some(err),
+            #[cfg(feature = "async-tokio")]
+            Flush { err } => Some(err),
+            ExtJsonSerialize { err } => Some(err),
+            ExtJsonParse { err } => Some(err),
+            ExtJsonDeserialize { err } => Some(err),
+            Serialize { err } => Some(err),
+            Deserialize { err } => Some(err),
+        }
+    }
+}
+
+
+
+
+
+/***** LIBRARY *****/
+/// Implements a [`serializer::Serializer`] for [`bson`].
+///
+/// Note that BSON is a binary format. As such, [`serializer::Serializer::to_string()`] and
+/// [`serializer::Serializer::to_string_pretty()`] serialize to (relaxed)
+/// [extended JSON](https://www.mongodb.com/docs/manual/reference/mongodb-extended-json/) instead,
+/// and [`serializer::Serializer::from_str()`] parses it again. Use the byte-, writer- and
+/// reader-functions to (de)serialize actual BSON; these have no pretty version, and so
+/// [`serializer::Serializer::to_bytes_pretty()`] and [`serializer::Serializer::to_writer_pretty()`]
+/// are simply aliases for [`serializer::Serializer::to_bytes()`] and
+/// [`serializer::Serializer::to_writer()`], respectively.
+///
+/// Also note that BSON only supports documents at the top level. Hence, the target type must
+/// serialize to a map (e.g., a struct).
+///
+/// # Examples
+/// ```rust
+/// use serde::{Deserialize, Serialize};
+/// use serializable::bson::Serializer;
+/// use serializable::Serializable;
+///
+/// #[derive(Debug, Deserialize, Eq, PartialEq, Serialize)]
+/// struct HelloWorld {
+///     hello: String,
+///     world: String,
+/// }
+/// impl Serializable<Serializer<HelloWorld>> for HelloWorld {}
+///
+/// assert_eq!(
+///     HelloWorld { hello: "Hello".into(), world: "World".into() }.to_bytes().unwrap(),
+///     b"\x27\x00\x00\x00\x02hello\x00\x06\x00\x00\x00Hello\x00\x02world\x00\x06\x00\x00\x00World\x00\x00"
+/// );
+///
+/// assert_eq!(
+///     HelloWorld::from_bytes(b"\x2a\x00\x00\x00\x02hello\x00\x08\x00\x00\x00Goodbye\x00\x02world\x00\x07\x00\x00\x00Planet\x00\x00").unwrap(),
+///     HelloWorld { hello: "Goodbye".into(), world: "Planet".into() }
+/// );
+///
+/// // Strings are in extended JSON
+/// assert_eq!(
+///     HelloWorld { hello: "Hello".into(), world: "World".into() }.to_string().unwrap(),
+///     "{\"hello\":\"Hello\",\"world\":\"World\"}"
+/// );
+/// assert_eq!(
+///     HelloWorld { hello: "Hello".into(), world: "World".into() }.to_string_pretty().unwrap(),
+///     "{\n  \"hello\": \"Hello\",\n  \"world\": \"World\"\n}"
+/// );
+/// assert_eq!(
+///     HelloWorld::from_str("{\"hello\":\"Goodbye\",\"world\":\"Planet\"}").unwrap(),
+///     HelloWorld { hello: "Goodbye".into(), world: "Planet".into() }
+/// );
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct Serializer<T>(PhantomData<T>);
+
+/// Marks the BSON [`Serializer`] as a [`Backend`](crate::Backend).
+///
+/// Use it to select this backend in [`SerializableAs`](crate::SerializableAs) (e.g.,
+/// `value.to_string_as::<Bson>()`).
+#[derive(Clone, Copy, Debug)]
+pub struct Bson;
+impl serializer::Backend for Bson {
+    type Serializer<T> = Serializer<T>;
+}
+
+impl<T: for<'de> Deserialize<'de> + Serialize> serializer::Serializer for Serializer<T> {
+    type Error = Error;
+    type Target = T;
+
+    #[inline]
+    fn to_string(value: &Self::Target) -> Result<String, Self::Error> {
+        let value: bson::Bson = bson::to_bson(value).map_err(|err| Error::Serialize { err })?;
+        serde_json::to_string(&value.into_relaxed_extjson()).map_err(|err| Error::ExtJsonSerialize { err })
+    }
+
+    #[inline]
+    fn to_string_pretty(value: &Self::Target) -> Result<String, Self::Error> {
+        let value: bson::Bson = bson::to_bson(value).map_err(|err| Error::Serialize { err })?;
+        serde_json::to_string_pretty(&value.into_relaxed_extjson()).map_err(|err| Error::ExtJsonSerialize { err })
+    }
+
+    #[inline]
+    fn to_bytes(value: &Self::Target) -> Result<Vec<u8>, Self::Error> { bson::to_vec(value).map_err(|err| Error::Serialize { err }) }
+
+    #[inline]
+    fn to_writer(value: &Self::Target, writer: impl Write) -> Result<(), Self::Error> {
+        let doc: Document = bson::to_document(value).map_err(|err| Error::Serialize { err })?;
+        doc.to_writer(writer).map_err(|err| Error::Serialize { err })
+    }
+
+    #[inline]
+    fn from_str(raw: impl AsRef<str>) -> Result<Self::Target, Self::Error> {
+        let value: serde_json::Value = serde_json::from_str(raw.as_ref()).map_err(|err| Error::ExtJsonParse { err })?;
+        let value: bson::Bson = bson::Bson::try_from(value).map_err(|err| Error::ExtJsonDeserialize { err })?;
+        bson::from_bson(value).map_err(|err| Error::Deserialize { err })
+    }
+
+    #[inline]
+    fn from_bytes(raw: impl AsRef<[u8]>) -> Result<Self::Target, Self::Error> { bson::from_slice(raw.as_ref()).map_err(|err| Error::Deserialize { err }) }
+
+    #[inline]
+    fn from_reader(reader: impl Read) -> Result<Self::Target, Self::Error> { bson::from_reader(reader).map_err(|err| Error::Deserialize { err }) }
+}
+
+#[cfg(feature = "async-tokio")]
+#[async_trait::async_trait]
+impl<T: Send + Sync + for<'de> Deserialize<'de> + Serialize> serializer::SerializerAsync for Serializer<T> {
+    #[inline]
+    async fn to_writer_async(value: &Self::Target, mut writer: impl Send + std::marker::Unpin + tokio::io::AsyncWrite) -> Result<(), Self::Error> {
+        use tokio::io::AsyncWriteExt;
+
+        // Serialize ourselves to a buffer first
+        let raw: Vec<u8> = <Self as serializer::Serializer>::to_bytes(value)?;
+
+        // Now write to the writer
+        if let Err(err) = writer.write_all(&raw).await {
+            return Err(Error::Write { err });
+        }
+        // ...making sure its flushed
+        match writer.flush().await {
+            Ok(_) => Ok(()),
+            Err(err) => Err(Error::Flush { err }),
+        }
+    }
+
+    #[inline]
+    async fn from_reader_async(mut reader: impl Send + std::marker::Unpin + tokio::io::AsyncRead) -> Result<T, Self::Error> {
+        use tokio::io::AsyncReadExt;
+
+        // Read the entire buffer first
+        let mut raw: Vec<u8> = Vec::new();
+        if let Err(err) = reader.read_to_end(&mut raw).await {
+            return Err(Error::Read { err });
+        }
+
+        // Then deserialize as bytes
+        <Self as serializer::Serializer>::from_bytes(&raw)
+    }
+}
@@ -0,0 +1,301 @@
+//  CBOR.rs
+//    by Lut99
+//
+//  Created:
+//    16 Oct 2026, 21:09:47
+//  Last edited:
+//    17 Oct 2026, 10:34:40
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Implements [`serializer::Serializer`] and cohorts for [`ciborium`].
+//
+
+use std::error;
+use std::fmt::{Display, Formatter, Result as FResult, Write as _};
+use std::io::{Read, Write};
+use std::marker::PhantomData;
+
+use ciborium::Value;
+use serde::{Deserialize, Serialize};
+
+use crate::serializer;
+
+
+/***** ERRORS *****/
+/// Defines errors that occur when using the CBOR [`Serializer`].
+#[derive(Debug)]
+pub enum Error {
+    /// Failed to write to the given writer.
+    #[cfg(feature = "async-tokio")]
+    Write { err: std::io::Error },
+    /// Failed to read from the given reader.
+    #[cfg(feature = "async-tokio")]
+    Read { err: std::io::Error },
+    /// Failed to flush the given writer.
+    #[cfg(feature = "async-tokio")]
+    Flush { err: std::io::Error },
+    /// CBOR is a binary format, and cannot be serialized to or deserialized from a string.
+    NotText,
+    /// Failed to serialize the object to CBOR diagnostic notation.
+    Diagnostic { err: ciborium::value::Error },
+    /// Failed to serialize the object to CBOR.
+    Serialize { err: ciborium::ser::Error<std::io::Error> },
+    /// Failed to deserialize the object from CBOR.
+    Deserialize { err: ciborium::de::Error<std::io::Error> },
+}
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FResult {
+        use Error::*;
+        match self {
+            #[cfg(feature = "async-tokio")]
+            Write { .. } => write!(f, "Failed to write to given writer"),
+            #[cfg(feature = "async-tokio")]
+            Read { .. } => write!(f, "Failed to read from given reader"),
+            #[cfg(feature = "async-tokio")]
+            Flush { .. } => write!(f, "Failed to flush the given writer"),
+            NotText => write!(f, "CBOR is a binary format and cannot be serialized to or deserialized from a string"),
+            Diagnostic { .. } => write!(f, "Failed to serialize to CBOR diagnostic notation"),
+            Serialize { .. } => write!(f, "Failed to serialize to CBOR"),
+            Deserialize { .. } => write!(f, "Failed to deserialize from CBOR"),
+        }
+    }
+}
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        use Error::*;
+        match self {
+            #[cfg(feature = "async-tokio")]
+            Write { err } => Some(err),
+            #[cfg(feature = "async-tokio")]
+            Read { err } => Some(err),
+            #[cfg(feature = "async-tokio")]
+            Flush { err } => Some(err),
+            NotText => None,
+            Diagnostic { err } => Some(err),
+            Serialize { err } => Some(err),
+            Deserialize { err } => Some(err),
+        }
+    }
+}
+
+
+
+
+
+/***** HELPER FUNCTIONS *****/
+/// Writes a [`Value`] in pretty [CBOR diagnostic notation](https://www.rfc-editor.org/rfc/rfc8949.html#name-diagnostic-notation).
+///
+/// Nested array elements and map entries are put on their own lines.
+///
+/// # Arguments
+/// - `value`: The [`Value`] to write.
+/// - `depth`: The current nesting depth (for indentation).
+/// - `out`: The string to write to.
+fn write_diag(value: &Value, depth: usize, out: &mut String) {
+    /// Writes a newline and indentation for the given depth.
+    fn newline(depth: usize, out: &mut String) {
+        out.push('\n');
+        for _ in 0..depth {
+            out.push_str("  ");
+        }
+    }
+
+    match value {
+        Value::Integer(i) => write!(out, "{}", i128::from(*i)).unwrap(),
+        Value::Bytes(b) => {
+            out.push_str("h'");
+            for byte in b {
+                write!(out, "{byte:02x}").unwrap();
+            }
+            out.push('\'');
+        },
+        Value::Float(f) => {
+            if f.is_nan() {
+                out.push_str("NaN");
+            } else if f.is_infinite() {
+                out.push_str(if *f > 0.0 { "Infinity" } else { "-Infinity" });
+            } else {
+                write!(out, "{f:?}").unwrap();
+            }
+        },
+        Value::Text(t) => {
+            out.push('"');
+            for c in t.chars() {
+                match c {
+                    '"' => out.push_str("\\\""),
+                    '\\' => out.push_str("\\\\"),
+                    '\n' => out.push_str("\\n"),
+                    '\r' => out.push_str("\\r"),
+                    '\t' => out.push_str("\\t"),
+                    c if c.is_control() => write!(out, "\\u{:04x}", c as u32).unwrap(),
+                    c => out.push(c),
+                }
+            }
+            out.push('"');
+        },
+        Value::Bool(b) => out.push_str(if *b { "true" } else { "false" }),
+        Value::Null => out.push_str("null"),
+        Value::Tag(tag, value) => {
+            write!(out, "{tag}(").unwrap();
+            write_diag(value, depth, out);
+            out.push(')');
+        },
+        Value::Array(elems) => {
+            out.push('[');
+            for (i, elem) in elems.iter().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+                newline(depth + 1, out);
+                write_diag(elem, depth + 1, out);
+            }
+            if !elems.is_empty() {
+                newline(depth, out);
+            }
+            out.push(']');
+        },
+        Value::Map(entries) => {
+            out.push('{');
+            for (i, (key, value)) in entries.iter().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+                newline(depth + 1, out);
+                write_diag(key, depth + 1, out);
+                out.push_str(": ");
+                write_diag(value, depth + 1, out);
+            }
+            if !entries.is_empty() {
+                newline(depth, out);
+            }
+            out.push('}');
+        },
+        _ => out.push_str("undefined"),
+    }
+}
+
+
+
+
+
+/***** LIBRARY *****/
+/// Implements a [`serializer::Serializer`] for [`ciborium`].
+///
+/// Note that CBOR is a binary format. As such, [`serializer::Serializer::to_string()`] and
+/// [`serializer::Serializer::from_str()`] always fail with an [`Error::NotText`]; use the byte-,
+/// writer- and reader-functions instead. These have no pretty version, and so
+/// [`serializer::Serializer::to_bytes_pretty()`] and [`serializer::Serializer::to_writer_pretty()`]
+/// are simply aliases for [`serializer::Serializer::to_bytes()`] and
+/// [`serializer::Serializer::to_writer()`], respectively.
+///
+/// The exception is [`serializer::Serializer::to_string_pretty()`], which writes
+/// [CBOR diagnostic notation](https://www.rfc-editor.org/rfc/rfc8949.html#name-diagnostic-notation)
+/// for inspection by humans. This is output-only: it cannot be deserialized again.
+///
+/// # Examples
+/// ```rust
+/// use serde::{Deserialize, Serialize};
+/// use serializable::cbor::Serializer;
+/// use serializable::Serializable;
+///
+/// #[derive(Debug, Deserialize, Eq, PartialEq, Serialize)]
+/// struct HelloWorld {
+///     hello: String,
+///     world: String,
+/// }
+/// impl Serializable<Serializer<HelloWorld>> for HelloWorld {}
+///
+/// assert_eq!(
+///     HelloWorld { hello: "Hello".into(), world: "World".into() }.to_bytes().unwrap(),
+///     b"\xa2\x65hello\x65Hello\x65world\x65World"
+/// );
+///
+/// assert_eq!(
+///     HelloWorld::from_bytes(b"\xa2\x65hello\x67Goodbye\x65world\x66Planet").unwrap(),
+///     HelloWorld { hello: "Goodbye".into(), world: "Planet".into() }
+/// );
+///
+/// // Pretty strings are in (output-only) diagnostic notation
+/// assert!(HelloWorld { hello: "Hello".into(), world: "World".into() }.to_string().is_err());
+/// assert_eq!(
+///     HelloWorld { hello: "Hello".into(), world: "World".into() }.to_string_pretty().unwrap(),
+///     "{\n  \"hello\": \"Hello\",\n  \"world\": \"World\"\n}"
+/// );
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct Serializer<T>(PhantomData<T>);
+
+/// Marks the CBOR [`Serializer`] as a [`Backend`](crate::Backend).
+///
+/// Use it to select this backend in [`SerializableAs`](crate::SerializableAs) (e.g.,
+/// `value.to_string_as::<Cbor>()`).
+#[derive(Clone, Copy, Debug)]
+pub struct Cbor;
+impl serializer::Backend for Cbor {
+    type Serializer<T> = Serializer<T>;
+}
+
+impl<T: for<'de> Deserialize<'de> + Serialize> serializer::Serializer for Serializer<T> {
+    type Error = Error;
+    type Target = T;
+
+    #[inline]
+    fn to_string(_value: &Self::Target) -> Result<String, Self::Error> { Err(Error::NotText) }
+
+    #[inline]
+    fn to_string_pretty(value: &Self::Target) -> Result<String, Self::Error> {
+        let value: Value = Value::serialized(value).map_err(|err| Error::Diagnostic { err })?;
+        let mut res: String = String::new();
+        write_diag(&value, 0, &mut res);
+        Ok(res)
+    }
+
+    #[inline]
+    fn to_writer(value: &Self::Target, writer: impl Write) -> Result<(), Self::Error> {
+        ciborium::into_writer(value, writer).map_err(|err| Error::Serialize { err })
+    }
+
+    #[inline]
+    fn from_str(_raw: impl AsRef<str>) -> Result<Self::Target, Self::Error> { Err(Error::NotText) }
+
+    #[inline]
+    fn from_reader(reader: impl Read) -> Result<Self::Target, Self::Error> { ciborium::from_reader(reader).map_err(|err| Error::Deserialize { err }) }
+}
+
+#[cfg(feature = "async-tokio")]
+#[async_trait::async_trait]
+impl<T: Send + Sync + for<'de> Deserialize<'de> + Serialize> serializer::SerializerAsync for Serializer<T> {
+    #[inline]
+    async fn to_writer_async(value: &Self::Target, mut writer: impl Send + std::marker::Unpin + tokio::io::AsyncWrite) -> Result<(), Self::Error> {
+        use tokio::io::AsyncWriteExt;
+
+        // Serialize ourselves to a buffer first
+        let raw: Vec<u8> = <Self as serializer::Serializer>::to_bytes(value)?;
+
+        // Now write to the writer
+        if let Err(err) = writer.write_all(&raw).await {
+            return Err(Error::Write { err });
+        }
+        // ...making sure its flushed
+        match writer.flush().await {
+            Ok(_) => Ok(()),
+            Err(err) => Err(Error::Flush { err }),
+        }
+    }
+
+    #[inline]
+    async fn from_reader_async(mut reader: impl Send + std::marker::Unpin + tokio::io::AsyncRead) -> Result<T, Self::Error> {
+        use tokio::io::AsyncReadExt;
+
+        // Read the entire buffer first
+        let mut raw: Vec<u8> = Vec::new();
+        if let Err(err) = reader.read_to_end(&mut raw).await {
+            return Err(Error::Read { err });
+        }
+
+        // Then deserialize as bytes
+        <Self as serializer::Serializer>::from_bytes(&raw)
+    }
+}
@@ -0,0 +1,258 @@
+//  CHECKSUM.rs
+//    by Lut99
+//
+//  Created:
+//    17 Oct 2026, 00:14:27
+//  Last edited:
+//    17 Oct 2026, 11:19:30
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Defines the [`Checksum`], which selects one of the enabled checksum
+//!   algorithms with which written files can be verified when they're read
+//!   back.
+//
+
+use std::ffi::OsString;
+use std::fmt::{Display, Formatter, Result as FResult};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+
+/***** LIBRARY *****/
+/// Selects one of the enabled checksum algorithms.
+///
+/// When writing files with [`Serializable::to_path_with_options()`](crate::Serializable::to_path_with_options())
+/// and [`WriteOptions::checksum`](crate::WriteOptions::checksum) set, the checksum of the file (as
+/// written, i.e., after compression) is stored as hexadecimal in a file next to it (e.g.,
+/// `state.json.b3`). When reading files (e.g., with
+/// [`Serializable::from_path()`](crate::Serializable::from_path())), the file is verified against
+/// such a checksum file if there is one. All other functions that write files (e.g.,
+/// [`Serializable::to_path()`](crate::Serializable::to_path())) remove existing checksum files,
+/// as they would no longer match.
+///
+/// Only algorithms whose feature is enabled are available as variants.
+///
+/// # Examples
+/// ```rust
+/// # #[cfg(all(feature = "checksum-crc32c", feature = "checksum-blake3"))] {
+/// use serializable::Checksum;
+///
+/// // Requires the `checksum-crc32c` and `checksum-blake3`-features, respectively
+/// assert_eq!(Checksum::Crc32c.compute(b"Hello, world!"), "c8a106e5");
+/// assert_eq!(Checksum::Blake3.path("state.json").to_str(), Some("state.json.b3"));
+/// # }
+/// ```
+///
+/// Overwriting a file without a checksum doesn't leave a stale one behind:
+/// ```rust
+/// # #[cfg(all(feature = "checksum-crc32c", feature = "serde-json"))] {
+/// use std::path::PathBuf;
+///
+/// use serde::{Deserialize, Serialize};
+/// use serializable::json::Serializer;
+/// use serializable::{Checksum, Serializable, WriteOptions};
+///
+/// #[derive(Debug, Deserialize, Eq, PartialEq, Serialize)]
+/// struct Counter {
+///     count: u32,
+/// }
+/// impl Serializable<Serializer<Counter>> for Counter {}
+///
+/// let path: PathBuf = std::env::temp_dir().join("test_stale_checksum.json");
+/// Counter { count: 1 }.to_path_with_options(&path, &WriteOptions { checksum: Some(Checksum::Crc32c), ..Default::default() }).unwrap();
+/// assert!(Checksum::Crc32c.path(&path).exists());
+///
+/// Counter { count: 2 }.to_path(&path).unwrap();
+/// assert!(!Checksum::Crc32c.path(&path).exists());
+/// assert_eq!(Counter::from_path(&path).unwrap(), Counter { count: 2 });
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Checksum {
+    /// [CRC-32C](https://www.rfc-editor.org/rfc/rfc3720#appendix-B.4) (Castagnoli), using
+    /// [`crc32c`](https://github.com/zowens/crc32c). Fast, and catches bit-rot and truncation, but
+    /// not deliberate tampering.
+    #[cfg(feature = "checksum-crc32c")]
+    Crc32c,
+    /// [BLAKE3](https://github.com/BLAKE3-team/BLAKE3), using
+    /// [`blake3`](https://github.com/BLAKE3-team/BLAKE3). A cryptographic hash, so it also makes
+    /// it infeasible to change the file without changing its checksum.
+    #[cfg(feature = "checksum-blake3")]
+    Blake3,
+}
+impl Checksum {
+    /// All algorithms that are enabled in this build.
+    pub const ALL: &'static [Self] = &[
+        #[cfg(feature = "checksum-crc32c")]
+        Self::Crc32c,
+        #[cfg(feature = "checksum-blake3")]
+        Self::Blake3,
+    ];
+
+
+
+    /// Returns the extension (without leading dot) that is appended to a file's path to get the
+    /// path of its checksum file.
+    ///
+    /// # Returns
+    /// The extension, in lowercase.
+    #[inline]
+    pub const fn extension(&self) -> &'static str {
+        match *self {
+            #[cfg(feature = "checksum-crc32c")]
+            Self::Crc32c => "crc32c",
+            #[cfg(feature = "checksum-blake3")]
+            Self::Blake3 => "b3",
+        }
+    }
+
+    /// Returns the human-readable name of this algorithm.
+    ///
+    /// # Returns
+    /// The name of the algorithm (e.g., `CRC32C`).
+    #[inline]
+    pub const fn name(&self) -> &'static str {
+        match *self {
+            #[cfg(feature = "checksum-crc32c")]
+            Self::Crc32c => "CRC32C",
+            #[cfg(feature = "checksum-blake3")]
+            Self::Blake3 => "BLAKE3",
+        }
+    }
+
+    /// Returns the path of the checksum file of the given file.
+    ///
+    /// # Arguments
+    /// - `path`: The path of the file to return the checksum file of.
+    ///
+    /// # Returns
+    /// The given path with `.<extension>` appended to it (e.g., `state.json.b3`).
+    #[inline]
+    pub fn path(&self, path: impl AsRef<Path>) -> PathBuf {
+        let mut sum: OsString = path.as_ref().as_os_str().to_owned();
+        sum.push(".");
+        sum.push(self.extension());
+        sum.into()
+    }
+
+    /// Computes the checksum of the given input.
+    ///
+    /// # Arguments
+    /// - `raw`: The input to compute the checksum of.
+    ///
+    /// # Returns
+    /// The checksum, in lowercase hexadecimal.
+    #[cfg_attr(not(any(feature = "checksum-blake3", feature = "checksum-crc32c")), allow(unused_variables))]
+    #[inline]
+    pub fn compute(&self, raw: &[u8]) -> String {
+        match *self {
+            #[cfg(feature = "checksum-crc32c")]
+            Self::Crc32c => format!("{:08x}", crc32c::crc32c(raw)),
+            #[cfg(feature = "checksum-blake3")]
+            Self::Blake3 => blake3::hash(raw).to_hex().to_string(),
+        }
+    }
+}
+impl Display for Checksum {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FResult { f.write_str(self.name()) }
+}
+
+
+
+/// Computes a checksum incrementally.
+pub(crate) enum Hasher {
+    /// Computes a CRC32C.
+    #[cfg(feature = "checksum-crc32c")]
+    Crc32c(u32),
+    /// Computes a BLAKE3 hash.
+    #[cfg(feature = "checksum-blake3")]
+    Blake3(Box<blake3::Hasher>),
+}
+impl Hasher {
+    /// Constructor for the Hasher.
+    ///
+    /// # Arguments
+    /// - `checksum`: The [`Checksum`] to compute.
+    ///
+    /// # Returns
+    /// A new Hasher that hasn't seen any input yet.
+    #[inline]
+    pub(crate) fn new(checksum: Checksum) -> Self {
+        match checksum {
+            #[cfg(feature = "checksum-crc32c")]
+            Checksum::Crc32c => Self::Crc32c(0),
+            #[cfg(feature = "checksum-blake3")]
+            Checksum::Blake3 => Self::Blake3(Box::new(blake3::Hasher::new())),
+        }
+    }
+
+    /// Adds the given input to the checksum.
+    ///
+    /// # Arguments
+    /// - `raw`: The next part of the input.
+    #[cfg_attr(not(any(feature = "checksum-blake3", feature = "checksum-crc32c")), allow(unused_variables))]
+    #[inline]
+    pub(crate) fn update(&mut self, raw: &[u8]) {
+        match *self {
+            #[cfg(feature = "checksum-crc32c")]
+            Self::Crc32c(ref mut crc) => *crc = crc32c::crc32c_append(*crc, raw),
+            #[cfg(feature = "checksum-blake3")]
+            Self::Blake3(ref mut hasher) => {
+                hasher.update(raw);
+            },
+        }
+    }
+
+    /// Returns the checksum of all input given so far.
+    ///
+    /// # Returns
+    /// The checksum, in lowercase hexadecimal.
+    #[inline]
+    pub(crate) fn finalize(&self) -> String {
+        match *self {
+            #[cfg(feature = "checksum-crc32c")]
+            Self::Crc32c(crc) => format!("{crc:08x}"),
+            #[cfg(feature = "checksum-blake3")]
+            Self::Blake3(ref hasher) => hasher.finalize().to_hex().to_string(),
+        }
+    }
+}
+
+
+
+/// Computes the checksum of everything written through it (if asked to).
+pub(crate) struct Hashing<'h, W> {
+    /// The writer to write to.
+    writer: W,
+    /// The hasher to update with everything that's written, if any.
+    hasher: Option<&'h mut Hasher>,
+}
+impl<'h, W: Write> Hashing<'h, W> {
+    /// Constructor for the Hashing writer.
+    ///
+    /// # Arguments
+    /// - `writer`: The [`Write`]r to write to.
+    /// - `hasher`: The [`Hasher`] to update with everything that's written, or [`None`] to only
+    ///   pass it on.
+    ///
+    /// # Returns
+    /// A new Hashing writer.
+    #[inline]
+    pub(crate) fn new(writer: W, hasher: Option<&'h mut Hasher>) -> Self { Self { writer, hasher } }
+}
+impl<W: Write> Write for Hashing<'_, W> {
+    #[inline]
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        let n: usize = self.writer.write(buf)?;
+        if let Some(hasher) = &mut self.hasher {
+            hasher.update(&buf[..n]);
+        }
+        Ok(n)
+    }
+
+    #[inline]
+    fn flush(&mut self) -> std::io::Result<()> { self.writer.flush() }
+}
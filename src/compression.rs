@@ -0,0 +1,420 @@
+//  COMPRESSION.rs
+//    by Lut99
+//
+//  Created:
+//    16 Oct 2026, 23:38:12
+//  Last edited:
+//    17 Oct 2026, 11:52:40
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Defines the [`Compression`], which selects one of the enabled
+//!   compression codecs that are transparently applied when reading or
+//!   writing files.
+//
+
+use std::fmt::{Display, Formatter, Result as FResult};
+use std::io::{Read, Write};
+use std::path::Path;
+
+
+/***** LIBRARY *****/
+/// Selects one of the enabled compression codecs.
+///
+/// When writing files (e.g., with [`Serializable::to_path()`](crate::Serializable::to_path())),
+/// the codec is chosen by the last extension of the path (e.g., `state.json.gz`), or explicitly
+/// through [`WriteOptions::compression`](crate::WriteOptions::compression). When reading files
+/// (e.g., with [`Serializable::from_path()`](crate::Serializable::from_path())), it is chosen by
+/// the extension of the path as well. Files without such an extension are always read as-is, even
+/// if they start with the magic bytes of a codec (see [`Compression::detect()`]). This way,
+/// uncompressed binary formats whose output happens to start with those bytes are still read
+/// correctly.
+///
+/// Only codecs whose feature is enabled are available as variants.
+///
+/// # Examples
+/// ```rust
+/// # #[cfg(all(feature = "compress-gzip", feature = "compress-zstd", feature = "compress-xz"))] {
+/// use serializable::Compression;
+///
+/// // Requires the `compress-gzip`, `compress-zstd` and `compress-xz`-features, respectively
+/// assert_eq!(Compression::from_path("state.json.gz"), Some(Compression::Gzip));
+/// assert_eq!(Compression::from_path("config.yaml.zst"), Some(Compression::Zstd));
+/// assert_eq!(Compression::from_path("snapshot.XZ"), Some(Compression::Xz));
+/// assert_eq!(Compression::from_path("state.json"), None);
+///
+/// assert_eq!(Compression::detect(b"\x1f\x8b\x08\x00"), Some(Compression::Gzip));
+/// assert_eq!(Compression::detect(b"{\"hello\":\"world\"}"), None);
+/// # }
+/// ```
+///
+/// Input that fails to decompress is reported as such, rather than as a backend error:
+/// ```rust
+/// # #[cfg(all(feature = "serde-json", feature = "compress-gzip"))] {
+/// use std::path::PathBuf;
+///
+/// use serde::{Deserialize, Serialize};
+/// use serializable::json::Serializer;
+/// use serializable::{Error, Serializable};
+///
+/// #[derive(Debug, Deserialize, Serialize)]
+/// struct HelloWorld {
+///     hello: String,
+/// }
+/// impl Serializable<Serializer<HelloWorld>> for HelloWorld {}
+///
+/// // A gzip header followed by garbage
+/// let path: PathBuf = std::env::temp_dir().join("test_corrupt.json.gz");
+/// std::fs::write(&path, b"\x1f\x8b\x08\x00garbage").unwrap();
+/// assert!(matches!(HelloWorld::from_path(&path), Err(Error::FileDecompress { .. })));
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Compression {
+    /// [gzip](https://www.rfc-editor.org/rfc/rfc1952), using [`flate2`](https://github.com/rust-lang/flate2-rs).
+    #[cfg(feature = "compress-gzip")]
+    Gzip,
+    /// [xz](https://tukaani.org/xz/format.html), using [`xz2`](https://github.com/alexcrichton/xz2-rs).
+    #[cfg(feature = "compress-xz")]
+    Xz,
+    /// [Zstandard](https://facebook.github.io/zstd), using [`zstd`](https://github.com/gyscos/zstd-rs).
+    #[cfg(feature = "compress-zstd")]
+    Zstd,
+}
+impl Compression {
+    /// All codecs that are enabled in this build.
+    pub const ALL: &'static [Self] = &[
+        #[cfg(feature = "compress-gzip")]
+        Self::Gzip,
+        #[cfg(feature = "compress-xz")]
+        Self::Xz,
+        #[cfg(feature = "compress-zstd")]
+        Self::Zstd,
+    ];
+
+
+
+    /// Returns the file extensions (without leading dot) that are associated with this codec.
+    ///
+    /// The first one is the preferred extension.
+    ///
+    /// # Returns
+    /// A list of extensions, in lowercase.
+    #[inline]
+    pub const fn extensions(&self) -> &'static [&'static str] {
+        match *self {
+            #[cfg(feature = "compress-gzip")]
+            Self::Gzip => &["gz", "gzip"],
+            #[cfg(feature = "compress-xz")]
+            Self::Xz => &["xz"],
+            #[cfg(feature = "compress-zstd")]
+            Self::Zstd => &["zst", "zstd"],
+        }
+    }
+
+    /// Returns the human-readable name of this codec.
+    ///
+    /// # Returns
+    /// The name of the codec (e.g., `gzip`).
+    #[inline]
+    pub const fn name(&self) -> &'static str {
+        match *self {
+            #[cfg(feature = "compress-gzip")]
+            Self::Gzip => "gzip",
+            #[cfg(feature = "compress-xz")]
+            Self::Xz => "xz",
+            #[cfg(feature = "compress-zstd")]
+            Self::Zstd => "Zstandard",
+        }
+    }
+
+    /// Returns the magic bytes with which data compressed with this codec starts.
+    ///
+    /// # Returns
+    /// The magic bytes, which are at most 6 bytes long.
+    #[inline]
+    pub const fn magic(&self) -> &'static [u8] {
+        match *self {
+            #[cfg(feature = "compress-gzip")]
+            Self::Gzip => &[0x1f, 0x8b],
+            #[cfg(feature = "compress-xz")]
+            Self::Xz => &[0xfd, b'7', b'z', b'X', b'Z', 0x00],
+            #[cfg(feature = "compress-zstd")]
+            Self::Zstd => &[0x28, 0xb5, 0x2f, 0xfd],
+        }
+    }
+
+    /// Finds the enabled codec associated with the given file extension.
+    ///
+    /// # Arguments
+    /// - `ext`: The extension to find the codec of, without leading dot. Matched
+    ///   case-insensitively.
+    ///
+    /// # Returns
+    /// The matching [`Compression`], or [`None`] if no enabled codec uses this extension.
+    #[inline]
+    pub fn from_extension(ext: impl AsRef<str>) -> Option<Self> {
+        let ext: &str = ext.as_ref();
+        Self::ALL.iter().find(|codec| codec.extensions().iter().any(|e| e.eq_ignore_ascii_case(ext))).copied()
+    }
+
+    /// Finds the enabled codec associated with the (last) extension of the given path.
+    ///
+    /// # Arguments
+    /// - `path`: The path to find the codec of.
+    ///
+    /// # Returns
+    /// The matching [`Compression`], or [`None`] if the path has no extension or no enabled codec
+    /// uses it (i.e., the file is not compressed).
+    #[inline]
+    pub fn from_path(path: impl AsRef<Path>) -> Option<Self> { Self::from_extension(path.as_ref().extension()?.to_str()?) }
+
+    /// Detects the enabled codec with which the given input is compressed.
+    ///
+    /// # Arguments
+    /// - `raw`: The (start of the) input to check. Only its first 6 bytes are used.
+    ///
+    /// # Returns
+    /// The [`Compression`] whose magic bytes the input starts with, or [`None`] if it doesn't look
+    /// compressed with any of the enabled codecs.
+    #[inline]
+    pub fn detect(raw: &[u8]) -> Option<Self> { Self::ALL.iter().find(|codec| raw.starts_with(codec.magic())).copied() }
+}
+impl Display for Compression {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FResult { f.write_str(self.name()) }
+}
+
+
+
+/// Compresses everything written to it with one of the enabled codecs (or not at all).
+///
+/// Note that [`Encoder::finish()`] must be called once done, as most codecs write a trailer.
+pub(crate) enum Encoder<W: Write> {
+    /// Writes to the wrapped writer as-is.
+    Plain(W),
+    /// Compresses with gzip.
+    #[cfg(feature = "compress-gzip")]
+    Gzip(flate2::write::GzEncoder<W>),
+    /// Compresses with xz.
+    #[cfg(feature = "compress-xz")]
+    Xz(xz2::write::XzEncoder<W>),
+    /// Compresses with Zstandard.
+    #[cfg(feature = "compress-zstd")]
+    Zstd(zstd::stream::write::Encoder<'static, W>),
+}
+impl<W: Write> Encoder<W> {
+    /// Constructor for the Encoder.
+    ///
+    /// # Arguments
+    /// - `writer`: The [`Write`]r to write the compressed output to.
+    /// - `compression`: The [`Compression`] to compress with, or [`None`] to not compress.
+    ///
+    /// # Returns
+    /// A new Encoder that compresses with the default level of the given codec.
+    ///
+    /// # Errors
+    /// This function errors if the codec failed to initialize.
+    #[inline]
+    pub(crate) fn new(writer: W, compression: Option<Compression>) -> std::io::Result<Self> {
+        match compression {
+            None => Ok(Self::Plain(writer)),
+            #[cfg(feature = "compress-gzip")]
+            Some(Compression::Gzip) => Ok(Self::Gzip(flate2::write::GzEncoder::new(writer, flate2::Compression::default()))),
+            #[cfg(feature = "compress-xz")]
+            Some(Compression::Xz) => Ok(Self::Xz(xz2::write::XzEncoder::new(writer, 6))),
+            #[cfg(feature = "compress-zstd")]
+            Some(Compression::Zstd) => Ok(Self::Zstd(zstd::stream::write::Encoder::new(writer, zstd::DEFAULT_COMPRESSION_LEVEL)?)),
+        }
+    }
+
+    /// Writes any remaining compressed output (and the codec's trailer) to the wrapped writer.
+    ///
+    /// # Returns
+    /// The wrapped writer.
+    ///
+    /// # Errors
+    /// This function errors if we failed to compress or write the remaining output.
+    #[inline]
+    pub(crate) fn finish(self) -> std::io::Result<W> {
+        match self {
+            Self::Plain(writer) => Ok(writer),
+            #[cfg(feature = "compress-gzip")]
+            Self::Gzip(enc) => enc.finish(),
+            #[cfg(feature = "compress-xz")]
+            Self::Xz(enc) => enc.finish(),
+            #[cfg(feature = "compress-zstd")]
+            Self::Zstd(enc) => enc.finish(),
+        }
+    }
+}
+impl<W: Write> Write for Encoder<W> {
+    #[inline]
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        match self {
+            Self::Plain(writer) => writer.write(buf),
+            #[cfg(feature = "compress-gzip")]
+            Self::Gzip(enc) => enc.write(buf),
+            #[cfg(feature = "compress-xz")]
+            Self::Xz(enc) => enc.write(buf),
+            #[cfg(feature = "compress-zstd")]
+            Self::Zstd(enc) => enc.write(buf),
+        }
+    }
+
+    #[inline]
+    fn flush(&mut self) -> std::io::Result<()> {
+        match self {
+            Self::Plain(writer) => writer.flush(),
+            #[cfg(feature = "compress-gzip")]
+            Self::Gzip(enc) => enc.flush(),
+            #[cfg(feature = "compress-xz")]
+            Self::Xz(enc) => enc.flush(),
+            #[cfg(feature = "compress-zstd")]
+            Self::Zstd(enc) => enc.flush(),
+        }
+    }
+}
+
+
+
+/// Decompresses everything read through it with the given enabled codec (or not at all).
+///
+/// The codec is never guessed from the input, so that uncompressed formats whose output happens to
+/// look compressed are read as-is. If the codec fails to decompress the input, then the error is
+/// kept around so that it can be reported as such (see [`Decoder::take_err()`]).
+pub(crate) struct Decoder<R: Read> {
+    /// The codec that reads the input.
+    codec: Codec<R>,
+    /// The first error that the codec encountered, if any.
+    err:   Option<std::io::Error>,
+}
+impl<R: Read> Decoder<R> {
+    /// Constructor for the Decoder.
+    ///
+    /// # Arguments
+    /// - `reader`: The [`Read`]er to read the (possibly) compressed input from.
+    /// - `compression`: The [`Compression`] the input is compressed with (e.g., as given by the
+    ///   extension of its path), or [`None`] to read it as-is.
+    ///
+    /// # Returns
+    /// A new Decoder that initializes the codec once it's first read from.
+    #[inline]
+    pub(crate) fn new(reader: R, compression: Option<Compression>) -> Self { Self { codec: Codec::Init(Some(reader), compression), err: None } }
+
+    /// Takes the error that the codec encountered while decompressing, if any.
+    ///
+    /// Note that errors of uncompressed input are never kept, as they aren't decompression errors.
+    ///
+    /// # Returns
+    /// The first [`std::io::Error`] that the codec returned, or [`None`] if it never failed.
+    #[inline]
+    pub(crate) fn take_err(&mut self) -> Option<std::io::Error> { self.err.take() }
+}
+impl<R: Read> Read for Decoder<R> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let res: std::io::Result<usize> = self.codec.read(buf);
+        if let Err(err) = &res {
+            if self.err.is_none() && !matches!(self.codec, Codec::Plain(_)) && err.kind() != std::io::ErrorKind::Interrupted {
+                // Keep the original around, and pass a copy to the reader
+                self.err = Some(std::io::Error::new(err.kind(), err.to_string()));
+            }
+        }
+        res
+    }
+}
+
+/// The codec used by a [`Decoder`].
+enum Codec<R: Read> {
+    /// The codec hasn't been initialized yet. Carries the codec to use, if any.
+    Init(Option<R>, Option<Compression>),
+    /// The input is not compressed.
+    Plain(R),
+    /// The input is compressed with gzip (possibly in multiple members).
+    #[cfg(feature = "compress-gzip")]
+    Gzip(flate2::read::MultiGzDecoder<R>),
+    /// The input is compressed with xz (possibly in multiple streams).
+    #[cfg(feature = "compress-xz")]
+    Xz(xz2::read::XzDecoder<R>),
+    /// The input is compressed with Zstandard.
+    #[cfg(feature = "compress-zstd")]
+    Zstd(zstd::stream::read::Decoder<'static, std::io::BufReader<R>>),
+}
+impl<R: Read> Read for Codec<R> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        if let Self::Init(reader, compression) = self {
+            let Some(reader) = reader.take() else {
+                return Err(std::io::Error::other("Failed to initialize decompressor"));
+            };
+            let compression: Option<Compression> = *compression;
+            *self = match compression {
+                None => Self::Plain(reader),
+                #[cfg(feature = "compress-gzip")]
+                Some(Compression::Gzip) => Self::Gzip(flate2::read::MultiGzDecoder::new(reader)),
+                #[cfg(feature = "compress-xz")]
+                Some(Compression::Xz) => Self::Xz(xz2::read::XzDecoder::new_multi_decoder(reader)),
+                #[cfg(feature = "compress-zstd")]
+                Some(Compression::Zstd) => Self::Zstd(zstd::stream::read::Decoder::new(reader)?),
+            };
+        }
+
+        match self {
+            Self::Init(_, _) => unreachable!(),
+            Self::Plain(reader) => reader.read(buf),
+            #[cfg(feature = "compress-gzip")]
+            Self::Gzip(dec) => dec.read(buf),
+            #[cfg(feature = "compress-xz")]
+            Self::Xz(dec) => dec.read(buf),
+            #[cfg(feature = "compress-zstd")]
+            Self::Zstd(dec) => dec.read(buf),
+        }
+    }
+}
+
+
+
+/// Compresses the given buffer in one go.
+///
+/// # Arguments
+/// - `raw`: The buffer to compress.
+/// - `compression`: The [`Compression`] to compress with, or [`None`] to not compress.
+///
+/// # Returns
+/// The compressed buffer (or `raw` itself if `compression` is [`None`]).
+///
+/// # Errors
+/// This function errors if the codec failed to compress.
+#[cfg(feature = "async-tokio")]
+#[inline]
+pub(crate) fn compress(raw: Vec<u8>, compression: Option<Compression>) -> std::io::Result<Vec<u8>> {
+    if compression.is_none() {
+        return Ok(raw);
+    }
+    let mut enc: Encoder<Vec<u8>> = Encoder::new(Vec::with_capacity(raw.len() / 2), compression)?;
+    enc.write_all(&raw)?;
+    enc.finish()
+}
+
+/// Decompresses the given buffer in one go.
+///
+/// # Arguments
+/// - `raw`: The buffer to decompress.
+/// - `compression`: The [`Compression`] the buffer is compressed with, or [`None`] if it isn't
+///   compressed.
+///
+/// # Returns
+/// The decompressed buffer (or `raw` itself if `compression` is [`None`]).
+///
+/// # Errors
+/// This function errors if the given codec failed to decompress.
+#[cfg(any(feature = "async-tokio", feature = "sign-ed25519"))]
+#[inline]
+pub(crate) fn decompress(raw: Vec<u8>, compression: Option<Compression>) -> std::io::Result<Vec<u8>> {
+    if compression.is_none() {
+        return Ok(raw);
+    }
+    let mut res: Vec<u8> = Vec::new();
+    Decoder::new(raw.as_slice(), compression).read_to_end(&mut res)?;
+    Ok(res)
+}
@@ -0,0 +1,353 @@
+//  CSV.rs
+//    by Lut99
+//
+//  Created:
+//    16 Oct 2026, 21:16:12
+//  Last edited:
+//    17 Oct 2026, 10:06:27
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Implements [`serializer::Serializer`] and cohorts for [`csv`].
+//
+
+use std::error;
+use std::fmt::{Display, Formatter, Result as FResult};
+use std::io::{Read, Write};
+use std::marker::PhantomData;
+use std::string::FromUtf8Error;
+
+pub use csv::QuoteStyle;
+use serde::{Deserialize, Serialize};
+
+use crate::serializable::Serializable;
+use crate::serializer;
+
+
+/***** CONSTANTS *****/
+/// The number of bytes of serialized records that are collected before they're written to an
+/// `async` writer.
+#[cfg(feature = "async-tokio")]
+const CHUNK_LEN: usize = 64 * 1024;
+
+
+
+
+
+/***** ERRORS *****/
+/// Defines errors that occur when using the CSV [`Serializer`].
+#[derive(Debug)]
+pub enum Error {
+    /// Failed to write to the given writer.
+    Write { err: std::io::Error },
+    /// Failed to read from the given reader.
+    #[cfg(feature = "async-tokio")]
+    Read { err: std::io::Error },
+    /// Failed to flush the given writer.
+    #[cfg(feature = "async-tokio")]
+    Flush { err: std::io::Error },
+    /// The serialized CSV was not valid UTF-8, and so cannot be represented as a string.
+    NotUtf8 { err: FromUtf8Error },
+    /// Failed to serialize a record to CSV.
+    Serialize { err: csv::Error },
+    /// Failed to deserialize a record from CSV.
+    Deserialize { err: csv::Error },
+}
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FResult {
+        use Error::*;
+        match self {
+            Write { .. } => write!(f, "Failed to write to given writer"),
+            #[cfg(feature = "async-tokio")]
+            Read { .. } => write!(f, "Failed to read from given reader"),
+            #[cfg(feature = "async-tokio")]
+            Flush { .. } => write!(f, "Failed to flush the given writer"),
+            NotUtf8 { .. } => write!(f, "Serialized CSV is not valid UTF-8"),
+            Serialize { .. } => write!(f, "Failed to serialize to CSV"),
+            Deserialize { .. } => write!(f, "Failed to deserialize from CSV"),
+        }
+    }
+}
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        use Error::*;
+        match self {
+            Write { err } => Some(err),
+            #[cfg(feature = "async-tokio")]
+            Read { err } => Some(err),
+            #[cfg(feature = "async-tokio")]
+            Flush { err } => Some(err),
+            NotUtf8 { err } => Some(err),
+            Serialize { err } => Some(err),
+            Deserialize { err } => Some(err),
+        }
+    }
+}
+
+
+
+
+
+/***** LIBRARY *****/
+/// Abstracts over collections of records that can be (de)serialized as a CSV table.
+///
+/// This is implemented for [`Vec`]s, but can also be implemented for your own types (e.g., a
+/// newtype around a [`Vec`] that you want to serialize with custom [`Options`]).
+pub trait Records {
+    /// The type of a single record, i.e., a single line in the table.
+    type Record;
+
+    /// Returns the records in this collection.
+    fn records(&self) -> &[Self::Record];
+
+    /// Builds the collection from the given records.
+    ///
+    /// # Arguments
+    /// - `records`: The deserialized records, in the order they appeared in the table.
+    fn from_records(records: Vec<Self::Record>) -> Self;
+}
+impl<R> Records for Vec<R> {
+    type Record = R;
+
+    #[inline]
+    fn records(&self) -> &[Self::Record] { self }
+
+    #[inline]
+    fn from_records(records: Vec<Self::Record>) -> Self { records }
+}
+
+
+
+/// Configures the CSV [`Serializer`].
+///
+/// All items have defaults, so implementations only have to override what they need.
+///
+/// # Examples
+/// ```rust
+/// use serde::{Deserialize, Serialize};
+/// use serializable::csv::{Options, QuoteStyle, Records, Serializer};
+/// use serializable::Serializable;
+///
+/// #[derive(Debug, Deserialize, Eq, PartialEq, Serialize)]
+/// struct Row {
+///     name: String,
+///     age: u32,
+/// }
+///
+/// struct Tsv;
+/// impl Options for Tsv {
+///     const DELIMITER: u8 = b'\t';
+///     const HAS_HEADERS: bool = false;
+///     const QUOTE_STYLE: QuoteStyle = QuoteStyle::NonNumeric;
+/// }
+///
+/// struct People(Vec<Row>);
+/// impl Records for People {
+///     type Record = Row;
+///
+///     fn records(&self) -> &[Row] { &self.0 }
+///
+///     fn from_records(records: Vec<Row>) -> Self { Self(records) }
+/// }
+/// impl Serializable<Serializer<People, Tsv>> for People {}
+///
+/// assert_eq!(
+///     People(vec![Row { name: "Alice".into(), age: 42 }, Row { name: "Bob".into(), age: 7 }]).to_string().unwrap(),
+///     "\"Alice\"\t42\n\"Bob\"\t7\n"
+/// );
+/// ```
+pub trait Options {
+    /// The field delimiter.
+    const DELIMITER: u8 = b',';
+    /// Whether the first line is a header with the field names. If so, it is written when
+    /// serializing and skipped when deserializing.
+    const HAS_HEADERS: bool = true;
+    /// The quote character.
+    const QUOTE: u8 = b'"';
+    /// When to quote fields.
+    const QUOTE_STYLE: QuoteStyle = QuoteStyle::Necessary;
+}
+
+/// The default [`Options`] for the CSV [`Serializer`].
+#[derive(Clone, Copy, Debug)]
+pub struct DefaultOptions;
+impl Options for DefaultOptions {}
+
+
+
+/// Implements a [`serializer::Serializer`] for [`csv`].
+///
+/// Note that, unlike other serializers, this serializer targets collections of (flat) records,
+/// i.e., anything implementing [`Records`]. Because of Rust's orphan rules, you cannot implement
+/// [`Serializable`] for a `Vec<T>` yourself; instead, every `Vec<T>` already implements it for the
+/// CSV serializer with its [`DefaultOptions`]. To use custom [`Options`] (e.g., to change the
+/// delimiter or quoting), implement [`Records`] for a type of your own.
+///
+/// Writing happens record-by-record, so [`serializer::Serializer::to_writer()`] never holds the
+/// full serialized table in memory. Similarly, `SerializerAsync::to_writer_async()` (under the
+/// `async-tokio`-feature) only holds the records serialized since its last write, which it does
+/// every 64 KiB or so. CSV has no pretty version available, so
+/// [`serializer::Serializer::to_string_pretty()`] and [`serializer::Serializer::to_writer_pretty()`]
+/// are simply aliases for [`serializer::Serializer::to_string()`] and
+/// [`serializer::Serializer::to_writer()`], respectively.
+///
+/// # Examples
+/// ```rust
+/// use serde::{Deserialize, Serialize};
+/// use serializable::csv::Serializer;
+/// use serializable::Serializable;
+///
+/// #[derive(Debug, Deserialize, Eq, PartialEq, Serialize)]
+/// struct Row {
+///     name: String,
+///     age: u32,
+/// }
+///
+/// // Note: no need to implement `Serializable`, `Vec<Row>` already does
+/// assert_eq!(
+///     vec![Row { name: "Alice".into(), age: 42 }, Row { name: "Bob".into(), age: 7 }].to_string().unwrap(),
+///     "name,age\nAlice,42\nBob,7\n"
+/// );
+///
+/// assert_eq!(Vec::<Row>::from_str("name,age\nCharlie,33\n").unwrap(), vec![Row { name: "Charlie".into(), age: 33 }]);
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct Serializer<T, O = DefaultOptions>(PhantomData<(T, O)>);
+
+/// Marks the CSV [`Serializer`] with its default options as a [`Backend`](crate::Backend).
+///
+/// Use it to select this backend in [`SerializableAs`](crate::SerializableAs) (e.g.,
+/// `value.to_string_as::<Csv>()`).
+#[derive(Clone, Copy, Debug)]
+pub struct Csv;
+impl serializer::Backend for Csv {
+    type Serializer<T> = Serializer<T>;
+}
+
+impl<T, O: Options> Serializer<T, O> {
+    /// Builds a [`csv::Writer`] that writes to the given writer with the configured [`Options`].
+    ///
+    /// # Arguments
+    /// - `writer`: The [`Write`]r to write the serialized records to.
+    /// - `headers`: Whether to write the header (if [`Options::HAS_HEADERS`] is set at all).
+    ///
+    /// # Returns
+    /// A new [`csv::Writer`].
+    #[inline]
+    fn csv_writer<W: Write>(writer: W, headers: bool) -> csv::Writer<W> {
+        csv::WriterBuilder::new()
+            .delimiter(O::DELIMITER)
+            .has_headers(O::HAS_HEADERS && headers)
+            .quote(O::QUOTE)
+            .quote_style(O::QUOTE_STYLE)
+            .from_writer(writer)
+    }
+}
+
+impl<T: Records, O: Options> serializer::Serializer for Serializer<T, O>
+where
+    T::Record: for<'de> Deserialize<'de> + Serialize,
+{
+    type Error = Error;
+    type Target = T;
+
+    #[inline]
+    fn to_string(value: &Self::Target) -> Result<String, Self::Error> {
+        let raw: Vec<u8> = Self::to_bytes(value)?;
+        String::from_utf8(raw).map_err(|err| Error::NotUtf8 { err })
+    }
+
+    #[inline]
+    fn to_writer(value: &Self::Target, writer: impl Write) -> Result<(), Self::Error> {
+        let mut writer: csv::Writer<_> = Self::csv_writer(writer, true);
+
+        // Write the records one-by-one
+        for record in value.records() {
+            if let Err(err) = writer.serialize(record) {
+                return Err(Error::Serialize { err });
+            }
+        }
+        writer.flush().map_err(|err| Error::Write { err })
+    }
+
+    #[inline]
+    fn from_str(raw: impl AsRef<str>) -> Result<Self::Target, Self::Error> { Self::from_reader(raw.as_ref().as_bytes()) }
+
+    #[inline]
+    fn from_reader(reader: impl Read) -> Result<Self::Target, Self::Error> {
+        match csv::ReaderBuilder::new()
+            .delimiter(O::DELIMITER)
+            .has_headers(O::HAS_HEADERS)
+            .quote(O::QUOTE)
+            .from_reader(reader)
+            .deserialize()
+            .collect::<Result<Vec<T::Record>, csv::Error>>()
+        {
+            Ok(records) => Ok(T::from_records(records)),
+            Err(err) => Err(Error::Deserialize { err }),
+        }
+    }
+}
+
+#[cfg(feature = "async-tokio")]
+#[async_trait::async_trait]
+impl<T: Send + Sync + Records, O: Send + Sync + Options> serializer::SerializerAsync for Serializer<T, O>
+where
+    T::Record: for<'de> Deserialize<'de> + Serialize,
+{
+    #[inline]
+    async fn to_writer_async(value: &Self::Target, mut writer: impl Send + std::marker::Unpin + tokio::io::AsyncWrite) -> Result<(), Self::Error> {
+        use tokio::io::AsyncWriteExt;
+
+        // Serialize the records to a buffer in chunks, writing each before serializing the next
+        let mut buf: Vec<u8> = Vec::with_capacity(CHUNK_LEN);
+        let len: usize = value.records().len();
+        let mut i: usize = 0;
+        while i < len {
+            // Only the first chunk gets a header
+            // NOTE: Records are only borrowed in between writes, as they needn't be `Sync`
+            let mut csv: csv::Writer<Vec<u8>> = Self::csv_writer(buf, i == 0);
+            for record in &value.records()[i..] {
+                if let Err(err) = csv.serialize(record) {
+                    return Err(Error::Serialize { err });
+                }
+                i += 1;
+                if csv.get_ref().len() >= CHUNK_LEN {
+                    break;
+                }
+            }
+            buf = match csv.into_inner() {
+                Ok(buf) => buf,
+                Err(err) => return Err(Error::Write { err: err.into_error() }),
+            };
+
+            // Write it
+            if let Err(err) = writer.write_all(&buf).await {
+                return Err(Error::Write { err });
+            }
+            buf.clear();
+        }
+        // ...making sure its flushed
+        match writer.flush().await {
+            Ok(_) => Ok(()),
+            Err(err) => Err(Error::Flush { err }),
+        }
+    }
+
+    #[inline]
+    async fn from_reader_async(mut reader: impl Send + std::marker::Unpin + tokio::io::AsyncRead) -> Result<T, Self::Error> {
+        use tokio::io::AsyncReadExt;
+
+        // Read the entire buffer first
+        let mut raw: Vec<u8> = Vec::new();
+        if let Err(err) = reader.read_to_end(&mut raw).await {
+            return Err(Error::Read { err });
+        }
+
+        // Then deserialize as bytes
+        <Self as serializer::Serializer>::from_bytes(&raw)
+    }
+}
+
+impl<R: for<'de> Deserialize<'de> + Serialize> Serializable<Serializer<Vec<R>>> for Vec<R> {}
@@ -0,0 +1,348 @@
+//  DOTENV.rs
+//    by Lut99
+//
+//  Created:
+//    16 Oct 2026, 21:21:25
+//  Last edited:
+//    17 Oct 2026, 10:14:52
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Implements [`serializer::Serializer`] and cohorts for `.env`-files.
+//
+
+use std::error;
+use std::fmt::{Display, Formatter, Result as FResult, Write as _};
+use std::io::{Read, Write};
+use std::marker::PhantomData;
+
+use serde::{Deserialize, Serialize};
+
+pub use crate::flat::Error as FlatError;
+use crate::flat::{check_key, quote, to_entries, unquote, Node, NodeDeserializer};
+use crate::serializer;
+
+
+/***** CONSTANTS *****/
+/// The characters that start a comment in a `.env`-file.
+const COMMENTS: &[char] = &['#'];
+
+/// The characters that may not occur in the keys of a `.env`-file.
+const INVALID_KEY: &[char] = &['=', '[', ']', '#', '\n', '\r', ' ', '\t'];
+
+
+
+
+
+/***** ERRORS *****/
+/// Defines errors that occur when using the `.env` [`Serializer`].
+#[derive(Debug)]
+pub enum Error {
+    /// Failed to write to the given writer.
+    Write { err: std::io::Error },
+    /// Failed to read from the given reader.
+    Read { err: std::io::Error },
+    /// Failed to flush the given writer.
+    #[cfg(feature = "async-tokio")]
+    Flush { err: std::io::Error },
+    /// Failed to serialize the object to a `.env`-file.
+    Serialize { err: FlatError },
+    /// Failed to deserialize the object from a `.env`-file.
+    Deserialize { err: FlatError },
+}
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FResult {
+        use Error::*;
+        match self {
+            Write { .. } => write!(f, "Failed to write to given writer"),
+            Read { .. } => write!(f, "Failed to read from given reader"),
+            #[cfg(feature = "async-tokio")]
+            Flush { .. } => write!(f, "Failed to flush the given writer"),
+            Serialize { .. } => write!(f, "Failed to serialize to .env"),
+            Deserialize { .. } => write!(f, "Failed to deserialize from .env"),
+        }
+    }
+}
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        use Error::*;
+        match self {
+            Write { err } => Some(err),
+            Read { err } => Some(err),
+            #[cfg(feature = "async-tokio")]
+            Flush { err } => Some(err),
+            Serialize { err } => Some(err),
+            Deserialize { err } => Some(err),
+        }
+    }
+}
+
+
+
+
+
+/***** HELPER FUNCTIONS *****/
+/// Writes serialized entries as a `.env`-file.
+///
+/// Keys are written in uppercase, and the keys of nested structs (or maps) are prefixed with the
+/// name of their field (e.g., `DATABASE_HOST`). Values containing `$` are quoted such that they're
+/// not expanded as variables.
+///
+/// # Arguments
+/// - `entries`: The serialized entries to write.
+/// - `pretty`: Whether to put empty lines around the keys of nested structs.
+///
+/// # Returns
+/// The serialized `.env`-file.
+///
+/// # Errors
+/// This function errors if any of the keys cannot be written.
+fn write(entries: &[(String, Node)], pretty: bool) -> Result<String, FlatError> {
+    let mut res: String = String::new();
+    let mut prev_table: bool = false;
+    for (key, node) in entries {
+        let key: String = key.to_ascii_uppercase();
+        match node {
+            Node::Value(value) => {
+                if pretty && prev_table {
+                    res.push('\n');
+                }
+                check_key(&key, INVALID_KEY)?;
+                writeln!(res, "{key}={}", quote(value, COMMENTS, true)).unwrap();
+                prev_table = false;
+            },
+            Node::Table(table) => {
+                if pretty && !res.is_empty() {
+                    res.push('\n');
+                }
+                for (field, node) in table {
+                    // NOTE: Serialization never nests tables more than one level deep
+                    if let Node::Value(value) = node {
+                        let key: String = format!("{key}_{}", field.to_ascii_uppercase());
+                        check_key(&key, INVALID_KEY)?;
+                        writeln!(res, "{key}={}", quote(value, COMMENTS, true)).unwrap();
+                    }
+                }
+                prev_table = true;
+            },
+        }
+    }
+    Ok(res)
+}
+
+/// Parses a `.env`-file to entries that can be deserialized.
+///
+/// # Arguments
+/// - `raw`: The `.env`-file to parse.
+///
+/// # Returns
+/// The variables defined in the file, in order.
+///
+/// # Errors
+/// This function errors if the input is not a valid `.env`-file.
+fn parse(raw: &str) -> Result<Vec<(String, Node)>, FlatError> {
+    let mut entries: Vec<(String, Node)> = Vec::new();
+    for (i, line) in raw.lines().enumerate() {
+        let line: &str = line.trim();
+        if line.is_empty() || line.starts_with(COMMENTS) {
+            continue;
+        }
+
+        // Parse the line as a key/value pair, optionally prefixed with `export`
+        let line: &str = line.strip_prefix("export ").unwrap_or(line);
+        let (key, value): (&str, &str) = match line.split_once('=') {
+            Some((key, value)) if !key.trim().is_empty() && !key.trim().contains(char::is_whitespace) => (key.trim(), value),
+            _ => return Err(FlatError::syntax(i + 1, "expected a `KEY=value` pair")),
+        };
+        entries.push((key.into(), Node::Value(unquote(value, COMMENTS, i + 1)?)));
+    }
+    Ok(entries)
+}
+
+
+
+
+
+/***** LIBRARY *****/
+/// Implements a [`serializer::Serializer`] for `.env`-files (as read by, e.g., `docker compose`).
+///
+/// Top-level fields of the serialized struct (or map) become variables named after the field in
+/// uppercase. Fields that are structs (or maps) themselves are flattened, prefixing their keys with
+/// the name of the field (e.g., `DATABASE_HOST`). Deeper nesting, sequences and enums with data
+/// cannot be represented, and result in an error. Note that keys of maps are uppercased too, so
+/// their original casing is lost, and that keys containing `=`, `[`, `]`, `#` or whitespace cannot
+/// be written either. Values containing `$` are single-quoted, so that they're not expanded as
+/// variables.
+///
+/// When deserializing, variables are matched to fields case-insensitively, and every value is
+/// parsed to the type of the field it is deserialized to. Lines starting with `#` are ignored as
+/// comments, and variables may optionally be prefixed with `export`.
+///
+/// # Examples
+/// ```rust
+/// use serde::{Deserialize, Serialize};
+/// use serializable::dotenv::Serializer;
+/// use serializable::Serializable;
+///
+/// #[derive(Debug, Deserialize, Eq, PartialEq, Serialize)]
+/// struct Database {
+///     host: String,
+///     port: u16,
+/// }
+///
+/// #[derive(Debug, Deserialize, Eq, PartialEq, Serialize)]
+/// struct Config {
+///     name: String,
+///     database: Database,
+/// }
+/// impl Serializable<Serializer<Config>> for Config {}
+///
+/// let config = Config { name: "my app".into(), database: Database { host: "localhost".into(), port: 5432 } };
+/// assert_eq!(config.to_string().unwrap(), "NAME=my app\nDATABASE_HOST=localhost\nDATABASE_PORT=5432\n");
+/// assert_eq!(config.to_string_pretty().unwrap(), "NAME=my app\n\nDATABASE_HOST=localhost\nDATABASE_PORT=5432\n");
+///
+/// assert_eq!(
+///     Config::from_str("# Generated\nexport NAME=\"my app\"\nDATABASE_HOST=localhost # the host\nDATABASE_PORT=5432\n").unwrap(),
+///     config
+/// );
+///
+/// // Variables are not expanded
+/// let config = Config { name: "$HOME".into(), database: Database { host: "localhost".into(), port: 5432 } };
+/// assert!(config.to_string().unwrap().starts_with("NAME='$HOME'\n"));
+/// assert_eq!(Config::from_str(&config.to_string().unwrap()).unwrap(), config);
+///
+/// // Keys with special characters cannot be written
+/// #[derive(Deserialize, Serialize)]
+/// struct Env {
+///     vars: std::collections::HashMap<String, String>,
+/// }
+/// impl Serializable<Serializer<Env>> for Env {}
+///
+/// assert!(Env { vars: [("A=B".into(), "c".into())].into() }.to_string().is_err());
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct Serializer<T>(PhantomData<T>);
+
+/// Marks the `.env` [`Serializer`] as a [`Backend`](crate::Backend).
+///
+/// Use it to select this backend in [`SerializableAs`](crate::SerializableAs) (e.g.,
+/// `value.to_string_as::<Dotenv>()`).
+#[derive(Clone, Copy, Debug)]
+pub struct Dotenv;
+impl serializer::Backend for Dotenv {
+    type Serializer<T> = Serializer<T>;
+}
+
+impl<T: for<'de> Deserialize<'de> + Serialize> serializer::Serializer for Serializer<T> {
+    type Error = Error;
+    type Target = T;
+
+    #[inline]
+    fn to_string(value: &Self::Target) -> Result<String, Self::Error> {
+        to_entries(value).and_then(|entries| write(&entries, false)).map_err(|err| Error::Serialize { err })
+    }
+
+    #[inline]
+    fn to_string_pretty(value: &Self::Target) -> Result<String, Self::Error> {
+        to_entries(value).and_then(|entries| write(&entries, true)).map_err(|err| Error::Serialize { err })
+    }
+
+    #[inline]
+    fn to_writer(value: &Self::Target, mut writer: impl Write) -> Result<(), Self::Error> {
+        // Write to string first
+        let raw: String = Self::to_string(value)?;
+
+        // Then write to the writer
+        match writer.write_all(raw.as_bytes()) {
+            Ok(_) => Ok(()),
+            Err(err) => Err(Error::Write { err }),
+        }
+    }
+
+    #[inline]
+    fn to_writer_pretty(value: &Self::Target, mut writer: impl Write) -> Result<(), Self::Error> {
+        // Write to string first
+        let raw: String = Self::to_string_pretty(value)?;
+
+        // Then write to the writer
+        match writer.write_all(raw.as_bytes()) {
+            Ok(_) => Ok(()),
+            Err(err) => Err(Error::Write { err }),
+        }
+    }
+
+    #[inline]
+    fn from_str(raw: impl AsRef<str>) -> Result<Self::Target, Self::Error> {
+        parse(raw.as_ref()).and_then(|entries| T::deserialize(NodeDeserializer::new(entries, true))).map_err(|err| Error::Deserialize { err })
+    }
+
+    #[inline]
+    fn from_reader(mut reader: impl Read) -> Result<Self::Target, Self::Error> {
+        // Simply read the whole reader
+        let mut raw: String = String::new();
+        if let Err(err) = reader.read_to_string(&mut raw) {
+            return Err(Error::Read { err });
+        }
+
+        // Now deserialize using the string edition
+        Self::from_str(&raw)
+    }
+}
+
+#[cfg(feature = "async-tokio")]
+#[async_trait::async_trait]
+impl<T: Send + Sync + for<'de> Deserialize<'de> + Serialize> serializer::SerializerAsync for Serializer<T> {
+    #[inline]
+    async fn to_writer_async(value: &Self::Target, mut writer: impl Send + std::marker::Unpin + tokio::io::AsyncWrite) -> Result<(), Self::Error> {
+        use tokio::io::AsyncWriteExt;
+
+        // Serialize ourselves to a string first
+        let raw: String = <Self as serializer::Serializer>::to_string(value)?;
+
+        // Now write to the writer
+        if let Err(err) = writer.write_all(raw.as_bytes()).await {
+            return Err(Error::Write { err });
+        }
+        // ...making sure its flushed
+        match writer.flush().await {
+            Ok(_) => Ok(()),
+            Err(err) => Err(Error::Flush { err }),
+        }
+    }
+
+    #[inline]
+    async fn to_writer_pretty_async(
+        value: &Self::Target,
+        mut writer: impl Send + std::marker::Unpin + tokio::io::AsyncWrite,
+    ) -> Result<(), Self::Error> {
+        use tokio::io::AsyncWriteExt;
+
+        // Serialize ourselves to a string first
+        let raw: String = <Self as serializer::Serializer>::to_string_pretty(value)?;
+
+        // Now write to the writer
+        if let Err(err) = writer.write_all(raw.as_bytes()).await {
+            return Err(Error::Write { err });
+        }
+        // ...making sure its flushed
+        match writer.flush().await {
+            Ok(_) => Ok(()),
+            Err(err) => Err(Error::Flush { err }),
+        }
+    }
+
+    #[inline]
+    async fn from_reader_async(mut reader: impl Send + std::marker::Unpin + tokio::io::AsyncRead) -> Result<T, Self::Error> {
+        use tokio::io::AsyncReadExt;
+
+        // Read the entire buffer first
+        let mut raw: String = String::new();
+        if let Err(err) = reader.read_to_string(&mut raw).await {
+            return Err(Error::Read { err });
+        }
+
+        // Then deserialize as string
+        <Self as serializer::Serializer>::from_str(&raw)
+    }
+}
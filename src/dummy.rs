@@ -4,7 +4,7 @@
 //  Created:
 //    29 Oct 2023, 11:59:19
 //  Last edited:
-//    30 Oct 2023, 12:30:22
+//    16 Oct 2026, 21:57:14
 //  Auto updated?
 //    Yes
 //
@@ -93,6 +93,16 @@ impl error::Error for Error {
 #[derive(Clone, Copy, Debug)]
 pub struct Serializer<T>(PhantomData<T>);
 
+/// Marks the dummy [`Serializer`] as a [`Backend`](crate::Backend).
+///
+/// Use it to select this backend in [`SerializableAs`](crate::SerializableAs) (e.g.,
+/// `value.to_string_as::<Dummy>()`).
+#[derive(Clone, Copy, Debug)]
+pub struct Dummy;
+impl serializer::Backend for Dummy {
+    type Serializer<T> = Serializer<T>;
+}
+
 impl<T: Default> serializer::Serializer for Serializer<T> {
     type Error = Error;
     type Target = T;
@@ -0,0 +1,614 @@
+//  ENCRYPTED.rs
+//    by Lut99
+//
+//  Created:
+//    16 Oct 2026, 23:52:40
+//  Last edited:
+//    17 Oct 2026, 09:41:48
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Implements the [`Encrypted`]-[`Serializer`](serializer::Serializer),
+//!   which seals the output of another serializer with an authenticated
+//!   cipher using keys from a caller-supplied [`KeyProvider`].
+//
+
+use std::error;
+use std::fmt::{Debug, Display, Formatter, Result as FResult};
+use std::io::{Read, Write};
+use std::marker::PhantomData;
+
+#[cfg(feature = "encrypt-aes-gcm")]
+use aes_gcm::aead;
+#[cfg(not(feature = "encrypt-aes-gcm"))]
+use chacha20poly1305::aead;
+
+use crate::location::PathSegment;
+use crate::pretty::PrettyOptions;
+use crate::serializer;
+
+
+/***** CONSTANTS *****/
+/// The magic bytes with which every encrypted output starts.
+pub const MAGIC: [u8; 4] = *b"SENC";
+
+/// The version of the header that is written.
+const VERSION: u8 = 1;
+
+/// The length of the nonces of all supported ciphers, in bytes.
+const NONCE_LEN: usize = 12;
+
+
+
+
+
+/***** ERRORS *****/
+/// Defines errors that occur when using the [`Encrypted`] serializer.
+///
+/// Note that failing to decrypt is reported as [`Error::Decrypt`], which is separate from the
+/// wrapped serializer failing to parse the decrypted plaintext ([`Error::Deserialize`]).
+///
+/// When deserializing through [`Serializable`](crate::Serializable), these errors arrive nested in
+/// the `Deserialize*`-variant of [`crate::Error`] for the input that failed (e.g.,
+/// `Error::DeserializeFile { err: encrypted::Error::Decrypt { .. }, .. }`), just like the errors
+/// of any other backend. This is on purpose: [`crate::Error`] is generic over the backend's error
+/// and doesn't know about encryption, and the outer variant still tells which input failed to
+/// decrypt (e.g., the path of the file). Use [`crate::Error::is_decrypt()`] to check for a
+/// decryption failure without spelling out the nesting.
+#[derive(Debug)]
+pub enum Error<E> {
+    /// Failed to write to the given writer.
+    Write { err: std::io::Error },
+    /// Failed to read from the given reader.
+    Read { err: std::io::Error },
+    /// Failed to flush the given writer.
+    #[cfg(feature = "async-tokio")]
+    Flush { err: std::io::Error },
+    /// Encrypted output is binary, and cannot be represented as a string.
+    NotText,
+    /// The wrapped serializer failed to serialize the value.
+    Serialize { err: E },
+    /// The wrapped serializer failed to deserialize the decrypted plaintext.
+    Deserialize { err: E },
+    /// The key provider failed to provide a key to encrypt with.
+    EncryptionKey { err: Box<dyn error::Error + Send + Sync> },
+    /// The key provider failed to provide the key to decrypt with.
+    DecryptionKey { id: String, err: Box<dyn error::Error + Send + Sync> },
+    /// The key provider doesn't know the key with which the input was encrypted.
+    UnknownKey { id: String },
+    /// The ID of the key to encrypt with doesn't fit in the header.
+    KeyIdTooLong { id: String },
+    /// Failed to encrypt the serialized value.
+    Encrypt { cipher: Cipher },
+    /// The input does not start with the [`MAGIC`] bytes.
+    NotEncrypted,
+    /// The input's header has a version that this crate doesn't know.
+    UnsupportedVersion { version: u8 },
+    /// The input was encrypted with a cipher that this crate doesn't know (or whose feature is
+    /// disabled).
+    UnknownCipher { id: u8 },
+    /// The input ended before its header did.
+    Truncated,
+    /// Failed to decrypt the input, i.e., the key is wrong or the input was tampered with.
+    Decrypt { cipher: Cipher, id: String },
+}
+impl<E> Display for Error<E> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FResult {
+        use Error::*;
+        match self {
+            Write { .. } => write!(f, "Failed to write to given writer"),
+            Read { .. } => write!(f, "Failed to read from given reader"),
+            #[cfg(feature = "async-tokio")]
+            Flush { .. } => write!(f, "Failed to flush the given writer"),
+            NotText => write!(f, "Encrypted output is binary and cannot be serialized to or deserialized from a string"),
+            Serialize { .. } => write!(f, "Failed to serialize plaintext"),
+            Deserialize { .. } => write!(f, "Failed to deserialize decrypted plaintext"),
+            EncryptionKey { .. } => write!(f, "Failed to get key to encrypt with"),
+            DecryptionKey { id, .. } => write!(f, "Failed to get key '{id}' to decrypt with"),
+            UnknownKey { id } => write!(f, "Unknown key '{id}'"),
+            KeyIdTooLong { id } => write!(f, "Key ID '{id}' is longer than 255 bytes"),
+            Encrypt { cipher } => write!(f, "Failed to encrypt with {cipher}"),
+            NotEncrypted => write!(f, "Input is not encrypted (missing header)"),
+            UnsupportedVersion { version } => write!(f, "Unsupported encryption header version {version}"),
+            UnknownCipher { id } => write!(f, "Unknown (or disabled) cipher with ID {id}"),
+            Truncated => write!(f, "Encryption header is truncated"),
+            Decrypt { cipher, id } => write!(f, "Failed to decrypt with {cipher} key '{id}' (wrong key, or input was tampered with)"),
+        }
+    }
+}
+impl<E: 'static + error::Error> error::Error for Error<E> {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        use Error::*;
+        match self {
+            Write { err } => Some(err),
+            Read { err } => Some(err),
+            #[cfg(feature = "async-tokio")]
+            Flush { err } => Some(err),
+            NotText => None,
+            Serialize { err } => Some(err),
+            Deserialize { err } => Some(err),
+            EncryptionKey { err } => Some(&**err),
+            DecryptionKey { err, .. } => Some(&**err),
+            UnknownKey { .. } => None,
+            KeyIdTooLong { .. } => None,
+            Encrypt { .. } => None,
+            NotEncrypted => None,
+            UnsupportedVersion { .. } => None,
+            UnknownCipher { .. } => None,
+            Truncated => None,
+            Decrypt { .. } => None,
+        }
+    }
+}
+
+impl<E> crate::Error<Error<E>> {
+    /// Returns whether this error was caused by failing to decrypt the input.
+    ///
+    /// This is the case if it's one of the `Deserialize*`-variants that wraps an
+    /// [`Error::Decrypt`], i.e., the key is wrong or the input was tampered with.
+    ///
+    /// # Returns
+    /// True if the input failed to decrypt, or false otherwise.
+    #[inline]
+    pub fn is_decrypt(&self) -> bool {
+        use crate::Error::*;
+        matches!(
+            self,
+            DeserializeString { err: Error::Decrypt { .. }, .. }
+                | DeserializeBytes { err: Error::Decrypt { .. }, .. }
+                | DeserializeReader { err: Error::Decrypt { .. }, .. }
+                | DeserializeFile { err: Error::Decrypt { .. }, .. }
+        )
+    }
+}
+
+
+
+
+
+/***** HELPERS *****/
+/// Encrypts the given plaintext with the given cipher.
+///
+/// # Arguments
+/// - `key`: The key to encrypt with.
+/// - `header`: The header written so far. The nonce and ciphertext are appended to it, and all
+///   of it is authenticated.
+/// - `plaintext`: The plaintext to encrypt.
+///
+/// # Errors
+/// This function errors if the cipher failed to encrypt.
+fn seal_with<A: aead::Aead + aead::KeyInit>(key: &[u8; 32], header: &mut Vec<u8>, plaintext: &[u8]) -> Result<(), aead::Error> {
+    let cipher: A = A::new_from_slice(key).map_err(|_| aead::Error)?;
+    let nonce: aead::Nonce<A> = A::generate_nonce(&mut aead::OsRng);
+    header.extend_from_slice(&nonce);
+    let ciphertext: Vec<u8> = cipher.encrypt(&nonce, aead::Payload { msg: plaintext, aad: header })?;
+    header.extend(ciphertext);
+    Ok(())
+}
+
+/// Decrypts the given ciphertext with the given cipher.
+///
+/// # Arguments
+/// - `key`: The key to decrypt with.
+/// - `header`: The header of the input, including its nonce.
+/// - `ciphertext`: The ciphertext (and tag) to decrypt.
+///
+/// # Returns
+/// The decrypted plaintext.
+///
+/// # Errors
+/// This function errors if the ciphertext did not authenticate with the given key and header.
+fn open_with<A: aead::Aead + aead::KeyInit>(key: &[u8; 32], header: &[u8], ciphertext: &[u8]) -> Result<Vec<u8>, aead::Error> {
+    let cipher: A = A::new_from_slice(key).map_err(|_| aead::Error)?;
+    let nonce: &aead::Nonce<A> = aead::Nonce::<A>::from_slice(&header[header.len() - NONCE_LEN..]);
+    cipher.decrypt(nonce, aead::Payload { msg: ciphertext, aad: header })
+}
+
+
+
+
+
+/***** LIBRARY *****/
+/// Selects one of the enabled authenticated ciphers.
+///
+/// Only ciphers whose feature is enabled are available as variants.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Cipher {
+    /// [AES-256](https://csrc.nist.gov/pubs/fips/197/final) in
+    /// [GCM](https://csrc.nist.gov/pubs/sp/800/38/d/final)-mode, using
+    /// [`aes-gcm`](https://github.com/RustCrypto/AEADs).
+    #[cfg(feature = "encrypt-aes-gcm")]
+    Aes256Gcm,
+    /// [ChaCha20-Poly1305](https://www.rfc-editor.org/rfc/rfc8439), using
+    /// [`chacha20poly1305`](https://github.com/RustCrypto/AEADs).
+    #[cfg(feature = "encrypt-chacha20poly1305")]
+    ChaCha20Poly1305,
+}
+impl Cipher {
+    /// All ciphers that are enabled in this build.
+    pub const ALL: &'static [Self] = &[
+        #[cfg(feature = "encrypt-aes-gcm")]
+        Self::Aes256Gcm,
+        #[cfg(feature = "encrypt-chacha20poly1305")]
+        Self::ChaCha20Poly1305,
+    ];
+
+
+
+    /// Returns the identifier of this cipher in the header of encrypted output.
+    ///
+    /// # Returns
+    /// A byte that is unique to this cipher.
+    #[inline]
+    pub const fn id(&self) -> u8 {
+        match *self {
+            #[cfg(feature = "encrypt-aes-gcm")]
+            Self::Aes256Gcm => 1,
+            #[cfg(feature = "encrypt-chacha20poly1305")]
+            Self::ChaCha20Poly1305 => 2,
+        }
+    }
+
+    /// Returns the human-readable name of this cipher.
+    ///
+    /// # Returns
+    /// The name of the cipher (e.g., `AES-256-GCM`).
+    #[inline]
+    pub const fn name(&self) -> &'static str {
+        match *self {
+            #[cfg(feature = "encrypt-aes-gcm")]
+            Self::Aes256Gcm => "AES-256-GCM",
+            #[cfg(feature = "encrypt-chacha20poly1305")]
+            Self::ChaCha20Poly1305 => "ChaCha20-Poly1305",
+        }
+    }
+
+    /// Finds the enabled cipher with the given identifier.
+    ///
+    /// # Arguments
+    /// - `id`: The identifier as returned by [`Cipher::id()`].
+    ///
+    /// # Returns
+    /// The matching [`Cipher`], or [`None`] if no enabled cipher has this identifier.
+    #[inline]
+    pub fn from_id(id: u8) -> Option<Self> { Self::ALL.iter().find(|cipher| cipher.id() == id).copied() }
+}
+impl Display for Cipher {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FResult { f.write_str(self.name()) }
+}
+
+
+
+/// Defines a 256-bit key together with the ID under which it's stored in encrypted output.
+///
+/// Note that its [`Debug`]-implementation doesn't show the key material.
+#[derive(Clone, Eq, PartialEq)]
+pub struct Key {
+    /// The ID of the key (e.g., a version or a fingerprint), which may be at most 255 bytes.
+    pub id: String,
+    /// The key material itself.
+    pub material: [u8; 32],
+}
+impl Debug for Key {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FResult { f.debug_struct("Key").field("id", &self.id).field("material", &"<redacted>").finish() }
+}
+
+
+
+/// Provides the keys with which the [`Encrypted`] serializer encrypts and decrypts.
+///
+/// Like [`Serializer`](serializer::Serializer)s, providers are types without state; implement it
+/// on a marker type that fetches keys from wherever they're stored (e.g., the environment, a
+/// keyring or a key management service).
+pub trait KeyProvider {
+    /// The cipher with which new output is encrypted.
+    const CIPHER: Cipher;
+
+    /// Returns the key with which new output is encrypted.
+    ///
+    /// # Returns
+    /// The [`Key`] to encrypt with. Its ID is stored in the output's header.
+    ///
+    /// # Errors
+    /// This function may error if the key could not be obtained.
+    fn encryption_key() -> Result<Key, Box<dyn error::Error + Send + Sync>>;
+
+    /// Returns the key with the given ID to decrypt input with.
+    ///
+    /// By default, this returns the [encryption key](KeyProvider::encryption_key()) if its ID
+    /// matches. Override it to also be able to read input encrypted with older keys.
+    ///
+    /// # Arguments
+    /// - `id`: The ID of the key as stored in the input's header.
+    ///
+    /// # Returns
+    /// The [`Key`] with the given ID, or [`None`] if this provider doesn't know it.
+    ///
+    /// # Errors
+    /// This function may error if the key could not be obtained.
+    fn decryption_key(id: &str) -> Result<Option<Key>, Box<dyn error::Error + Send + Sync>> {
+        let key: Key = Self::encryption_key()?;
+        Ok(if key.id == id { Some(key) } else { None })
+    }
+}
+
+
+
+/// Implements a [`serializer::Serializer`] that encrypts the output of another serializer.
+///
+/// The output of the wrapped serializer is sealed with [`KeyProvider::CIPHER`] under
+/// [`KeyProvider::encryption_key()`], using a fresh random nonce every time. It is prefixed with a
+/// header that stores the cipher, the ID of the key and the nonce, all of which are authenticated
+/// as well. When deserializing, the key with the ID in the header is fetched through
+/// [`KeyProvider::decryption_key()`], and input that does not decrypt with it results in an
+/// [`Error::Decrypt`].
+///
+/// Note that the output is binary. As such, [`serializer::Serializer::to_string()`] and
+/// [`serializer::Serializer::from_str()`] always fail with an [`Error::NotText`]; use the byte-,
+/// writer-, reader- or path-functions instead.
+///
+/// # Examples
+/// ```rust
+/// use std::error::Error;
+///
+/// use serializable::dummy::Serializer;
+/// use serializable::encrypted::{self, Cipher, Encrypted, Key, KeyProvider};
+/// use serializable::Serializable;
+///
+/// struct Keys;
+/// impl KeyProvider for Keys {
+///     // Any of the enabled ciphers (e.g., `Cipher::Aes256Gcm`)
+///     const CIPHER: Cipher = Cipher::ALL[0];
+///
+///     fn encryption_key() -> Result<Key, Box<dyn Error + Send + Sync>> { Ok(Key { id: "v1".into(), material: [42; 32] }) }
+/// }
+///
+/// #[derive(Debug, Default, Eq, PartialEq)]
+/// struct Credentials {
+///     token: String,
+/// }
+/// impl Serializable<Encrypted<Serializer<Credentials>, Keys>> for Credentials {}
+///
+/// let raw: Vec<u8> = Credentials::default().to_bytes().unwrap();
+/// assert!(raw.starts_with(b"SENC"));
+/// assert!(!raw.windows(12).any(|w| w == b"<dummy_text>"));
+/// assert_eq!(Credentials::from_bytes(&raw).unwrap(), Credentials::default());
+///
+/// // Tampering with the output is detected
+/// let mut raw: Vec<u8> = raw;
+/// *raw.last_mut().unwrap() ^= 1;
+/// assert!(matches!(Credentials::from_bytes(&raw), Err(serializable::Error::DeserializeBytes { err: encrypted::Error::Decrypt { .. }, .. })));
+/// assert!(Credentials::from_bytes(&raw).unwrap_err().is_decrypt());
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct Encrypted<S, K>(PhantomData<(S, K)>);
+impl<S: serializer::Serializer, K: KeyProvider> Encrypted<S, K> {
+    /// Encrypts the given plaintext with the current key of the provider.
+    ///
+    /// # Arguments
+    /// - `plaintext`: The serialized value to encrypt.
+    ///
+    /// # Returns
+    /// The header followed by the ciphertext.
+    ///
+    /// # Errors
+    /// This function errors if the key provider failed or the cipher failed to encrypt.
+    fn seal(plaintext: &[u8]) -> Result<Vec<u8>, Error<S::Error>> {
+        let key: Key = K::encryption_key().map_err(|err| Error::EncryptionKey { err })?;
+        let id_len: u8 = u8::try_from(key.id.len()).map_err(|_| Error::KeyIdTooLong { id: key.id.clone() })?;
+
+        // Write the header first...
+        let mut res: Vec<u8> = Vec::with_capacity(MAGIC.len() + 3 + key.id.len() + NONCE_LEN + plaintext.len() + 16);
+        res.extend_from_slice(&MAGIC);
+        res.push(VERSION);
+        res.push(K::CIPHER.id());
+        res.push(id_len);
+        res.extend_from_slice(key.id.as_bytes());
+
+        // ...and then the nonce and ciphertext
+        match K::CIPHER {
+            #[cfg(feature = "encrypt-aes-gcm")]
+            Cipher::Aes256Gcm => seal_with::<aes_gcm::Aes256Gcm>(&key.material, &mut res, plaintext),
+            #[cfg(feature = "encrypt-chacha20poly1305")]
+            Cipher::ChaCha20Poly1305 => seal_with::<chacha20poly1305::ChaCha20Poly1305>(&key.material, &mut res, plaintext),
+        }
+        .map_err(|_| Error::Encrypt { cipher: K::CIPHER })?;
+        Ok(res)
+    }
+
+    /// Parses the header of the given input and decrypts it with the key from the provider.
+    ///
+    /// # Arguments
+    /// - `raw`: The header followed by the ciphertext.
+    ///
+    /// # Returns
+    /// The decrypted plaintext.
+    ///
+    /// # Errors
+    /// This function errors if the header is invalid, the key provider failed or did not know the
+    /// key, or if the input did not decrypt.
+    fn open(raw: &[u8]) -> Result<Vec<u8>, Error<S::Error>> {
+        // Parse the header
+        let Some(rest) = raw.strip_prefix(&MAGIC) else { return Err(Error::NotEncrypted) };
+        let [version, cipher, id_len, ..] = *rest else { return Err(Error::Truncated) };
+        if version != VERSION {
+            return Err(Error::UnsupportedVersion { version });
+        }
+        let cipher: Cipher = Cipher::from_id(cipher).ok_or(Error::UnknownCipher { id: cipher })?;
+        let header_len: usize = MAGIC.len() + 3 + id_len as usize + NONCE_LEN;
+        if raw.len() < header_len {
+            return Err(Error::Truncated);
+        }
+        let (header, ciphertext): (&[u8], &[u8]) = raw.split_at(header_len);
+        let id: String = String::from_utf8_lossy(&header[MAGIC.len() + 3..header_len - NONCE_LEN]).into_owned();
+
+        // Get the key
+        let key: Key = match K::decryption_key(&id) {
+            Ok(Some(key)) => key,
+            Ok(None) => return Err(Error::UnknownKey { id }),
+            Err(err) => return Err(Error::DecryptionKey { id, err }),
+        };
+
+        // Decrypt
+        match cipher {
+            #[cfg(feature = "encrypt-aes-gcm")]
+            Cipher::Aes256Gcm => open_with::<aes_gcm::Aes256Gcm>(&key.material, header, ciphertext),
+            #[cfg(feature = "encrypt-chacha20poly1305")]
+            Cipher::ChaCha20Poly1305 => open_with::<chacha20poly1305::ChaCha20Poly1305>(&key.material, header, ciphertext),
+        }
+        .map_err(|_| Error::Decrypt { cipher, id })
+    }
+}
+
+impl<S, K> serializer::Serializer for Encrypted<S, K>
+where
+    S: serializer::Serializer,
+    S::Error: 'static,
+    K: KeyProvider,
+{
+    type Error = Error<S::Error>;
+    type Target = S::Target;
+
+    #[inline]
+    fn to_string(_value: &Self::Target) -> Result<String, Self::Error> { Err(Error::NotText) }
+
+    #[inline]
+    fn to_bytes(value: &Self::Target) -> Result<Vec<u8>, Self::Error> { Self::seal(&S::to_bytes(value).map_err(|err| Error::Serialize { err })?) }
+
+    #[inline]
+    fn to_bytes_pretty(value: &Self::Target) -> Result<Vec<u8>, Self::Error> {
+        Self::seal(&S::to_bytes_pretty(value).map_err(|err| Error::Serialize { err })?)
+    }
+
+    #[inline]
+    fn to_bytes_pretty_with(value: &Self::Target, opts: &PrettyOptions) -> Result<Vec<u8>, Self::Error> {
+        Self::seal(&S::to_bytes_pretty_with(value, opts).map_err(|err| Error::Serialize { err })?)
+    }
+
+    #[inline]
+    fn to_writer(value: &Self::Target, mut writer: impl Write) -> Result<(), Self::Error> {
+        writer.write_all(&Self::to_bytes(value)?).map_err(|err| Error::Write { err })
+    }
+
+    #[inline]
+    fn to_writer_pretty(value: &Self::Target, mut writer: impl Write) -> Result<(), Self::Error> {
+        writer.write_all(&Self::to_bytes_pretty(value)?).map_err(|err| Error::Write { err })
+    }
+
+    #[inline]
+    fn to_writer_pretty_with(value: &Self::Target, opts: &PrettyOptions, mut writer: impl Write) -> Result<(), Self::Error> {
+        writer.write_all(&Self::to_bytes_pretty_with(value, opts)?).map_err(|err| Error::Write { err })
+    }
+
+    #[inline]
+    fn from_str(_raw: impl AsRef<str>) -> Result<Self::Target, Self::Error> { Err(Error::NotText) }
+
+    #[inline]
+    fn from_bytes(raw: impl AsRef<[u8]>) -> Result<Self::Target, Self::Error> {
+        S::from_bytes(Self::open(raw.as_ref())?).map_err(|err| Error::Deserialize { err })
+    }
+
+    #[inline]
+    fn from_reader(mut reader: impl Read) -> Result<Self::Target, Self::Error> {
+        let mut raw: Vec<u8> = Vec::new();
+        reader.read_to_end(&mut raw).map_err(|err| Error::Read { err })?;
+        Self::from_bytes(raw)
+    }
+
+    // NOTE: Locations are not forwarded, as they'd point into the plaintext instead of the input
+    #[inline]
+    fn field_path(err: &Self::Error) -> Vec<PathSegment> {
+        match err {
+            Error::Deserialize { err } => S::field_path(err),
+            _ => Vec::new(),
+        }
+    }
+}
+
+#[cfg(feature = "async-tokio")]
+#[async_trait::async_trait]
+impl<S, K> serializer::SerializerAsync for Encrypted<S, K>
+where
+    S: serializer::Serializer,
+    S::Target: Send + Sync,
+    S::Error: 'static + Send,
+    K: KeyProvider,
+{
+    #[inline]
+    async fn to_writer_async(value: &Self::Target, mut writer: impl Send + std::marker::Unpin + tokio::io::AsyncWrite) -> Result<(), Self::Error> {
+        use tokio::io::AsyncWriteExt;
+
+        // Encrypt to a buffer first
+        let raw: Vec<u8> = <Self as serializer::Serializer>::to_bytes(value)?;
+
+        // Now write to the writer
+        if let Err(err) = writer.write_all(&raw).await {
+            return Err(Error::Write { err });
+        }
+        // ...making sure its flushed
+        match writer.flush().await {
+            Ok(_) => Ok(()),
+            Err(err) => Err(Error::Flush { err }),
+        }
+    }
+
+    #[inline]
+    async fn to_writer_pretty_async(
+        value: &Self::Target,
+        mut writer: impl Send + std::marker::Unpin + tokio::io::AsyncWrite,
+    ) -> Result<(), Self::Error> {
+        use tokio::io::AsyncWriteExt;
+
+        // Encrypt to a buffer first
+        let raw: Vec<u8> = <Self as serializer::Serializer>::to_bytes_pretty(value)?;
+
+        // Now write to the writer
+        if let Err(err) = writer.write_all(&raw).await {
+            return Err(Error::Write { err });
+        }
+        // ...making sure its flushed
+        match writer.flush().await {
+            Ok(_) => Ok(()),
+            Err(err) => Err(Error::Flush { err }),
+        }
+    }
+
+    #[inline]
+    async fn to_writer_pretty_with_async(
+        value: &Self::Target,
+        opts: &PrettyOptions,
+        mut writer: impl Send + std::marker::Unpin + tokio::io::AsyncWrite,
+    ) -> Result<(), Self::Error> {
+        use tokio::io::AsyncWriteExt;
+
+        // Encrypt to a buffer first
+        let raw: Vec<u8> = <Self as serializer::Serializer>::to_bytes_pretty_with(value, opts)?;
+
+        // Now write to the writer
+        if let Err(err) = writer.write_all(&raw).await {
+            return Err(Error::Write { err });
+        }
+        // ...making sure its flushed
+        match writer.flush().await {
+            Ok(_) => Ok(()),
+            Err(err) => Err(Error::Flush { err }),
+        }
+    }
+
+    #[inline]
+    async fn from_reader_async(mut reader: impl Send + std::marker::Unpin + tokio::io::AsyncRead) -> Result<Self::Target, Self::Error> {
+        use tokio::io::AsyncReadExt;
+
+        // Read the entire buffer first
+        let mut raw: Vec<u8> = Vec::new();
+        if let Err(err) = reader.read_to_end(&mut raw).await {
+            return Err(Error::Read { err });
+        }
+
+        // Then decrypt and deserialize as bytes
+        <Self as serializer::Serializer>::from_bytes(&raw)
+    }
+}
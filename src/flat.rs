@@ -0,0 +1,613 @@
+//  FLAT.rs
+//    by Lut99
+//
+//  Created:
+//    16 Oct 2026, 21:20:47
+//  Last edited:
+//    17 Oct 2026, 10:14:52
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Implements a shared (de)serializer for flat, key/value-based formats
+//!   (e.g., INI or `.env`-files), where every value is a string and
+//!   structs may be nested at most one level deep.
+//
+
+use std::borrow::Cow;
+use std::error;
+use std::fmt::{Display, Formatter, Result as FResult};
+
+use serde::de::value::MapDeserializer;
+use serde::de::{self, IntoDeserializer as _, Unexpected, Visitor};
+use serde::ser::{self, Impossible};
+use serde::Serialize;
+
+
+/***** ERRORS *****/
+/// Defines errors that occur when (de)serializing flat, key/value-based formats (e.g., INI or `.env`-files).
+#[derive(Debug)]
+pub struct Error {
+    /// The message describing what went wrong.
+    msg: String,
+}
+impl Error {
+    /// Creates a new error for a syntax error in the input.
+    ///
+    /// # Arguments
+    /// - `line`: The (one-indexed) line number where the error occurred.
+    /// - `msg`: Something describing what went wrong.
+    ///
+    /// # Returns
+    /// A new Error that mentions the line number.
+    #[inline]
+    pub(crate) fn syntax(line: usize, msg: impl Display) -> Self { Self { msg: format!("Line {line}: {msg}") } }
+
+    /// Creates a new error for a Rust construct that cannot be represented in flat formats.
+    ///
+    /// # Arguments
+    /// - `what`: The (plural) name of the unsupported construct.
+    ///
+    /// # Returns
+    /// A new Error that explains the construct is not supported.
+    #[inline]
+    fn unsupported(what: &str) -> Self { Self { msg: format!("{what} are not supported in flat key/value formats") } }
+
+    /// Creates a new error for a key that cannot be written.
+    ///
+    /// # Arguments
+    /// - `key`: The key that cannot be written.
+    ///
+    /// # Returns
+    /// A new Error that mentions the key.
+    #[inline]
+    fn invalid_key(key: &str) -> Self { Self { msg: format!("key {key:?} cannot be written (it is empty, or contains characters with a special meaning)") } }
+}
+impl Display for Error {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FResult { write!(f, "{}", self.msg) }
+}
+impl error::Error for Error {}
+impl ser::Error for Error {
+    #[inline]
+    fn custom<T: Display>(msg: T) -> Self { Self { msg: msg.to_string() } }
+}
+impl de::Error for Error {
+    #[inline]
+    fn custom<T: Display>(msg: T) -> Self { Self { msg: msg.to_string() } }
+}
+
+
+
+
+
+/***** HELPERS *****/
+/// Serializes a single [`Node`], allowing at most `depth` levels of nested tables.
+struct NodeSerializer {
+    /// The number of tables that may still be nested.
+    depth: usize,
+}
+impl NodeSerializer {
+    /// Starts serializing a table.
+    fn table(self) -> Result<TableSerializer, Error> {
+        if self.depth == 0 {
+            return Err(<Error as ser::Error>::custom("structs and maps may be nested at most one level deep in flat key/value formats"));
+        }
+        Ok(TableSerializer { depth: self.depth - 1, entries: Vec::new(), key: None })
+    }
+}
+impl ser::Serializer for NodeSerializer {
+    type Error = Error;
+    type Ok = Option<Node>;
+    type SerializeMap = TableSerializer;
+    type SerializeSeq = Impossible<Self::Ok, Self::Error>;
+    type SerializeStruct = TableSerializer;
+    type SerializeStructVariant = Impossible<Self::Ok, Self::Error>;
+    type SerializeTuple = Impossible<Self::Ok, Self::Error>;
+    type SerializeTupleStruct = Impossible<Self::Ok, Self::Error>;
+    type SerializeTupleVariant = Impossible<Self::Ok, Self::Error>;
+
+    #[inline]
+    fn serialize_bool(self, v: bool) -> Result<Self::Ok, Self::Error> { Ok(Some(Node::Value(v.to_string()))) }
+
+    #[inline]
+    fn serialize_i8(self, v: i8) -> Result<Self::Ok, Self::Error> { Ok(Some(Node::Value(v.to_string()))) }
+
+    #[inline]
+    fn serialize_i16(self, v: i16) -> Result<Self::Ok, Self::Error> { Ok(Some(Node::Value(v.to_string()))) }
+
+    #[inline]
+    fn serialize_i32(self, v: i32) -> Result<Self::Ok, Self::Error> { Ok(Some(Node::Value(v.to_string()))) }
+
+    #[inline]
+    fn serialize_i64(self, v: i64) -> Result<Self::Ok, Self::Error> { Ok(Some(Node::Value(v.to_string()))) }
+
+    #[inline]
+    fn serialize_i128(self, v: i128) -> Result<Self::Ok, Self::Error> { Ok(Some(Node::Value(v.to_string()))) }
+
+    #[inline]
+    fn serialize_u8(self, v: u8) -> Result<Self::Ok, Self::Error> { Ok(Some(Node::Value(v.to_string()))) }
+
+    #[inline]
+    fn serialize_u16(self, v: u16) -> Result<Self::Ok, Self::Error> { Ok(Some(Node::Value(v.to_string()))) }
+
+    #[inline]
+    fn serialize_u32(self, v: u32) -> Result<Self::Ok, Self::Error> { Ok(Some(Node::Value(v.to_string()))) }
+
+    #[inline]
+    fn serialize_u64(self, v: u64) -> Result<Self::Ok, Self::Error> { Ok(Some(Node::Value(v.to_string()))) }
+
+    #[inline]
+    fn serialize_u128(self, v: u128) -> Result<Self::Ok, Self::Error> { Ok(Some(Node::Value(v.to_string()))) }
+
+    #[inline]
+    fn serialize_f32(self, v: f32) -> Result<Self::Ok, Self::Error> { Ok(Some(Node::Value(v.to_string()))) }
+
+    #[inline]
+    fn serialize_f64(self, v: f64) -> Result<Self::Ok, Self::Error> { Ok(Some(Node::Value(v.to_string()))) }
+
+    #[inline]
+    fn serialize_char(self, v: char) -> Result<Self::Ok, Self::Error> { Ok(Some(Node::Value(v.to_string()))) }
+
+    #[inline]
+    fn serialize_str(self, v: &str) -> Result<Self::Ok, Self::Error> { Ok(Some(Node::Value(v.into()))) }
+
+    #[inline]
+    fn serialize_bytes(self, _v: &[u8]) -> Result<Self::Ok, Self::Error> { Err(Error::unsupported("Byte arrays")) }
+
+    #[inline]
+    fn serialize_none(self) -> Result<Self::Ok, Self::Error> { Ok(None) }
+
+    #[inline]
+    fn serialize_some<T: ?Sized + Serialize>(self, value: &T) -> Result<Self::Ok, Self::Error> { value.serialize(self) }
+
+    #[inline]
+    fn serialize_unit(self) -> Result<Self::Ok, Self::Error> { Ok(None) }
+
+    #[inline]
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<Self::Ok, Self::Error> { Ok(None) }
+
+    #[inline]
+    fn serialize_unit_variant(self, _name: &'static str, _variant_index: u32, variant: &'static str) -> Result<Self::Ok, Self::Error> {
+        Ok(Some(Node::Value(variant.into())))
+    }
+
+    #[inline]
+    fn serialize_newtype_struct<T: ?Sized + Serialize>(self, _name: &'static str, value: &T) -> Result<Self::Ok, Self::Error> {
+        value.serialize(self)
+    }
+
+    #[inline]
+    fn serialize_newtype_variant<T: ?Sized + Serialize>(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _value: &T,
+    ) -> Result<Self::Ok, Self::Error> {
+        Err(Error::unsupported("Enum variants with data"))
+    }
+
+    #[inline]
+    fn serialize_seq(self, _len: Option<usize>) -> Result<Self::SerializeSeq, Self::Error> { Err(Error::unsupported("Sequences")) }
+
+    #[inline]
+    fn serialize_tuple(self, _len: usize) -> Result<Self::SerializeTuple, Self::Error> { Err(Error::unsupported("Tuples")) }
+
+    #[inline]
+    fn serialize_tuple_struct(self, _name: &'static str, _len: usize) -> Result<Self::SerializeTupleStruct, Self::Error> {
+        Err(Error::unsupported("Tuple structs"))
+    }
+
+    #[inline]
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleVariant, Self::Error> {
+        Err(Error::unsupported("Enum variants with data"))
+    }
+
+    #[inline]
+    fn serialize_map(self, _len: Option<usize>) -> Result<Self::SerializeMap, Self::Error> { self.table() }
+
+    #[inline]
+    fn serialize_struct(self, _name: &'static str, _len: usize) -> Result<Self::SerializeStruct, Self::Error> { self.table() }
+
+    #[inline]
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStructVariant, Self::Error> {
+        Err(Error::unsupported("Enum variants with data"))
+    }
+}
+
+/// Serializes the entries of a [`Node::Table`].
+struct TableSerializer {
+    /// The number of tables that may still be nested in this table.
+    depth: usize,
+    /// The entries serialized so far.
+    entries: Vec<(String, Node)>,
+    /// The key of the next map entry, if [`ser::SerializeMap::serialize_key()`] has been called.
+    key: Option<String>,
+}
+impl TableSerializer {
+    /// Serializes a value and adds it to the table, unless it is empty (e.g., [`None`]).
+    fn push<T: ?Sized + Serialize>(&mut self, key: String, value: &T) -> Result<(), Error> {
+        if let Some(node) = value.serialize(NodeSerializer { depth: self.depth })? {
+            self.entries.push((key, node));
+        }
+        Ok(())
+    }
+}
+impl ser::SerializeMap for TableSerializer {
+    type Error = Error;
+    type Ok = Option<Node>;
+
+    #[inline]
+    fn serialize_key<T: ?Sized + Serialize>(&mut self, key: &T) -> Result<(), Self::Error> {
+        match key.serialize(NodeSerializer { depth: 0 })? {
+            Some(Node::Value(key)) => {
+                self.key = Some(key);
+                Ok(())
+            },
+            _ => Err(<Error as ser::Error>::custom("map keys must be non-empty, scalar values")),
+        }
+    }
+
+    #[inline]
+    fn serialize_value<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), Self::Error> {
+        let key: String = self.key.take().expect("serialize_value() called before serialize_key()");
+        self.push(key, value)
+    }
+
+    #[inline]
+    fn end(self) -> Result<Self::Ok, Self::Error> { Ok(Some(Node::Table(self.entries))) }
+}
+impl ser::SerializeStruct for TableSerializer {
+    type Error = Error;
+    type Ok = Option<Node>;
+
+    #[inline]
+    fn serialize_field<T: ?Sized + Serialize>(&mut self, key: &'static str, value: &T) -> Result<(), Self::Error> { self.push(key.into(), value) }
+
+    #[inline]
+    fn end(self) -> Result<Self::Ok, Self::Error> { Ok(Some(Node::Table(self.entries))) }
+}
+
+
+
+/// Deserializes a single [`Node`], parsing its string values to whatever type is requested.
+pub(crate) struct NodeDeserializer {
+    /// The node to deserialize.
+    node:     Node,
+    /// Whether to group `PREFIX_KEY`-entries as the `KEY`-field of a nested struct named `PREFIX`.
+    prefixes: bool,
+}
+impl NodeDeserializer {
+    /// Constructor for the NodeDeserializer.
+    ///
+    /// # Arguments
+    /// - `entries`: The top-level entries to deserialize.
+    /// - `prefixes`: Whether to group `PREFIX_KEY`-entries as the `KEY`-field of a nested struct named `PREFIX` (as done in `.env`-files).
+    ///
+    /// # Returns
+    /// A new NodeDeserializer that can be used to deserialize a struct or map.
+    #[inline]
+    pub(crate) fn new(entries: Vec<(String, Node)>, prefixes: bool) -> Self { Self { node: Node::Table(entries), prefixes } }
+
+    /// Matches the entries of a table to the fields of the struct deserialized from it.
+    ///
+    /// Keys are matched case-insensitively. If `prefixes` is given, entries that don't match a
+    /// field are grouped by the longest field they are prefixed with (e.g., `DATABASE_HOST`
+    /// becomes the `HOST`-entry of the `database`-field).
+    fn group(entries: Vec<(String, Node)>, fields: &[&str], prefixes: bool) -> Vec<(String, Node)> {
+        let mut res: Vec<(String, Node)> = Vec::with_capacity(entries.len());
+        for (key, node) in entries {
+            // Attempt to match a field directly
+            if let Some(field) = fields.iter().find(|field| field.eq_ignore_ascii_case(&key)) {
+                res.push(((*field).into(), node));
+                continue;
+            }
+
+            // Else, attempt to match the prefix of a field
+            let prefix: Option<(&str, &str)> = if prefixes && matches!(node, Node::Value(_)) {
+                fields
+                    .iter()
+                    .filter_map(|field| match (key.get(..field.len()), key.get(field.len()..)) {
+                        (Some(prefix), Some(rest)) if prefix.eq_ignore_ascii_case(field) && rest.len() > 1 && rest.starts_with('_') => {
+                            Some((*field, &rest[1..]))
+                        },
+                        _ => None,
+                    })
+                    .max_by_key(|(field, _)| field.len())
+            } else {
+                None
+            };
+            match prefix {
+                Some((field, rest)) => {
+                    let rest: String = rest.into();
+                    match res.iter_mut().find(|(key, node)| key == field && matches!(node, Node::Table(_))) {
+                        Some((_, Node::Table(table))) => table.push((rest, node)),
+                        _ => res.push((field.into(), Node::Table(vec![(rest, node)]))),
+                    }
+                },
+                None => res.push((key, node)),
+            }
+        }
+        res
+    }
+}
+impl<'de> de::IntoDeserializer<'de, Error> for NodeDeserializer {
+    type Deserializer = Self;
+
+    #[inline]
+    fn into_deserializer(self) -> Self::Deserializer { self }
+}
+
+/// Implements the `deserialize_*()`-functions that parse a number from a string value.
+macro_rules! deserialize_parse {
+    ($($deserialize:ident => $visit:ident),+ $(,)?) => {
+        $(
+            fn $deserialize<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+                match self.node {
+                    Node::Value(raw) => match raw.trim().parse() {
+                        Ok(value) => visitor.$visit(value),
+                        Err(err) => Err(<Error as de::Error>::custom(format!("invalid value {raw:?}: {err}"))),
+                    },
+                    node => Self { node, prefixes: self.prefixes }.deserialize_any(visitor),
+                }
+            }
+        )+
+    };
+}
+
+impl<'de> de::Deserializer<'de> for NodeDeserializer {
+    type Error = Error;
+
+    serde::forward_to_deserialize_any! { str string bytes byte_buf seq tuple tuple_struct map identifier ignored_any }
+
+    deserialize_parse! {
+        deserialize_i8 => visit_i8,
+        deserialize_i16 => visit_i16,
+        deserialize_i32 => visit_i32,
+        deserialize_i64 => visit_i64,
+        deserialize_i128 => visit_i128,
+        deserialize_u8 => visit_u8,
+        deserialize_u16 => visit_u16,
+        deserialize_u32 => visit_u32,
+        deserialize_u64 => visit_u64,
+        deserialize_u128 => visit_u128,
+        deserialize_f32 => visit_f32,
+        deserialize_f64 => visit_f64,
+    }
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        match self.node {
+            Node::Value(raw) => visitor.visit_string(raw),
+            Node::Table(entries) => {
+                let prefixes: bool = self.prefixes;
+                visitor.visit_map(MapDeserializer::new(entries.into_iter().map(|(key, node)| (key, Self { node, prefixes }))))
+            },
+        }
+    }
+
+    fn deserialize_bool<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        match self.node {
+            Node::Value(raw) => match raw.trim().to_ascii_lowercase().as_str() {
+                "true" | "yes" | "on" | "1" => visitor.visit_bool(true),
+                "false" | "no" | "off" | "0" => visitor.visit_bool(false),
+                _ => Err(de::Error::invalid_value(Unexpected::Str(&raw), &visitor)),
+            },
+            node => Self { node, prefixes: self.prefixes }.deserialize_any(visitor),
+        }
+    }
+
+    fn deserialize_char<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        match self.node {
+            Node::Value(raw) => {
+                let mut chars = raw.chars();
+                match (chars.next(), chars.next()) {
+                    (Some(c), None) => visitor.visit_char(c),
+                    _ => Err(de::Error::invalid_value(Unexpected::Str(&raw), &visitor)),
+                }
+            },
+            node => Self { node, prefixes: self.prefixes }.deserialize_any(visitor),
+        }
+    }
+
+    #[inline]
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> { visitor.visit_some(self) }
+
+    #[inline]
+    fn deserialize_unit<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> { visitor.visit_unit() }
+
+    #[inline]
+    fn deserialize_unit_struct<V: Visitor<'de>>(self, _name: &'static str, visitor: V) -> Result<V::Value, Self::Error> { visitor.visit_unit() }
+
+    #[inline]
+    fn deserialize_newtype_struct<V: Visitor<'de>>(self, _name: &'static str, visitor: V) -> Result<V::Value, Self::Error> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_struct<V: Visitor<'de>>(self, _name: &'static str, fields: &'static [&'static str], visitor: V) -> Result<V::Value, Self::Error> {
+        match self.node {
+            Node::Table(entries) => Self { node: Node::Table(Self::group(entries, fields, self.prefixes)), prefixes: self.prefixes }.deserialize_any(visitor),
+            node => Self { node, prefixes: self.prefixes }.deserialize_any(visitor),
+        }
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(self, _name: &'static str, _variants: &'static [&'static str], visitor: V) -> Result<V::Value, Self::Error> {
+        match self.node {
+            Node::Value(raw) => visitor.visit_enum(raw.into_deserializer()),
+            Node::Table(_) => Err(de::Error::invalid_type(Unexpected::Map, &visitor)),
+        }
+    }
+}
+
+
+
+
+
+/***** LIBRARY *****/
+/// Represents a value in a flat, key/value-based format.
+#[derive(Clone, Debug)]
+pub(crate) enum Node {
+    /// A single value, always represented as a string.
+    Value(String),
+    /// A table of (ordered) key/value pairs, e.g., a section in an INI-file.
+    Table(Vec<(String, Node)>),
+}
+
+
+
+/// Serializes a value to the entries of a flat, key/value-based format.
+///
+/// # Arguments
+/// - `value`: The value to serialize. Must be a struct or map, whose fields are scalars or structs/maps of scalars.
+///
+/// # Returns
+/// The entries of the top-level table, in the order they were serialized.
+///
+/// # Errors
+/// This function errors if the value (or one of its fields) cannot be represented in the flat format.
+pub(crate) fn to_entries<T: ?Sized + Serialize>(value: &T) -> Result<Vec<(String, Node)>, Error> {
+    match value.serialize(NodeSerializer { depth: 2 })? {
+        Some(Node::Table(entries)) => Ok(entries),
+        _ => Err(<Error as ser::Error>::custom("only structs and maps can be serialized to flat key/value formats")),
+    }
+}
+
+
+
+/// Checks whether the given key can be written to a flat format.
+///
+/// # Arguments
+/// - `key`: The key (or section name) to check.
+/// - `invalid`: The characters that may not occur in keys of the target format.
+///
+/// # Errors
+/// This function errors if the key is empty, starts or ends with whitespace or contains any of the
+/// `invalid` characters, as it would then be read back differently (or not at all).
+pub(crate) fn check_key(key: &str, invalid: &[char]) -> Result<(), Error> {
+    if key.is_empty() || key.trim() != key || key.contains(invalid) {
+        return Err(Error::invalid_key(key));
+    }
+    Ok(())
+}
+
+/// Quotes a value if it cannot be written literally.
+///
+/// # Arguments
+/// - `value`: The value to (maybe) quote.
+/// - `comments`: The characters that start a comment in the target format.
+/// - `interpolates`: Whether the target format expands variables (e.g., `$VAR`) in unquoted and
+///   double-quoted values.
+///
+/// # Returns
+/// The value as it should be written, which is a double-quoted, escaped string if it contains
+/// comment characters, newlines or leading/trailing whitespace. If `interpolates`, then values
+/// containing `$` are single-quoted instead (as those are taken literally), or double-quoted with
+/// the `$` escaped if that's not possible.
+pub(crate) fn quote<'v>(value: &'v str, comments: &[char], interpolates: bool) -> Cow<'v, str> {
+    let dollar: bool = interpolates && value.contains('$');
+    if !dollar && value.trim() == value && !value.starts_with(['"', '\'']) && !value.contains(['\n', '\r']) && !value.contains(comments) {
+        return Cow::Borrowed(value);
+    }
+    if dollar && !value.contains(['\'', '\n', '\r']) {
+        return Cow::Owned(format!("'{value}'"));
+    }
+
+    let mut res: String = String::with_capacity(value.len() + 2);
+    res.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => res.push_str("\\\""),
+            '\\' => res.push_str("\\\\"),
+            '\n' => res.push_str("\\n"),
+            '\r' => res.push_str("\\r"),
+            '\t' => res.push_str("\\t"),
+            '$' if interpolates => res.push_str("\\$"),
+            c => res.push(c),
+        }
+    }
+    res.push('"');
+    Cow::Owned(res)
+}
+
+/// Parses a (possibly quoted) value as written by [`quote()`].
+///
+/// # Arguments
+/// - `raw`: The raw value, i.e., everything after the `=` on a line.
+/// - `comments`: The characters that start a comment in the source format.
+/// - `line`: The line number of the value, used in errors.
+///
+/// # Returns
+/// The parsed value, with quotes and trailing comments removed.
+///
+/// # Errors
+/// This function errors if a quoted value is not terminated, or if there is trailing garbage after it.
+pub(crate) fn unquote(raw: &str, comments: &[char], line: usize) -> Result<String, Error> {
+    let raw: &str = raw.trim();
+    let mut chars = raw.char_indices();
+    let (value, rest): (String, &str) = match chars.next() {
+        Some((_, '"')) => {
+            let mut value: String = String::new();
+            let mut end: Option<usize> = None;
+            while let Some((i, c)) = chars.next() {
+                match c {
+                    '"' => {
+                        end = Some(i + 1);
+                        break;
+                    },
+                    '\\' => match chars.next() {
+                        Some((_, 'n')) => value.push('\n'),
+                        Some((_, 'r')) => value.push('\r'),
+                        Some((_, 't')) => value.push('\t'),
+                        Some((_, c @ ('"' | '\\' | '$'))) => value.push(c),
+                        Some((_, c)) => {
+                            value.push('\\');
+                            value.push(c);
+                        },
+                        None => break,
+                    },
+                    c => value.push(c),
+                }
+            }
+            match end {
+                Some(end) => (value, &raw[end..]),
+                None => return Err(Error::syntax(line, "unterminated quoted value")),
+            }
+        },
+        Some((_, '\'')) => match raw[1..].find('\'') {
+            Some(end) => (raw[1..1 + end].into(), &raw[end + 2..]),
+            None => return Err(Error::syntax(line, "unterminated quoted value")),
+        },
+        _ => {
+            // Strip any comment, which must be preceded by whitespace
+            let mut end: usize = raw.len();
+            let mut prev: char = ' ';
+            for (i, c) in raw.char_indices() {
+                if i > 0 && prev.is_whitespace() && comments.contains(&c) {
+                    end = i;
+                    break;
+                }
+                prev = c;
+            }
+            return Ok(raw[..end].trim_end().into());
+        },
+    };
+
+    // Only allow comments after a quoted value
+    let rest: &str = rest.trim_start();
+    if !rest.is_empty() && !rest.starts_with(comments) {
+        return Err(Error::syntax(line, format!("unexpected {rest:?} after quoted value")));
+    }
+    Ok(value)
+}
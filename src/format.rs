@@ -0,0 +1,1078 @@
+//  FORMAT.rs
+//    by Lut99
+//
+//  Created:
+//    16 Oct 2026, 21:41:05
+//  Last edited:
+//    17 Oct 2026, 11:52:40
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Defines the [`Format`], which can be used to select one of the
+//!   enabled [`Serializer`](crate::Serializer)s at runtime (e.g., based on
+//!   a file's extension or its contents), and the [`SerializableAuto`]-
+//!   trait that does so while (de)serializing.
+//
+
+use std::any::type_name;
+use std::borrow::Cow;
+use std::error;
+use std::ffi::OsStr;
+use std::fmt::{Display, Formatter, Result as FResult};
+use std::fs::File;
+use std::io::{Read, Write};
+use std::path::Path;
+
+use serde::de::{IgnoredAny, MapAccess, SeqAccess, Visitor};
+use serde::{Deserialize, Serialize};
+
+use crate::compression::Compression;
+use crate::location::{Location, PathSegment};
+use crate::pretty::PrettyOptions;
+use crate::serializable::{context, read_decoded, read_raw, read_verified, write_checksum, write_compressed};
+use crate::serializer;
+
+
+/***** HELPER MACROS *****/
+/// Calls the same [`Serializer`](serializer::Serializer)-function on whichever backend is
+/// selected by the given [`Format`], wrapping its error in the matching [`Error`]-variant.
+///
+/// Use as `dispatch!(format, S => <S<T> as serializer::Serializer>::to_string(value))`, where `S`
+/// is the name under which the backend's `Serializer` becomes available in the expression.
+macro_rules! dispatch {
+    ($format:expr, $ser:ident => $call:expr) => {
+        match $format {
+            #[cfg(feature = "serde-bson")]
+            Format::Bson => {
+                use crate::bson::Serializer as $ser;
+                $call.map_err(|err| Error::Bson { err: Box::new(err) })
+            },
+            #[cfg(feature = "serde-cbor")]
+            Format::Cbor => {
+                use crate::cbor::Serializer as $ser;
+                $call.map_err(|err| Error::Cbor { err: Box::new(err) })
+            },
+            #[cfg(feature = "serde-dotenv")]
+            Format::Dotenv => {
+                use crate::dotenv::Serializer as $ser;
+                $call.map_err(|err| Error::Dotenv { err: Box::new(err) })
+            },
+            #[cfg(feature = "serde-ini")]
+            Format::Ini => {
+                use crate::ini::Serializer as $ser;
+                $call.map_err(|err| Error::Ini { err: Box::new(err) })
+            },
+            #[cfg(feature = "serde-json")]
+            Format::Json => {
+                use crate::json::Serializer as $ser;
+                $call.map_err(|err| Error::Json { err: Box::new(err) })
+            },
+            #[cfg(feature = "serde-json5")]
+            Format::Json5 => {
+                use crate::json5::Serializer as $ser;
+                $call.map_err(|err| Error::Json5 { err: Box::new(err) })
+            },
+            #[cfg(feature = "serde-msgpack")]
+            Format::MessagePack => {
+                use crate::msgpack::Serializer as $ser;
+                $call.map_err(|err| Error::MessagePack { err: Box::new(err) })
+            },
+            #[cfg(feature = "serde-ron")]
+            Format::Ron => {
+                use crate::ron::Serializer as $ser;
+                $call.map_err(|err| Error::Ron { err: Box::new(err) })
+            },
+            #[cfg(feature = "serde-toml")]
+            Format::Toml => {
+                use crate::toml::Serializer as $ser;
+                $call.map_err(|err| Error::Toml { err: Box::new(err) })
+            },
+            #[cfg(feature = "serde-xml")]
+            Format::Xml => {
+                use crate::xml::Serializer as $ser;
+                $call.map_err(|err| Error::Xml { err: Box::new(err) })
+            },
+            #[cfg(feature = "serde-yaml")]
+            Format::Yaml => {
+                use crate::yaml::Serializer as $ser;
+                $call.map_err(|err| Error::Yaml { err: Box::new(err) })
+            },
+        }
+    };
+}
+
+
+
+
+
+/***** ERRORS *****/
+/// Defines the errors of any of the backends that a [`Format`] may select.
+///
+/// Note that this error is transparent, i.e., it simply shows the error of the wrapped backend.
+/// The backend errors are boxed to keep this error small, as some of them are quite large.
+#[derive(Debug)]
+pub enum Error {
+    /// The BSON backend failed.
+    #[cfg(feature = "serde-bson")]
+    Bson { err: Box<crate::bson::Error> },
+    /// The CBOR backend failed.
+    #[cfg(feature = "serde-cbor")]
+    Cbor { err: Box<crate::cbor::Error> },
+    /// The `.env` backend failed.
+    #[cfg(feature = "serde-dotenv")]
+    Dotenv { err: Box<crate::dotenv::Error> },
+    /// The INI backend failed.
+    #[cfg(feature = "serde-ini")]
+    Ini { err: Box<crate::ini::Error> },
+    /// The JSON backend failed.
+    #[cfg(feature = "serde-json")]
+    Json { err: Box<crate::json::Error> },
+    /// The JSON5 backend failed.
+    #[cfg(feature = "serde-json5")]
+    Json5 { err: Box<crate::json5::Error> },
+    /// The MessagePack backend failed.
+    #[cfg(feature = "serde-msgpack")]
+    MessagePack { err: Box<crate::msgpack::Error> },
+    /// The RON backend failed.
+    #[cfg(feature = "serde-ron")]
+    Ron { err: Box<crate::ron::Error> },
+    /// The TOML backend failed.
+    #[cfg(feature = "serde-toml")]
+    Toml { err: Box<crate::toml::Error> },
+    /// The XML backend failed.
+    #[cfg(feature = "serde-xml")]
+    Xml { err: Box<crate::xml::Error> },
+    /// The YAML backend failed.
+    #[cfg(feature = "serde-yaml")]
+    Yaml { err: Box<crate::yaml::Error> },
+}
+impl Error {
+    /// Returns the wrapped backend error.
+    ///
+    /// # Returns
+    /// A reference to the error of the backend that failed.
+    #[inline]
+    pub fn inner(&self) -> &(dyn 'static + error::Error) {
+        use Error::*;
+        match self {
+            #[cfg(feature = "serde-bson")]
+            Bson { err } => err,
+            #[cfg(feature = "serde-cbor")]
+            Cbor { err } => err,
+            #[cfg(feature = "serde-dotenv")]
+            Dotenv { err } => err,
+            #[cfg(feature = "serde-ini")]
+            Ini { err } => err,
+            #[cfg(feature = "serde-json")]
+            Json { err } => err,
+            #[cfg(feature = "serde-json5")]
+            Json5 { err } => err,
+            #[cfg(feature = "serde-msgpack")]
+            MessagePack { err } => err,
+            #[cfg(feature = "serde-ron")]
+            Ron { err } => err,
+            #[cfg(feature = "serde-toml")]
+            Toml { err } => err,
+            #[cfg(feature = "serde-xml")]
+            Xml { err } => err,
+            #[cfg(feature = "serde-yaml")]
+            Yaml { err } => err,
+        }
+    }
+
+    /// Finds where in the input this error occurred.
+    ///
+    /// Only the JSON, TOML and YAML backends track this. See their errors'
+    /// `location()`-functions for details.
+    ///
+    /// # Arguments
+    /// - `raw`: The input that was being deserialized, if it's available.
+    ///
+    /// # Returns
+    /// The [`Location`] of the error, or [`None`] if it has none (or its
+    /// backend doesn't know it).
+    #[inline]
+    pub fn location(&self, raw: Option<&str>) -> Option<Location> {
+        use Error::*;
+        match (self, raw) {
+            #[cfg(feature = "serde-bson")]
+            (Bson { .. }, _) => None,
+            #[cfg(feature = "serde-cbor")]
+            (Cbor { .. }, _) => None,
+            #[cfg(feature = "serde-dotenv")]
+            (Dotenv { .. }, _) => None,
+            #[cfg(feature = "serde-ini")]
+            (Ini { .. }, _) => None,
+            #[cfg(feature = "serde-json")]
+            (Json { err }, raw) => err.location(raw),
+            #[cfg(feature = "serde-json5")]
+            (Json5 { .. }, _) => None,
+            #[cfg(feature = "serde-msgpack")]
+            (MessagePack { .. }, _) => None,
+            #[cfg(feature = "serde-ron")]
+            (Ron { .. }, _) => None,
+            #[cfg(feature = "serde-toml")]
+            (Toml { err }, raw) => err.location(raw),
+            #[cfg(feature = "serde-xml")]
+            (Xml { .. }, _) => None,
+            #[cfg(feature = "serde-yaml")]
+            (Yaml { err }, raw) => err.location(raw),
+        }
+    }
+
+    /// Returns the path to the field at which this error occurred.
+    ///
+    /// Only the JSON, TOML and YAML backends track this.
+    ///
+    /// # Returns
+    /// A list of [`PathSegment`]s leading to the field that failed. It is
+    /// empty if the error has no path (or its backend doesn't know it).
+    #[inline]
+    pub fn field_path(&self) -> Vec<PathSegment> {
+        use Error::*;
+        match self {
+            #[cfg(feature = "serde-bson")]
+            Bson { .. } => Vec::new(),
+            #[cfg(feature = "serde-cbor")]
+            Cbor { .. } => Vec::new(),
+            #[cfg(feature = "serde-dotenv")]
+            Dotenv { .. } => Vec::new(),
+            #[cfg(feature = "serde-ini")]
+            Ini { .. } => Vec::new(),
+            #[cfg(feature = "serde-json")]
+            Json { err } => err.field_path(),
+            #[cfg(feature = "serde-json5")]
+            Json5 { .. } => Vec::new(),
+            #[cfg(feature = "serde-msgpack")]
+            MessagePack { .. } => Vec::new(),
+            #[cfg(feature = "serde-ron")]
+            Ron { .. } => Vec::new(),
+            #[cfg(feature = "serde-toml")]
+            Toml { err } => err.field_path(),
+            #[cfg(feature = "serde-xml")]
+            Xml { .. } => Vec::new(),
+            #[cfg(feature = "serde-yaml")]
+            Yaml { err } => err.field_path(),
+        }
+    }
+}
+impl Display for Error {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FResult { Display::fmt(self.inner(), f) }
+}
+impl error::Error for Error {
+    #[inline]
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> { self.inner().source() }
+}
+
+
+
+
+
+/***** HELPERS *****/
+/// Deserializes any map or sequence (ignoring its contents) but nothing else.
+///
+/// Used by [`detect_format()`] to check whether some input is a structured document in a
+/// particular format. Scalars are rejected because most text formats (most notably YAML) accept
+/// arbitrary text as a single string.
+struct Probe;
+impl Serialize for Probe {
+    #[inline]
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> { serializer.serialize_unit() }
+}
+impl<'de> Deserialize<'de> for Probe {
+    #[inline]
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        /// The visitor for the [`Probe`].
+        struct ProbeVisitor;
+        impl<'de> Visitor<'de> for ProbeVisitor {
+            type Value = Probe;
+
+            #[inline]
+            fn expecting(&self, f: &mut Formatter) -> FResult { write!(f, "a map or a sequence") }
+
+            #[inline]
+            fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+                while map.next_entry::<IgnoredAny, IgnoredAny>()?.is_some() {}
+                Ok(Probe)
+            }
+
+            #[inline]
+            fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+                while seq.next_element::<IgnoredAny>()?.is_some() {}
+                Ok(Probe)
+            }
+        }
+
+        deserializer.deserialize_any(ProbeVisitor)
+    }
+}
+
+
+
+/// Checks whether the given input looks like a [BSON](https://bsonspec.org) document.
+///
+/// BSON documents start with their total length as a little-endian 32-bit integer, and end with a
+/// null-byte.
+///
+/// # Arguments
+/// - `raw`: The input to check.
+///
+/// # Returns
+/// True if the framing of the input matches that of a BSON document, or false otherwise.
+#[cfg(feature = "serde-bson")]
+#[inline]
+fn is_bson(raw: &[u8]) -> bool {
+    raw.len() >= 5 && raw[..4] == (raw.len() as u32).to_le_bytes() && raw[raw.len() - 1] == 0x00
+}
+
+
+
+
+
+/***** LIBRARY *****/
+/// Selects one of the enabled [`Serializer`](crate::Serializer)-backends at runtime.
+///
+/// Only backends whose feature is enabled are available as variants. Backends that are not
+/// self-describing (bincode, postcard) or that only support collections of records (CSV) are not
+/// selectable.
+///
+/// # Examples
+/// ```rust
+/// # #[cfg(all(feature = "serde-json", feature = "serde-yaml", feature = "serde-toml"))] {
+/// use serializable::Format;
+///
+/// // Requires the `serde-json`, `serde-yaml` and `serde-toml`-features, respectively
+/// assert_eq!(Format::from_path("config.json"), Some(Format::Json));
+/// assert_eq!(Format::from_path("config.yml"), Some(Format::Yaml));
+/// assert_eq!(Format::from_path("config.YAML"), Some(Format::Yaml));
+/// assert_eq!(Format::from_path("config.toml"), Some(Format::Toml));
+/// assert_eq!(Format::from_path("config.txt"), None);
+///
+/// assert_eq!(Format::Json.to_string(&vec![1, 2, 3]).unwrap(), "[1,2,3]");
+/// assert_eq!(Format::Yaml.from_str::<Vec<u32>>("- 1\n- 2\n- 3\n").unwrap(), vec![1, 2, 3]);
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Format {
+    /// [BSON](https://bsonspec.org), using [`bson::Serializer`](crate::bson::Serializer).
+    #[cfg(feature = "serde-bson")]
+    Bson,
+    /// [CBOR](https://cbor.io), using [`cbor::Serializer`](crate::cbor::Serializer).
+    #[cfg(feature = "serde-cbor")]
+    Cbor,
+    /// `.env`-files, using [`dotenv::Serializer`](crate::dotenv::Serializer).
+    #[cfg(feature = "serde-dotenv")]
+    Dotenv,
+    /// INI-files, using [`ini::Serializer`](crate::ini::Serializer).
+    #[cfg(feature = "serde-ini")]
+    Ini,
+    /// [JSON](https://json.org), using [`json::Serializer`](crate::json::Serializer).
+    #[cfg(feature = "serde-json")]
+    Json,
+    /// [JSON5](https://json5.org), using [`json5::Serializer`](crate::json5::Serializer).
+    #[cfg(feature = "serde-json5")]
+    Json5,
+    /// [MessagePack](https://msgpack.org), using [`msgpack::Serializer`](crate::msgpack::Serializer).
+    #[cfg(feature = "serde-msgpack")]
+    MessagePack,
+    /// [RON](https://github.com/ron-rs/ron), using [`ron::Serializer`](crate::ron::Serializer).
+    #[cfg(feature = "serde-ron")]
+    Ron,
+    /// [TOML](https://toml.io), using [`toml::Serializer`](crate::toml::Serializer).
+    #[cfg(feature = "serde-toml")]
+    Toml,
+    /// [XML](https://www.w3.org/XML), using [`xml::Serializer`](crate::xml::Serializer) with its default options.
+    #[cfg(feature = "serde-xml")]
+    Xml,
+    /// [YAML](https://yaml.org), using [`yaml::Serializer`](crate::yaml::Serializer).
+    #[cfg(feature = "serde-yaml")]
+    Yaml,
+}
+impl Format {
+    /// All formats that are enabled in this build.
+    pub const ALL: &'static [Self] = &[
+        #[cfg(feature = "serde-bson")]
+        Self::Bson,
+        #[cfg(feature = "serde-cbor")]
+        Self::Cbor,
+        #[cfg(feature = "serde-dotenv")]
+        Self::Dotenv,
+        #[cfg(feature = "serde-ini")]
+        Self::Ini,
+        #[cfg(feature = "serde-json")]
+        Self::Json,
+        #[cfg(feature = "serde-json5")]
+        Self::Json5,
+        #[cfg(feature = "serde-msgpack")]
+        Self::MessagePack,
+        #[cfg(feature = "serde-ron")]
+        Self::Ron,
+        #[cfg(feature = "serde-toml")]
+        Self::Toml,
+        #[cfg(feature = "serde-xml")]
+        Self::Xml,
+        #[cfg(feature = "serde-yaml")]
+        Self::Yaml,
+    ];
+
+
+
+    /// Returns the file extensions (without leading dot) that are associated with this format.
+    ///
+    /// The first one is the preferred extension.
+    ///
+    /// # Returns
+    /// A list of extensions, in lowercase.
+    #[inline]
+    pub const fn extensions(&self) -> &'static [&'static str] {
+        match self {
+            #[cfg(feature = "serde-bson")]
+            Self::Bson => &["bson"],
+            #[cfg(feature = "serde-cbor")]
+            Self::Cbor => &["cbor"],
+            #[cfg(feature = "serde-dotenv")]
+            Self::Dotenv => &["env"],
+            #[cfg(feature = "serde-ini")]
+            Self::Ini => &["ini", "cfg"],
+            #[cfg(feature = "serde-json")]
+            Self::Json => &["json"],
+            #[cfg(feature = "serde-json5")]
+            Self::Json5 => &["json5", "jsonc"],
+            #[cfg(feature = "serde-msgpack")]
+            Self::MessagePack => &["msgpack", "mpk"],
+            #[cfg(feature = "serde-ron")]
+            Self::Ron => &["ron"],
+            #[cfg(feature = "serde-toml")]
+            Self::Toml => &["toml"],
+            #[cfg(feature = "serde-xml")]
+            Self::Xml => &["xml"],
+            #[cfg(feature = "serde-yaml")]
+            Self::Yaml => &["yml", "yaml"],
+        }
+    }
+
+    /// Finds the enabled format associated with the given file extension.
+    ///
+    /// # Arguments
+    /// - `ext`: The extension to find the format of, without leading dot. Matched
+    ///   case-insensitively.
+    ///
+    /// # Returns
+    /// The matching [`Format`], or [`None`] if no enabled format uses this extension.
+    #[inline]
+    pub fn from_extension(ext: impl AsRef<str>) -> Option<Self> {
+        let ext: &str = ext.as_ref();
+        Self::ALL.iter().find(|format| format.extensions().iter().any(|e| e.eq_ignore_ascii_case(ext))).copied()
+    }
+
+    /// Finds the enabled format associated with the extension of the given path.
+    ///
+    /// Files called `.env` are considered to have the `env`-extension. If the last extension is
+    /// that of an enabled [`Compression`] codec (e.g., `state.json.gz`), then
+    /// the extension before it is used instead.
+    ///
+    /// # Arguments
+    /// - `path`: The path to find the format of.
+    ///
+    /// # Returns
+    /// The matching [`Format`], or [`None`] if the path has no extension or no enabled format
+    /// uses it.
+    #[inline]
+    pub fn from_path(path: impl AsRef<Path>) -> Option<Self> {
+        let path: &Path = path.as_ref();
+        let path: Cow<Path> = if Compression::from_path(path).is_some() { Cow::Owned(path.with_extension("")) } else { Cow::Borrowed(path) };
+        let ext: &OsStr = match (path.extension(), path.file_name()) {
+            (Some(ext), _) => ext,
+            (None, Some(name)) if name == ".env" => OsStr::new("env"),
+            _ => return None,
+        };
+        Self::from_extension(ext.to_str()?)
+    }
+
+
+
+    /// Serializes the given value to a string using this format's backend.
+    ///
+    /// See [`Serializer::to_string()`](serializer::Serializer::to_string()) for more information.
+    #[inline]
+    pub fn to_string<T: for<'de> Deserialize<'de> + Serialize>(&self, value: &T) -> Result<String, Error> {
+        dispatch!(self, S => <S<T> as serializer::Serializer>::to_string(value))
+    }
+
+    /// Serializes the given value to a string using this format's pretty backend.
+    ///
+    /// See [`Serializer::to_string_pretty()`](serializer::Serializer::to_string_pretty()) for more
+    /// information.
+    #[inline]
+    pub fn to_string_pretty<T: for<'de> Deserialize<'de> + Serialize>(&self, value: &T) -> Result<String, Error> {
+        dispatch!(self, S => <S<T> as serializer::Serializer>::to_string_pretty(value))
+    }
+
+    /// Serializes the given value to a string using this format's pretty backend, honouring the
+    /// given [`PrettyOptions`] as far as the backend can.
+    ///
+    /// See [`Serializer::to_string_pretty_with()`](serializer::Serializer::to_string_pretty_with())
+    /// for more information.
+    #[inline]
+    pub fn to_string_pretty_with<T: for<'de> Deserialize<'de> + Serialize>(&self, value: &T, opts: &PrettyOptions) -> Result<String, Error> {
+        dispatch!(self, S => <S<T> as serializer::Serializer>::to_string_pretty_with(value, opts))
+    }
+
+    /// Serializes the given value to a byte buffer using this format's backend.
+    ///
+    /// See [`Serializer::to_bytes()`](serializer::Serializer::to_bytes()) for more information.
+    #[inline]
+    pub fn to_bytes<T: for<'de> Deserialize<'de> + Serialize>(&self, value: &T) -> Result<Vec<u8>, Error> {
+        dispatch!(self, S => <S<T> as serializer::Serializer>::to_bytes(value))
+    }
+
+    /// Serializes the given value to a byte buffer using this format's pretty backend.
+    ///
+    /// See [`Serializer::to_bytes_pretty()`](serializer::Serializer::to_bytes_pretty()) for more
+    /// information.
+    #[inline]
+    pub fn to_bytes_pretty<T: for<'de> Deserialize<'de> + Serialize>(&self, value: &T) -> Result<Vec<u8>, Error> {
+        dispatch!(self, S => <S<T> as serializer::Serializer>::to_bytes_pretty(value))
+    }
+
+    /// Serializes the given value to a byte buffer using this format's pretty backend, honouring
+    /// the given [`PrettyOptions`] as far as the backend can.
+    ///
+    /// See [`Serializer::to_bytes_pretty_with()`](serializer::Serializer::to_bytes_pretty_with())
+    /// for more information.
+    #[inline]
+    pub fn to_bytes_pretty_with<T: for<'de> Deserialize<'de> + Serialize>(&self, value: &T, opts: &PrettyOptions) -> Result<Vec<u8>, Error> {
+        dispatch!(self, S => <S<T> as serializer::Serializer>::to_bytes_pretty_with(value, opts))
+    }
+
+    /// Serializes the given value to the given writer using this format's backend.
+    ///
+    /// See [`Serializer::to_writer()`](serializer::Serializer::to_writer()) for more information.
+    #[inline]
+    pub fn to_writer<T: for<'de> Deserialize<'de> + Serialize>(&self, value: &T, writer: impl Write) -> Result<(), Error> {
+        dispatch!(self, S => <S<T> as serializer::Serializer>::to_writer(value, writer))
+    }
+
+    /// Serializes the given value to the given writer using this format's pretty backend.
+    ///
+    /// See [`Serializer::to_writer_pretty()`](serializer::Serializer::to_writer_pretty()) for more
+    /// information.
+    #[inline]
+    pub fn to_writer_pretty<T: for<'de> Deserialize<'de> + Serialize>(&self, value: &T, writer: impl Write) -> Result<(), Error> {
+        dispatch!(self, S => <S<T> as serializer::Serializer>::to_writer_pretty(value, writer))
+    }
+
+    /// Serializes the given value to the given writer using this format's pretty backend,
+    /// honouring the given [`PrettyOptions`] as far as the backend can.
+    ///
+    /// See [`Serializer::to_writer_pretty_with()`](serializer::Serializer::to_writer_pretty_with())
+    /// for more information.
+    #[inline]
+    pub fn to_writer_pretty_with<T: for<'de> Deserialize<'de> + Serialize>(
+        &self,
+        value: &T,
+        opts: &PrettyOptions,
+        writer: impl Write,
+    ) -> Result<(), Error> {
+        dispatch!(self, S => <S<T> as serializer::Serializer>::to_writer_pretty_with(value, opts, writer))
+    }
+
+    /// Deserializes the given string using this format's backend.
+    ///
+    /// See [`Serializer::from_str()`](serializer::Serializer::from_str()) for more information.
+    #[inline]
+    pub fn from_str<T: for<'de> Deserialize<'de> + Serialize>(&self, raw: impl AsRef<str>) -> Result<T, Error> {
+        dispatch!(self, S => <S<T> as serializer::Serializer>::from_str(raw))
+    }
+
+    /// Deserializes the given byte buffer using this format's backend.
+    ///
+    /// See [`Serializer::from_bytes()`](serializer::Serializer::from_bytes()) for more information.
+    #[inline]
+    pub fn from_bytes<T: for<'de> Deserialize<'de> + Serialize>(&self, raw: impl AsRef<[u8]>) -> Result<T, Error> {
+        dispatch!(self, S => <S<T> as serializer::Serializer>::from_bytes(raw))
+    }
+
+    /// Deserializes the contents of the given reader using this format's backend.
+    ///
+    /// See [`Serializer::from_reader()`](serializer::Serializer::from_reader()) for more
+    /// information.
+    #[inline]
+    pub fn from_reader<T: for<'de> Deserialize<'de> + Serialize>(&self, reader: impl Read) -> Result<T, Error> {
+        dispatch!(self, S => <S<T> as serializer::Serializer>::from_reader(reader))
+    }
+}
+impl Display for Format {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FResult {
+        match self {
+            #[cfg(feature = "serde-bson")]
+            Self::Bson => write!(f, "BSON"),
+            #[cfg(feature = "serde-cbor")]
+            Self::Cbor => write!(f, "CBOR"),
+            #[cfg(feature = "serde-dotenv")]
+            Self::Dotenv => write!(f, ".env"),
+            #[cfg(feature = "serde-ini")]
+            Self::Ini => write!(f, "INI"),
+            #[cfg(feature = "serde-json")]
+            Self::Json => write!(f, "JSON"),
+            #[cfg(feature = "serde-json5")]
+            Self::Json5 => write!(f, "JSON5"),
+            #[cfg(feature = "serde-msgpack")]
+            Self::MessagePack => write!(f, "MessagePack"),
+            #[cfg(feature = "serde-ron")]
+            Self::Ron => write!(f, "RON"),
+            #[cfg(feature = "serde-toml")]
+            Self::Toml => write!(f, "TOML"),
+            #[cfg(feature = "serde-xml")]
+            Self::Xml => write!(f, "XML"),
+            #[cfg(feature = "serde-yaml")]
+            Self::Yaml => write!(f, "YAML"),
+        }
+    }
+}
+
+
+
+/// Attempts to guess the [`Format`] of the given input.
+///
+/// Only formats that are enabled can be detected. The input is first classified as binary (if it
+/// isn't valid UTF-8 or contains null-bytes) or text, after which the candidate formats of that
+/// class are tried in order of how strict they are. An input only matches a format if it parses
+/// as a map or a sequence in that format; plain scalars are never detected.
+///
+/// Text formats are tried in the order JSON, JSON5, TOML, XML, RON, YAML, `.env` and INI.
+/// Binary formats are BSON (which is recognized by its framing) and CBOR and MessagePack (of which
+/// the most likely one based on the first byte is tried first).
+///
+/// Note that some inputs are valid in multiple formats (e.g., a `.env`-file is also a valid
+/// INI-file). As such, this is a best-effort guess that does not replace an explicit format.
+///
+/// # Arguments
+/// - `raw`: The input to guess the format of.
+///
+/// # Returns
+/// The detected [`Format`], or [`None`] if the input doesn't look like any of the enabled formats.
+///
+/// # Examples
+/// ```rust
+/// # #[cfg(all(feature = "serde-json", feature = "serde-yaml", feature = "serde-toml", feature = "serde-msgpack"))] {
+/// use serializable::{detect_format, Format};
+///
+/// // Requires the `serde-json`, `serde-yaml`, `serde-toml` and `serde-msgpack`-features, respectively
+/// assert_eq!(detect_format(b"{ \"hello\": \"world\" }"), Some(Format::Json));
+/// assert_eq!(detect_format(b"hello: world\n"), Some(Format::Yaml));
+/// assert_eq!(detect_format(b"[hello]\nworld = 42\n"), Some(Format::Toml));
+/// assert_eq!(detect_format(b"\x81\xa5hello\xa5world"), Some(Format::MessagePack));
+/// assert_eq!(detect_format(b"Hello, world!"), None);
+/// # }
+/// ```
+pub fn detect_format(raw: &[u8]) -> Option<Format> {
+    /// Returns whether the given input parses in the given format.
+    #[inline]
+    fn probe(format: Format, raw: &[u8]) -> bool { format.from_bytes::<Probe>(raw).is_ok() }
+
+    // Classify the input as binary or text
+    let text: Option<&str> = std::str::from_utf8(raw).ok().filter(|text| !text.contains('\0'));
+    let Some(text) = text else {
+        // Binary format; BSON we can recognize by its framing
+        #[cfg(feature = "serde-bson")]
+        if is_bson(raw) && probe(Format::Bson, raw) {
+            return Some(Format::Bson);
+        }
+
+        // CBOR and MessagePack have overlapping encodings, so use the first byte to see which is more likely
+        // (CBOR maps and tags use a range that are strings and scalars in MessagePack, and MessagePack maps use a range of small arrays in CBOR)
+        let candidates: &[Format] = match raw.first() {
+            Some(0xa0..=0xdb) => &[
+                #[cfg(feature = "serde-cbor")]
+                Format::Cbor,
+                #[cfg(feature = "serde-msgpack")]
+                Format::MessagePack,
+            ],
+            _ => &[
+                #[cfg(feature = "serde-msgpack")]
+                Format::MessagePack,
+                #[cfg(feature = "serde-cbor")]
+                Format::Cbor,
+            ],
+        };
+        return candidates.iter().find(|format| probe(**format, raw)).copied();
+    };
+
+    // Text format; skip any BOM and leading whitespace
+    let text: &str = text.trim_start_matches('\u{feff}').trim_start();
+    if text.is_empty() {
+        return None;
+    }
+
+    // Try the formats from strict to lenient
+    let candidates: &[Format] = &[
+        #[cfg(feature = "serde-json")]
+        Format::Json,
+        #[cfg(feature = "serde-json5")]
+        Format::Json5,
+        #[cfg(feature = "serde-toml")]
+        Format::Toml,
+        #[cfg(feature = "serde-xml")]
+        Format::Xml,
+        #[cfg(feature = "serde-ron")]
+        Format::Ron,
+        #[cfg(feature = "serde-yaml")]
+        Format::Yaml,
+        #[cfg(feature = "serde-dotenv")]
+        Format::Dotenv,
+        #[cfg(feature = "serde-ini")]
+        Format::Ini,
+    ];
+    candidates
+        .iter()
+        .find(|format| {
+            // Only consider XML if it actually looks like it, as quick-xml is quite lenient
+            #[cfg(feature = "serde-xml")]
+            if **format == Format::Xml && !text.starts_with('<') {
+                return false;
+            }
+            probe(**format, text.as_bytes())
+        })
+        .copied()
+}
+
+
+
+/// Provides functions that select the [`Format`] at runtime (based on a file's extension or the
+/// contents of the input), instead of using the backend of a particular
+/// [`Serializable`](crate::Serializable).
+///
+/// Unlike [`Serializable`](crate::Serializable), this trait is not generic over a backend. As
+/// such, it can also be used on types that implement [`Serializable`](crate::Serializable) for
+/// multiple backends (which is typically the case for types that can be read from several formats)
+/// without running into type inference errors.
+///
+/// It is automatically implemented for all types that can be serialized and deserialized with
+/// [`serde`].
+///
+/// # Examples
+/// ```rust
+/// # #[cfg(all(feature = "serde-json", feature = "serde-yaml"))] {
+/// use std::path::PathBuf;
+///
+/// use serde::{Deserialize, Serialize};
+/// use serializable::{json, yaml, Serializable, SerializableAuto as _};
+///
+/// #[derive(Debug, Deserialize, Eq, PartialEq, Serialize)]
+/// struct HelloWorld {
+///     hello: String,
+///     world: String,
+/// }
+/// impl Serializable<json::Serializer<HelloWorld>> for HelloWorld {}
+/// impl Serializable<yaml::Serializer<HelloWorld>> for HelloWorld {}
+///
+/// let path: PathBuf = std::env::temp_dir().join("test_auto.yml");
+/// HelloWorld { hello: "Hello".into(), world: "World".into() }.to_path_auto(&path).unwrap();
+/// assert_eq!(std::fs::read_to_string(&path).unwrap(), "hello: Hello\nworld: World\n");
+/// assert_eq!(HelloWorld::from_path_auto(&path).unwrap(), HelloWorld { hello: "Hello".into(), world: "World".into() });
+/// # }
+/// ```
+pub trait SerializableAuto: Sized + for<'de> Deserialize<'de> + Serialize {
+    /// Convenience function for serializing this object to a file, using the [`Format`]
+    /// associated with its extension.
+    ///
+    /// # Arguments
+    /// - `path`: The path to serialize this object to.
+    ///
+    /// # Errors
+    /// This function may error with an [`Error::UnknownFormat`](crate::Error::UnknownFormat) if
+    /// the extension of `path` is unknown or its backend is not enabled, an
+    /// [`Error::FileCreate`](crate::Error::FileCreate) if it failed to create a new file, or an
+    /// [`Error::SerializeFile`](crate::Error::SerializeFile) if the selected backend failed to
+    /// serialize. This may also be because it failed to write to the file.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # #[cfg(all(feature = "serde-json", feature = "serde-yaml"))] {
+    /// use std::path::PathBuf;
+    ///
+    /// use serde::{Deserialize, Serialize};
+    /// use serializable::{json, yaml, Serializable, SerializableAuto as _};
+    ///
+    /// #[derive(Debug, Deserialize, Eq, PartialEq, Serialize)]
+    /// struct HelloWorld {
+    ///     hello: String,
+    ///     world: String,
+    /// }
+    /// impl Serializable<json::Serializer<HelloWorld>> for HelloWorld {}
+    /// impl Serializable<yaml::Serializer<HelloWorld>> for HelloWorld {}
+    ///
+    /// let path: PathBuf = std::env::temp_dir().join("test_to_path_auto.yml");
+    /// HelloWorld { hello: "Hello".into(), world: "World".into() }.to_path_auto(&path).unwrap();
+    /// assert_eq!(std::fs::read_to_string(path).unwrap(), "hello: Hello\nworld: World\n");
+    ///
+    /// let path: PathBuf = std::env::temp_dir().join("test_to_path_auto.txt");
+    /// assert!(matches!(
+    ///     HelloWorld { hello: "Hello".into(), world: "World".into() }.to_path_auto(&path),
+    ///     Err(serializable::Error::UnknownFormat { .. })
+    /// ));
+    /// # }
+    /// ```
+    #[inline]
+    fn to_path_auto(&self, path: impl AsRef<Path>) -> Result<(), crate::Error<Error>> { self.to_path_pretty_opt_auto(path, false) }
+    /// Convenience function for serializing this object to a file, using the pretty backend of
+    /// the [`Format`] associated with its extension.
+    ///
+    /// # Arguments
+    /// - `path`: The path to serialize this object to.
+    ///
+    /// # Errors
+    /// This function may error with an [`Error::UnknownFormat`](crate::Error::UnknownFormat) if
+    /// the extension of `path` is unknown or its backend is not enabled, an
+    /// [`Error::FileCreate`](crate::Error::FileCreate) if it failed to create a new file, or an
+    /// [`Error::SerializeFile`](crate::Error::SerializeFile) if the selected backend failed to
+    /// serialize. This may also be because it failed to write to the file.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # #[cfg(all(feature = "serde-json", feature = "serde-yaml"))] {
+    /// use std::path::PathBuf;
+    ///
+    /// use serde::{Deserialize, Serialize};
+    /// use serializable::{json, yaml, Serializable, SerializableAuto as _};
+    ///
+    /// #[derive(Debug, Deserialize, Eq, PartialEq, Serialize)]
+    /// struct HelloWorld {
+    ///     hello: String,
+    ///     world: String,
+    /// }
+    /// impl Serializable<json::Serializer<HelloWorld>> for HelloWorld {}
+    /// impl Serializable<yaml::Serializer<HelloWorld>> for HelloWorld {}
+    ///
+    /// let path: PathBuf = std::env::temp_dir().join("test_to_path_pretty_auto.json");
+    /// HelloWorld { hello: "Hello".into(), world: "World".into() }.to_path_pretty_auto(&path).unwrap();
+    /// assert_eq!(std::fs::read_to_string(path).unwrap(), "{\n  \"hello\": \"Hello\",\n  \"world\": \"World\"\n}");
+    /// # }
+    /// ```
+    #[inline]
+    fn to_path_pretty_auto(&self, path: impl AsRef<Path>) -> Result<(), crate::Error<Error>> { self.to_path_pretty_opt_auto(path, true) }
+    /// Convenience function for serializing this object to a file using dynamic prettyness, using
+    /// the [`Format`] associated with its extension.
+    ///
+    /// Pretty output uses the default [`PrettyOptions`] of the selected backend.
+    ///
+    /// # Arguments
+    /// - `path`: The path to serialize this object to.
+    /// - `pretty`: Whether to use the pretty formatter or not.
+    ///
+    /// # Errors
+    /// This function may error with an [`Error::UnknownFormat`](crate::Error::UnknownFormat) if
+    /// the extension of `path` is unknown or its backend is not enabled, an
+    /// [`Error::FileCreate`](crate::Error::FileCreate) if it failed to create a new file, or an
+    /// [`Error::SerializeFile`](crate::Error::SerializeFile) if the selected backend failed to
+    /// serialize. This may also be because it failed to write to the file.
+    fn to_path_pretty_opt_auto(&self, path: impl AsRef<Path>, pretty: bool) -> Result<(), crate::Error<Error>> {
+        // Find the format
+        let path: &Path = path.as_ref();
+        let format: Format = match Format::from_path(path) {
+            Some(format) => format,
+            None => return Err(crate::Error::UnknownFormat { path: path.into() }),
+        };
+
+        // Open the file as a writer
+        let handle: File = match File::create(path) {
+            Ok(handle) => handle,
+            Err(err) => {
+                return Err(crate::Error::FileCreate { path: path.into(), err });
+            },
+        };
+
+        // Pass to the writer impl of the format
+        write_compressed(handle, path, Compression::from_path(path), |w| {
+            let res: Result<(), Error> = if pretty { format.to_writer_pretty(self, w) } else { format.to_writer(self, w) };
+            res.map_err(|err| crate::Error::SerializeFile { what: type_name::<Self>(), path: path.into(), err })
+        })?;
+
+        // Remove any checksum files, as they no longer match
+        write_checksum(path, None, false)
+    }
+    /// Convenience function for deserializing this object from a file, using the [`Format`]
+    /// associated with its extension.
+    ///
+    /// # Arguments
+    /// - `path`: The path to deserialize this object from.
+    ///
+    /// # Returns
+    /// A new Self that represents the deserialized object.
+    ///
+    /// # Errors
+    /// This function may error with an [`Error::UnknownFormat`](crate::Error::UnknownFormat) if
+    /// the extension of `path` is unknown or its backend is not enabled, an
+    /// [`Error::FileOpen`](crate::Error::FileOpen) if it failed to open the file, or an
+    /// [`Error::DeserializeFile`](crate::Error::DeserializeFile) if the selected backend failed to
+    /// deserialize. This may also be because it failed to read from the file.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # #[cfg(all(feature = "serde-json", feature = "serde-toml"))] {
+    /// use std::path::PathBuf;
+    ///
+    /// use serde::{Deserialize, Serialize};
+    /// use serializable::{json, toml, Serializable, SerializableAuto as _};
+    ///
+    /// #[derive(Debug, Deserialize, Eq, PartialEq, Serialize)]
+    /// struct HelloWorld {
+    ///     hello: String,
+    ///     world: String,
+    /// }
+    /// impl Serializable<json::Serializer<HelloWorld>> for HelloWorld {}
+    /// impl Serializable<toml::Serializer<HelloWorld>> for HelloWorld {}
+    ///
+    /// let path: PathBuf = std::env::temp_dir().join("test_from_path_auto.toml");
+    /// std::fs::write(&path, "hello = \"Goodbye\"\nworld = \"Planet\"\n").unwrap();
+    /// assert_eq!(HelloWorld::from_path_auto(path).unwrap(), HelloWorld { hello: "Goodbye".into(), world: "Planet".into() });
+    /// # }
+    /// ```
+    fn from_path_auto(path: impl AsRef<Path>) -> Result<Self, crate::Error<Error>> {
+        // Find the format
+        let path: &Path = path.as_ref();
+        let format: Format = match Format::from_path(path) {
+            Some(format) => format,
+            None => return Err(crate::Error::UnknownFormat { path: path.into() }),
+        };
+
+        // Open the file as a reader
+        let handle: File = match File::open(path) {
+            Ok(handle) => handle,
+            Err(err) => {
+                return Err(crate::Error::FileOpen { path: path.into(), err });
+            },
+        };
+
+        // Verify the file if it has a checksum, then pass to the reader impl of the format (decompressing if needed)
+        let res: Result<Self, Error> = match read_verified(&handle, path)? {
+            Some(raw) => read_decoded(raw.as_slice(), path, |dec| format.from_reader(dec))?,
+            None => read_decoded(handle, path, |dec| format.from_reader(dec))?,
+        };
+        match res {
+            Ok(res) => Ok(res),
+            Err(err) => {
+                // Read the file again to find the location and offending line
+                let raw: Option<String> = read_raw(path);
+                let loc: Option<Location> = err.location(raw.as_deref());
+                Err(crate::Error::DeserializeFile { what: type_name::<Self>(), path: path.into(), ctx: context(err.field_path(), loc, raw.as_deref()), err })
+            },
+        }
+    }
+    /// Convenience function for deserializing this object from the given byte buffer, using the
+    /// [`Format`] detected from its contents.
+    ///
+    /// See [`detect_format()`] for how the format is detected.
+    ///
+    /// # Arguments
+    /// - `raw`: The raw buffer that provides a serialized instantiation of Self.
+    ///
+    /// # Returns
+    /// A new Self that represents the deserialized counterpart.
+    ///
+    /// # Errors
+    /// This function may error with an [`Error::UndetectedFormat`](crate::Error::UndetectedFormat)
+    /// if the format of `raw` could not be detected, or an
+    /// [`Error::DeserializeBytes`](crate::Error::DeserializeBytes) if the detected backend failed
+    /// to deserialize.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # #[cfg(all(feature = "serde-json", feature = "serde-yaml"))] {
+    /// use serde::{Deserialize, Serialize};
+    /// use serializable::{json, yaml, Serializable, SerializableAuto as _};
+    ///
+    /// #[derive(Debug, Deserialize, Eq, PartialEq, Serialize)]
+    /// struct HelloWorld {
+    ///     hello: String,
+    ///     world: String,
+    /// }
+    /// impl Serializable<json::Serializer<HelloWorld>> for HelloWorld {}
+    /// impl Serializable<yaml::Serializer<HelloWorld>> for HelloWorld {}
+    ///
+    /// assert_eq!(
+    ///     HelloWorld::from_bytes_detect(b"{ \"hello\": \"Hello\", \"world\": \"World\" }").unwrap(),
+    ///     HelloWorld { hello: "Hello".into(), world: "World".into() }
+    /// );
+    /// assert_eq!(
+    ///     HelloWorld::from_bytes_detect(b"hello: Goodbye\nworld: Planet\n").unwrap(),
+    ///     HelloWorld { hello: "Goodbye".into(), world: "Planet".into() }
+    /// );
+    /// assert!(matches!(HelloWorld::from_bytes_detect(b"Hello, world!"), Err(serializable::Error::UndetectedFormat)));
+    /// # }
+    /// ```
+    fn from_bytes_detect(raw: impl AsRef<[u8]>) -> Result<Self, crate::Error<Error>> {
+        let raw: &[u8] = raw.as_ref();
+        let format: Format = match detect_format(raw) {
+            Some(format) => format,
+            None => return Err(crate::Error::UndetectedFormat),
+        };
+        match format.from_bytes(raw) {
+            Ok(res) => Ok(res),
+            Err(err) => Err(crate::Error::DeserializeBytes { what: type_name::<Self>(), ctx: context(err.field_path(), None, None), err }),
+        }
+    }
+    /// Convenience function for deserializing this object from the given reader, using the
+    /// [`Format`] detected from its contents.
+    ///
+    /// Note that this reads the entire `reader` into memory first, as its contents have to be
+    /// inspected before they can be deserialized. See [`detect_format()`] for how the format is
+    /// detected.
+    ///
+    /// # Arguments
+    /// - `reader`: The [`Read`]er that provides a serialized instantiation of Self.
+    ///
+    /// # Returns
+    /// A new Self that represents the deserialized counterpart.
+    ///
+    /// # Errors
+    /// This function may error with an [`Error::ReaderRead`](crate::Error::ReaderRead) if it
+    /// failed to read from the given `reader`, an
+    /// [`Error::UndetectedFormat`](crate::Error::UndetectedFormat) if the format of its contents
+    /// could not be detected, or an [`Error::DeserializeReader`](crate::Error::DeserializeReader)
+    /// if the detected backend failed to deserialize.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # #[cfg(all(feature = "serde-json", feature = "serde-toml"))] {
+    /// use serde::{Deserialize, Serialize};
+    /// use serializable::{json, toml, Serializable, SerializableAuto as _};
+    ///
+    /// #[derive(Debug, Deserialize, Eq, PartialEq, Serialize)]
+    /// struct HelloWorld {
+    ///     hello: String,
+    ///     world: String,
+    /// }
+    /// impl Serializable<json::Serializer<HelloWorld>> for HelloWorld {}
+    /// impl Serializable<toml::Serializer<HelloWorld>> for HelloWorld {}
+    ///
+    /// assert_eq!(
+    ///     HelloWorld::from_reader_detect("hello = \"Goodbye\"\nworld = \"Planet\"\n".as_bytes()).unwrap(),
+    ///     HelloWorld { hello: "Goodbye".into(), world: "Planet".into() }
+    /// );
+    /// # }
+    /// ```
+    fn from_reader_detect(mut reader: impl Read) -> Result<Self, crate::Error<Error>> {
+        // Buffer the entire reader first
+        let mut raw: Vec<u8> = Vec::new();
+        if let Err(err) = reader.read_to_end(&mut raw) {
+            return Err(crate::Error::ReaderRead { err });
+        }
+
+        // Then deserialize as bytes
+        match Self::from_bytes_detect(&raw) {
+            Ok(res) => Ok(res),
+            Err(crate::Error::DeserializeBytes { what, ctx, err }) => {
+                // We still have the input, so we can also find the offending line
+                let raw: Option<&str> = std::str::from_utf8(&raw).ok();
+                let loc: Option<Location> = err.location(raw);
+                Err(crate::Error::DeserializeReader { what, ctx: context(ctx.field_path, loc, raw), err })
+            },
+            Err(err) => Err(err),
+        }
+    }
+}
+impl<T: for<'de> Deserialize<'de> + Serialize> SerializableAuto for T {}
@@ -0,0 +1,348 @@
+//  INI.rs
+//    by Lut99
+//
+//  Created:
+//    16 Oct 2026, 21:21:10
+//  Last edited:
+//    17 Oct 2026, 10:14:52
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Implements [`serializer::Serializer`] and cohorts for INI-files.
+//
+
+use std::error;
+use std::fmt::{Display, Formatter, Result as FResult, Write as _};
+use std::io::{Read, Write};
+use std::marker::PhantomData;
+
+use serde::{Deserialize, Serialize};
+
+pub use crate::flat::Error as FlatError;
+use crate::flat::{check_key, quote, to_entries, unquote, Node, NodeDeserializer};
+use crate::serializer;
+
+
+/***** CONSTANTS *****/
+/// The characters that start a comment in an INI-file.
+const COMMENTS: &[char] = &[';', '#'];
+
+/// The characters that may not occur in the keys (and section names) of an INI-file.
+const INVALID_KEY: &[char] = &['=', '[', ']', ';', '#', '\n', '\r'];
+
+
+
+
+
+/***** ERRORS *****/
+/// Defines errors that occur when using the INI [`Serializer`].
+#[derive(Debug)]
+pub enum Error {
+    /// Failed to write to the given writer.
+    Write { err: std::io::Error },
+    /// Failed to read from the given reader.
+    Read { err: std::io::Error },
+    /// Failed to flush the given writer.
+    #[cfg(feature = "async-tokio")]
+    Flush { err: std::io::Error },
+    /// Failed to serialize the object to INI.
+    Serialize { err: FlatError },
+    /// Failed to deserialize the object from INI.
+    Deserialize { err: FlatError },
+}
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FResult {
+        use Error::*;
+        match self {
+            Write { .. } => write!(f, "Failed to write to given writer"),
+            Read { .. } => write!(f, "Failed to read from given reader"),
+            #[cfg(feature = "async-tokio")]
+            Flush { .. } => write!(f, "Failed to flush the given writer"),
+            Serialize { .. } => write!(f, "Failed to serialize to INI"),
+            Deserialize { .. } => write!(f, "Failed to deserialize from INI"),
+        }
+    }
+}
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        use Error::*;
+        match self {
+            Write { err } => Some(err),
+            Read { err } => Some(err),
+            #[cfg(feature = "async-tokio")]
+            Flush { err } => Some(err),
+            Serialize { err } => Some(err),
+            Deserialize { err } => Some(err),
+        }
+    }
+}
+
+
+
+
+
+/***** HELPER FUNCTIONS *****/
+/// Writes serialized entries as an INI-file.
+///
+/// Top-level scalars are written first, as they would otherwise end up in the preceding section.
+///
+/// # Arguments
+/// - `entries`: The serialized entries to write.
+/// - `pretty`: Whether to put spaces around the `=` and empty lines between sections.
+///
+/// # Returns
+/// The serialized INI-file.
+///
+/// # Errors
+/// This function errors if any of the keys or section names cannot be written.
+fn write(entries: &[(String, Node)], pretty: bool) -> Result<String, FlatError> {
+    let sep: &str = if pretty { " = " } else { "=" };
+    let mut res: String = String::new();
+    for (key, node) in entries {
+        if let Node::Value(value) = node {
+            check_key(key, INVALID_KEY)?;
+            writeln!(res, "{key}{sep}{}", quote(value, COMMENTS, false)).unwrap();
+        }
+    }
+    for (name, node) in entries {
+        if let Node::Table(table) = node {
+            if pretty && !res.is_empty() {
+                res.push('\n');
+            }
+            check_key(name, INVALID_KEY)?;
+            writeln!(res, "[{name}]").unwrap();
+            for (key, node) in table {
+                // NOTE: Serialization never nests tables more than one level deep
+                if let Node::Value(value) = node {
+                    check_key(key, INVALID_KEY)?;
+                    writeln!(res, "{key}{sep}{}", quote(value, COMMENTS, false)).unwrap();
+                }
+            }
+        }
+    }
+    Ok(res)
+}
+
+/// Parses an INI-file to entries that can be deserialized.
+///
+/// # Arguments
+/// - `raw`: The INI-file to parse.
+///
+/// # Returns
+/// The top-level keys, followed by every section as a table. Sections that occur multiple times are merged.
+///
+/// # Errors
+/// This function errors if the input is not a valid INI-file.
+fn parse(raw: &str) -> Result<Vec<(String, Node)>, FlatError> {
+    let mut entries: Vec<(String, Node)> = Vec::new();
+    let mut section: Option<usize> = None;
+    for (i, line) in raw.lines().enumerate() {
+        let line: &str = line.trim();
+        if line.is_empty() || line.starts_with(COMMENTS) {
+            continue;
+        }
+
+        // Either parse a section header...
+        if let Some(header) = line.strip_prefix('[') {
+            let name: &str = match header.split_once(']') {
+                Some((name, rest)) if rest.trim().is_empty() || rest.trim_start().starts_with(COMMENTS) => name.trim(),
+                Some(_) => return Err(FlatError::syntax(i + 1, "unexpected characters after section header")),
+                None => return Err(FlatError::syntax(i + 1, "unterminated section header")),
+            };
+            section = Some(match entries.iter().position(|(key, node)| key == name && matches!(node, Node::Table(_))) {
+                Some(pos) => pos,
+                None => {
+                    entries.push((name.into(), Node::Table(Vec::new())));
+                    entries.len() - 1
+                },
+            });
+            continue;
+        }
+
+        // ...or a key/value pair
+        let (key, value): (&str, &str) = match line.split_once('=') {
+            Some((key, value)) if !key.trim().is_empty() => (key.trim(), value),
+            _ => return Err(FlatError::syntax(i + 1, "expected a section header or a key/value pair")),
+        };
+        let entry: (String, Node) = (key.into(), Node::Value(unquote(value, COMMENTS, i + 1)?));
+        match section {
+            Some(pos) => {
+                if let Node::Table(table) = &mut entries[pos].1 {
+                    table.push(entry);
+                }
+            },
+            None => entries.push(entry),
+        }
+    }
+    Ok(entries)
+}
+
+
+
+
+
+/***** LIBRARY *****/
+/// Implements a [`serializer::Serializer`] for INI-files.
+///
+/// Top-level fields of the serialized struct (or map) become keys outside any section, whereas
+/// fields that are structs (or maps) themselves become sections. Deeper nesting, sequences and
+/// enums with data cannot be represented, and result in an error.
+///
+/// When deserializing, keys are matched case-insensitively and every value is parsed to the
+/// type of the field it is deserialized to. Booleans may be written as `true`/`false`,
+/// `yes`/`no`, `on`/`off` or `1`/`0`. Lines starting with `;` or `#` are ignored as comments.
+///
+/// # Examples
+/// ```rust
+/// use serde::{Deserialize, Serialize};
+/// use serializable::ini::Serializer;
+/// use serializable::Serializable;
+///
+/// #[derive(Debug, Deserialize, Eq, PartialEq, Serialize)]
+/// struct Database {
+///     host: String,
+///     port: u16,
+/// }
+///
+/// #[derive(Debug, Deserialize, Eq, PartialEq, Serialize)]
+/// struct Config {
+///     name: String,
+///     database: Database,
+/// }
+/// impl Serializable<Serializer<Config>> for Config {}
+///
+/// let config = Config { name: "app".into(), database: Database { host: "localhost".into(), port: 5432 } };
+/// assert_eq!(config.to_string().unwrap(), "name=app\n[database]\nhost=localhost\nport=5432\n");
+/// assert_eq!(config.to_string_pretty().unwrap(), "name = app\n\n[database]\nhost = localhost\nport = 5432\n");
+///
+/// assert_eq!(
+///     Config::from_str("; Legacy config\nName = app\n\n[Database]\nhost = localhost ; the host\nport = 5432\n").unwrap(),
+///     config
+/// );
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct Serializer<T>(PhantomData<T>);
+
+/// Marks the INI [`Serializer`] as a [`Backend`](crate::Backend).
+///
+/// Use it to select this backend in [`SerializableAs`](crate::SerializableAs) (e.g.,
+/// `value.to_string_as::<Ini>()`).
+#[derive(Clone, Copy, Debug)]
+pub struct Ini;
+impl serializer::Backend for Ini {
+    type Serializer<T> = Serializer<T>;
+}
+
+impl<T: for<'de> Deserialize<'de> + Serialize> serializer::Serializer for Serializer<T> {
+    type Error = Error;
+    type Target = T;
+
+    #[inline]
+    fn to_string(value: &Self::Target) -> Result<String, Self::Error> {
+        to_entries(value).and_then(|entries| write(&entries, false)).map_err(|err| Error::Serialize { err })
+    }
+
+    #[inline]
+    fn to_string_pretty(value: &Self::Target) -> Result<String, Self::Error> {
+        to_entries(value).and_then(|entries| write(&entries, true)).map_err(|err| Error::Serialize { err })
+    }
+
+    #[inline]
+    fn to_writer(value: &Self::Target, mut writer: impl Write) -> Result<(), Self::Error> {
+        // Write to string first
+        let raw: String = Self::to_string(value)?;
+
+        // Then write to the writer
+        match writer.write_all(raw.as_bytes()) {
+            Ok(_) => Ok(()),
+            Err(err) => Err(Error::Write { err }),
+        }
+    }
+
+    #[inline]
+    fn to_writer_pretty(value: &Self::Target, mut writer: impl Write) -> Result<(), Self::Error> {
+        // Write to string first
+        let raw: String = Self::to_string_pretty(value)?;
+
+        // Then write to the writer
+        match writer.write_all(raw.as_bytes()) {
+            Ok(_) => Ok(()),
+            Err(err) => Err(Error::Write { err }),
+        }
+    }
+
+    #[inline]
+    fn from_str(raw: impl AsRef<str>) -> Result<Self::Target, Self::Error> {
+        parse(raw.as_ref()).and_then(|entries| T::deserialize(NodeDeserializer::new(entries, false))).map_err(|err| Error::Deserialize { err })
+    }
+
+    #[inline]
+    fn from_reader(mut reader: impl Read) -> Result<Self::Target, Self::Error> {
+        // Simply read the whole reader
+        let mut raw: String = String::new();
+        if let Err(err) = reader.read_to_string(&mut raw) {
+            return Err(Error::Read { err });
+        }
+
+        // Now deserialize using the string edition
+        Self::from_str(&raw)
+    }
+}
+
+#[cfg(feature = "async-tokio")]
+#[async_trait::async_trait]
+impl<T: Send + Sync + for<'de> Deserialize<'de> + Serialize> serializer::SerializerAsync for Serializer<T> {
+    #[inline]
+    async fn to_writer_async(value: &Self::Target, mut writer: impl Send + std::marker::Unpin + tokio::io::AsyncWrite) -> Result<(), Self::Error> {
+        use tokio::io::AsyncWriteExt;
+
+        // Serialize ourselves to a string first
+        let raw: String = <Self as serializer::Serializer>::to_string(value)?;
+
+        // Now write to the writer
+        if let Err(err) = writer.write_all(raw.as_bytes()).await {
+            return Err(Error::Write { err });
+        }
+        // ...making sure its flushed
+        match writer.flush().await {
+            Ok(_) => Ok(()),
+            Err(err) => Err(Error::Flush { err }),
+        }
+    }
+
+    #[inline]
+    async fn to_writer_pretty_async(
+        value: &Self::Target,
+        mut writer: impl Send + std::marker::Unpin + tokio::io::AsyncWrite,
+    ) -> Result<(), Self::Error> {
+        use tokio::io::AsyncWriteExt;
+
+        // Serialize ourselves to a string first
+        let raw: String = <Self as serializer::Serializer>::to_string_pretty(value)?;
+
+        // Now write to the writer
+        if let Err(err) = writer.write_all(raw.as_bytes()).await {
+            return Err(Error::Write { err });
+        }
+        // ...making sure its flushed
+        match writer.flush().await {
+            Ok(_) => Ok(()),
+            Err(err) => Err(Error::Flush { err }),
+        }
+    }
+
+    #[inline]
+    async fn from_reader_async(mut reader: impl Send + std::marker::Unpin + tokio::io::AsyncRead) -> Result<T, Self::Error> {
+        use tokio::io::AsyncReadExt;
+
+        // Read the entire buffer first
+        let mut raw: String = String::new();
+        if let Err(err) = reader.read_to_string(&mut raw).await {
+            return Err(Error::Read { err });
+        }
+
+        // Then deserialize as string
+        <Self as serializer::Serializer>::from_str(&raw)
+    }
+}
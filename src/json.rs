@@ -4,12 +4,13 @@
 //  Created:
 //    28 Oct 2023, 13:02:40
 //  Last edited:
-//    30 Oct 2023, 12:31:55
+//    17 Oct 2026, 12:21:44
 //  Auto updated?
 //    Yes
 //
 //  Description:
-//!   Implements [`serializer::Serializer`] and cohorts for [`serde_json`].
+//!   Implements [`serializer::Serializer`] and cohorts for [`serde_json`],
+//!   optionally writing canonical JSON ([RFC 8785](https://www.rfc-editor.org/rfc/rfc8785)).
 //
 
 use std::error;
@@ -18,7 +19,10 @@ use std::io::{Read, Write};
 use std::marker::PhantomData;
 
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
 
+use crate::location::{deserialize_tracked, Location, PathSegment};
+use crate::pretty::{self, PrettyOptions};
 use crate::serializer;
 
 
@@ -27,7 +31,6 @@ use crate::serializer;
 #[derive(Debug)]
 pub enum Error {
     /// Failed to write to the given writer.
-    #[cfg(feature = "async-tokio")]
     Write { err: std::io::Error },
     /// Failed to read from the given reader.
     #[cfg(feature = "async-tokio")]
@@ -38,13 +41,47 @@ pub enum Error {
     /// Failed to serialize the object to JSON.
     Serialize { err: serde_json::Error },
     /// Failed to deserialize the object from JSON.
-    Deserialize { err: serde_json::Error },
+    ///
+    /// `field_path` is the path to the field that failed (see [`Error::field_path()`]).
+    Deserialize { field_path: Vec<PathSegment>, err: serde_json::Error },
+}
+impl Error {
+    /// Finds where in the input this error occurred.
+    ///
+    /// The location is only available for errors that occurred while deserializing (and not while
+    /// reading). `raw` is only used to compute the byte offset.
+    ///
+    /// # Arguments
+    /// - `raw`: The input that was being deserialized, if it's available.
+    ///
+    /// # Returns
+    /// The [`Location`] of the error, or [`None`] if it has none.
+    #[inline]
+    pub fn location(&self, raw: Option<&str>) -> Option<Location> {
+        match self {
+            Self::Deserialize { err, .. } if !err.is_io() => Some(Location::from_line_column(err.line(), err.column(), raw)),
+            _ => None,
+        }
+    }
+
+    /// Returns the path to the field at which this error occurred.
+    ///
+    /// # Returns
+    /// A list of [`PathSegment`]s leading to the field (e.g., `servers[3].tls.cert_path`). It is
+    /// empty if this error has no path (e.g., it didn't occur while deserializing, or it occurred
+    /// at the root).
+    #[inline]
+    pub fn field_path(&self) -> Vec<PathSegment> {
+        match self {
+            Self::Deserialize { field_path, .. } => field_path.clone(),
+            _ => Vec::new(),
+        }
+    }
 }
 impl Display for Error {
     fn fmt(&self, f: &mut Formatter<'_>) -> FResult {
         use Error::*;
         match self {
-            #[cfg(feature = "async-tokio")]
             Write { .. } => write!(f, "Failed to write to given writer"),
             #[cfg(feature = "async-tokio")]
             Read { .. } => write!(f, "Failed to read from given reader"),
@@ -59,30 +96,307 @@ impl error::Error for Error {
     fn source(&self) -> Option<&(dyn error::Error + 'static)> {
         use Error::*;
         match self {
-            #[cfg(feature = "async-tokio")]
             Write { err } => Some(err),
             #[cfg(feature = "async-tokio")]
             Read { err } => Some(err),
             #[cfg(feature = "async-tokio")]
             Flush { err } => Some(err),
             Serialize { err } => Some(err),
-            Deserialize { err } => Some(err),
+            Deserialize { err, .. } => Some(err),
+        }
+    }
+}
+
+
+
+
+
+/***** HELPERS *****/
+/// Deserializes a value with the given JSON deserializer, keeping track of the field it fails at.
+///
+/// # Arguments
+/// - `de`: The [`serde_json::Deserializer`] to deserialize with.
+///
+/// # Returns
+/// The deserialized value.
+///
+/// # Errors
+/// This function errors if the deserializer failed, or if there was trailing input.
+#[inline]
+fn deserialize<'de, T: Deserialize<'de>, R: serde_json::de::Read<'de>>(mut de: serde_json::Deserializer<R>) -> Result<T, Error> {
+    let res: T = deserialize_tracked(&mut de).map_err(|(field_path, err)| Error::Deserialize { field_path, err })?;
+    de.end().map_err(|err| Error::Deserialize { field_path: Vec::new(), err })?;
+    Ok(res)
+}
+
+
+
+
+
+/// Writes the given value as canonical JSON, as specified by the JSON Canonicalization Scheme
+/// ([RFC 8785](https://www.rfc-editor.org/rfc/rfc8785)).
+///
+/// # Arguments
+/// - `value`: The [`Value`] to write.
+/// - `out`: The string to write to.
+fn write_canonical(value: &Value, out: &mut String) {
+    match value {
+        Value::Null => out.push_str("null"),
+        Value::Bool(value) => out.push_str(if *value { "true" } else { "false" }),
+        // NOTE: Numbers are IEEE 754 doubles in JCS, which is why integers are converted too
+        Value::Number(value) => write_canonical_number(value.as_f64().unwrap_or(0.0), out),
+        Value::String(value) => write_canonical_string(value, out),
+        Value::Array(values) => {
+            out.push('[');
+            for (i, value) in values.iter().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+                write_canonical(value, out);
+            }
+            out.push(']');
+        },
+        Value::Object(values) => {
+            // Keys are sorted by their UTF-16 code units, not by their (UTF-8) bytes
+            let mut keys: Vec<&String> = values.keys().collect();
+            keys.sort_by(|lhs, rhs| lhs.encode_utf16().cmp(rhs.encode_utf16()));
+
+            out.push('{');
+            for (i, key) in keys.into_iter().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+                write_canonical_string(key, out);
+                out.push(':');
+                write_canonical(&values[key], out);
+            }
+            out.push('}');
+        },
+    }
+}
+
+/// Writes the given number like ECMAScript's `Number.prototype.toString()`, as required by
+/// [RFC 8785](https://www.rfc-editor.org/rfc/rfc8785#section-3.2.2.3).
+///
+/// # Arguments
+/// - `value`: The (finite) number to write.
+/// - `out`: The string to write to.
+fn write_canonical_number(value: f64, out: &mut String) {
+    if value == 0.0 {
+        // Also covers `-0`
+        out.push('0');
+        return;
+    }
+    if value < 0.0 {
+        out.push('-');
+    }
+
+    // Rust gives the shortest digits that round-trip, which is what ECMAScript uses too; we only
+    // have to place the decimal point differently
+    let sci: String = format!("{:e}", value.abs());
+    let (mantissa, exp): (&str, &str) = sci.split_once('e').unwrap_or((&sci, "0"));
+    let digits: String = mantissa.replace('.', "");
+    let k: i32 = digits.len() as i32;
+    let n: i32 = exp.parse::<i32>().unwrap_or(0) + 1;
+    if k <= n && n <= 21 {
+        out.push_str(&digits);
+        out.push_str(&"0".repeat((n - k) as usize));
+    } else if 0 < n && n <= 21 {
+        out.push_str(&digits[..n as usize]);
+        out.push('.');
+        out.push_str(&digits[n as usize..]);
+    } else if -6 < n && n <= 0 {
+        out.push_str("0.");
+        out.push_str(&"0".repeat((-n) as usize));
+        out.push_str(&digits);
+    } else {
+        out.push_str(&digits[..1]);
+        if k > 1 {
+            out.push('.');
+            out.push_str(&digits[1..]);
         }
+        out.push_str(&format!("e{}{}", if n > 0 { '+' } else { '-' }, (n - 1).abs()));
     }
 }
 
+/// Writes the given string as a JSON string, escaping it as required by
+/// [RFC 8785](https://www.rfc-editor.org/rfc/rfc8785#section-3.2.2.2).
+///
+/// # Arguments
+/// - `value`: The string to write.
+/// - `out`: The string to write to.
+fn write_canonical_string(value: &str, out: &mut String) {
+    out.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\u{08}' => out.push_str("\\b"),
+            '\u{0C}' => out.push_str("\\f"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if c < ' ' => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+}
+
+/// Serializes the given value to canonical JSON.
+///
+/// See [`Options::CANONICAL`] for what this entails.
+///
+/// # Arguments
+/// - `value`: The value to serialize.
+///
+/// # Returns
+/// The serialized value.
+///
+/// # Errors
+/// This function errors if the value failed to serialize to JSON.
+pub(crate) fn to_canonical_string<T: ?Sized + Serialize>(value: &T) -> Result<String, Error> {
+    let value: Value = serde_json::to_value(value).map_err(|err| Error::Serialize { err })?;
+    let mut out: String = String::new();
+    write_canonical(&value, &mut out);
+    Ok(out)
+}
+
+/// Serializes the given value to (pretty) JSON in the given writer, honouring the given
+/// [`Options`].
+///
+/// Only canonical JSON and pretty JSON with short arrays on one line are serialized in memory
+/// first; everything else is streamed to `writer`.
+///
+/// # Arguments
+/// - `value`: The value to serialize.
+/// - `pretty`: The [`PrettyOptions`] to use the pretty formatter with, or [`None`] to not use it.
+///   Ignored if [`Options::CANONICAL`].
+/// - `writer`: The [`Write`]r to write the serialized value to.
+///
+/// # Errors
+/// This function errors if the value failed to serialize to JSON, or if we failed to write it.
+#[inline]
+fn serialize<T: Serialize, O: Options>(value: &T, pretty: Option<&PrettyOptions>, mut writer: impl Write) -> Result<(), Error> {
+    if O::CANONICAL {
+        let raw: String = to_canonical_string(value)?;
+        writer.write_all(raw.as_bytes()).map_err(|err| Error::Write { err })
+    } else if let Some(opts) = pretty {
+        pretty::to_json_writer(value, opts, writer).map_err(|err| Error::Serialize { err })
+    } else {
+        serde_json::to_writer(writer, value).map_err(|err| Error::Serialize { err })
+    }
+}
+
+/// Serializes the given value to (pretty) JSON in memory, honouring the given [`Options`].
+///
+/// # Arguments
+/// - `value`: The value to serialize.
+/// - `pretty`: The [`PrettyOptions`] to use the pretty formatter with, or [`None`] to not use it.
+///   Ignored if [`Options::CANONICAL`].
+///
+/// # Returns
+/// The serialized value.
+///
+/// # Errors
+/// This function errors if the value failed to serialize to JSON.
+#[inline]
+fn serialize_to_vec<T: Serialize, O: Options>(value: &T, pretty: Option<&PrettyOptions>) -> Result<Vec<u8>, Error> {
+    if O::CANONICAL {
+        return to_canonical_string(value).map(String::into_bytes);
+    }
+    let mut raw: Vec<u8> = Vec::new();
+    serialize::<T, O>(value, pretty, &mut raw)?;
+    Ok(raw)
+}
+
+/// Serializes the given value to a (pretty) JSON string, honouring the given [`Options`].
+///
+/// # Arguments
+/// - `value`: The value to serialize.
+/// - `pretty`: The [`PrettyOptions`] to use the pretty formatter with, or [`None`] to not use it.
+///   Ignored if [`Options::CANONICAL`].
+///
+/// # Returns
+/// The serialized value.
+///
+/// # Errors
+/// This function errors if the value failed to serialize to JSON.
+#[inline]
+fn serialize_to_string<T: Serialize, O: Options>(value: &T, pretty: Option<&PrettyOptions>) -> Result<String, Error> {
+    serialize_to_vec::<T, O>(value, pretty).map(|raw| String::from_utf8(raw).expect("serde_json wrote invalid UTF-8"))
+}
+
 
 
 
 
 /***** LIBRARY *****/
+/// Configures the JSON [`Serializer`].
+///
+/// All constants have defaults, so implementations only have to override what they change.
+///
+/// # Examples
+/// ```rust
+/// use std::collections::HashMap;
+///
+/// use serializable::json::{Options, Serializer};
+/// use serializable::Serializable;
+///
+/// struct Canonical;
+/// impl Options for Canonical {
+///     const CANONICAL: bool = true;
+/// }
+///
+/// #[derive(serde::Deserialize, serde::Serialize)]
+/// struct Scores(HashMap<String, f64>);
+/// impl Serializable<Serializer<Scores, Canonical>> for Scores {}
+///
+/// let scores = Scores(HashMap::from([("b".into(), 1e21), ("a".into(), 10.0), ("c".into(), 0.000001)]));
+/// assert_eq!(scores.to_string().unwrap(), "{\"a\":10,\"b\":1e+21,\"c\":0.000001}");
+/// assert_eq!(scores.to_string_pretty().unwrap(), scores.to_string().unwrap());
+/// ```
+pub trait Options {
+    /// Whether to write canonical JSON, as specified by the JSON Canonicalization Scheme
+    /// ([RFC 8785](https://www.rfc-editor.org/rfc/rfc8785)).
+    ///
+    /// Canonical JSON has its object keys sorted, no whitespace, numbers written like ECMAScript
+    /// does and minimal string escaping. As such, two semantically equal values always serialize
+    /// to the same bytes (e.g., regardless of the iteration order of a `HashMap`), which makes the
+    /// output suitable for hashing or signing. Note that JCS treats all numbers as IEEE 754
+    /// doubles, so integers beyond 2^53 lose precision.
+    ///
+    /// If enabled, pretty output is canonical too.
+    const CANONICAL: bool = false;
+}
+
+/// The default [`Options`] for the JSON [`Serializer`].
+#[derive(Clone, Copy, Debug)]
+pub struct DefaultOptions;
+impl Options for DefaultOptions {}
+
+/// [`Options`] for the JSON [`Serializer`] that make it write canonical JSON (see
+/// [`Options::CANONICAL`]).
+#[derive(Clone, Copy, Debug)]
+pub struct CanonicalOptions;
+impl Options for CanonicalOptions {
+    const CANONICAL: bool = true;
+}
+
+
+
 /// Implements a [`serializer::Serializer`] for [`serde_json`].
 ///
+/// The serializer can be configured (e.g., to write canonical JSON) by giving it custom
+/// [`Options`]. Its pretty output honours all [`PrettyOptions`] (unless it's canonical). Its
+/// canonical form (see [`serializer::Serializer::to_bytes_canonical()`]) is always canonical JSON,
+/// regardless of the options.
+///
 /// # Examples
 /// ```rust
 /// use serde::{Deserialize, Serialize};
 /// use serializable::json::Serializer;
-/// use serializable::Serializable;
+/// use serializable::{PrettyOptions, Serializable};
 ///
 /// #[derive(Debug, Deserialize, Eq, PartialEq, Serialize)]
 /// struct HelloWorld {
@@ -95,6 +409,18 @@ impl error::Error for Error {
 ///     HelloWorld { hello: "Hello".into(), world: "World".into() }.to_string().unwrap(),
 ///     "{\"hello\":\"Hello\",\"world\":\"World\"}"
 /// );
+/// assert_eq!(
+///     HelloWorld { hello: "Hello".into(), world: "World".into() }
+///         .to_string_pretty_with(&PrettyOptions { indent: Some("    "), trailing_newline: true, ..Default::default() })
+///         .unwrap(),
+///     "{\n    \"hello\": \"Hello\",\n    \"world\": \"World\"\n}\n"
+/// );
+///
+/// let mut buf: Vec<u8> = Vec::new();
+/// HelloWorld { hello: "Hello".into(), world: "World".into() }
+///     .to_writer_pretty_with(&PrettyOptions { trailing_newline: true, ..Default::default() }, &mut buf)
+///     .unwrap();
+/// assert_eq!(buf, b"{\n  \"hello\": \"Hello\",\n  \"world\": \"World\"\n}\n");
 ///
 /// assert_eq!(
 ///     HelloWorld::from_str("{\"hello\":\"Goodbye\",\"world\":\"Planet\"}").unwrap(),
@@ -102,42 +428,79 @@ impl error::Error for Error {
 /// )
 /// ```
 #[derive(Clone, Copy, Debug)]
-pub struct Serializer<T>(PhantomData<T>);
+pub struct Serializer<T, O = DefaultOptions>(PhantomData<(T, O)>);
+
+/// Marks the JSON [`Serializer`] with its default options as a [`Backend`](crate::Backend).
+///
+/// Use it to select this backend in [`SerializableAs`](crate::SerializableAs) (e.g.,
+/// `value.to_string_as::<Json>()`).
+#[derive(Clone, Copy, Debug)]
+pub struct Json;
+impl serializer::Backend for Json {
+    type Serializer<T> = Serializer<T>;
+}
 
-impl<T: for<'de> Deserialize<'de> + Serialize> serializer::Serializer for Serializer<T> {
+impl<T: for<'de> Deserialize<'de> + Serialize, O: Options> serializer::Serializer for Serializer<T, O> {
     type Error = Error;
     type Target = T;
 
     #[inline]
-    fn to_string(value: &Self::Target) -> Result<String, Self::Error> { serde_json::to_string(value).map_err(|err| Error::Serialize { err }) }
+    fn to_string(value: &Self::Target) -> Result<String, Self::Error> { serialize_to_string::<T, O>(value, None) }
 
     #[inline]
-    fn to_string_pretty(value: &Self::Target) -> Result<String, Self::Error> {
-        serde_json::to_string_pretty(value).map_err(|err| Error::Serialize { err })
-    }
+    fn to_string_pretty(value: &Self::Target) -> Result<String, Self::Error> { serialize_to_string::<T, O>(value, Some(&PrettyOptions::DEFAULT)) }
 
     #[inline]
-    fn to_writer(value: &Self::Target, writer: impl Write) -> Result<(), Self::Error> {
-        serde_json::to_writer(writer, value).map_err(|err| Error::Serialize { err })
-    }
+    fn to_string_pretty_with(value: &Self::Target, opts: &PrettyOptions) -> Result<String, Self::Error> { serialize_to_string::<T, O>(value, Some(opts)) }
+
+    #[inline]
+    fn to_bytes(value: &Self::Target) -> Result<Vec<u8>, Self::Error> { serialize_to_vec::<T, O>(value, None) }
+
+    #[inline]
+    fn to_bytes_pretty(value: &Self::Target) -> Result<Vec<u8>, Self::Error> { serialize_to_vec::<T, O>(value, Some(&PrettyOptions::DEFAULT)) }
+
+    #[inline]
+    fn to_bytes_pretty_with(value: &Self::Target, opts: &PrettyOptions) -> Result<Vec<u8>, Self::Error> { serialize_to_vec::<T, O>(value, Some(opts)) }
+
+    #[inline]
+    fn to_bytes_canonical(value: &Self::Target) -> Result<Vec<u8>, Self::Error> { to_canonical_string(value).map(String::into_bytes) }
+
+    #[inline]
+    fn to_writer(value: &Self::Target, writer: impl Write) -> Result<(), Self::Error> { serialize::<T, O>(value, None, writer) }
 
     #[inline]
     fn to_writer_pretty(value: &Self::Target, writer: impl Write) -> Result<(), Self::Error> {
-        serde_json::to_writer_pretty(writer, value).map_err(|err| Error::Serialize { err })
+        serialize::<T, O>(value, Some(&PrettyOptions::DEFAULT), writer)
+    }
+
+    #[inline]
+    fn to_writer_pretty_with(value: &Self::Target, opts: &PrettyOptions, writer: impl Write) -> Result<(), Self::Error> {
+        serialize::<T, O>(value, Some(opts), writer)
     }
 
     #[inline]
     fn from_str(raw: impl AsRef<str>) -> Result<Self::Target, Self::Error> {
-        serde_json::from_str(raw.as_ref()).map_err(|err| Error::Serialize { err })
+        deserialize(serde_json::Deserializer::from_str(raw.as_ref()))
     }
 
     #[inline]
-    fn from_reader(reader: impl Read) -> Result<Self::Target, Self::Error> { serde_json::from_reader(reader).map_err(|err| Error::Serialize { err }) }
+    fn from_bytes(raw: impl AsRef<[u8]>) -> Result<Self::Target, Self::Error> {
+        deserialize(serde_json::Deserializer::from_slice(raw.as_ref()))
+    }
+
+    #[inline]
+    fn from_reader(reader: impl Read) -> Result<Self::Target, Self::Error> { deserialize(serde_json::Deserializer::from_reader(reader)) }
+
+    #[inline]
+    fn location(err: &Self::Error, raw: Option<&str>) -> Option<Location> { err.location(raw) }
+
+    #[inline]
+    fn field_path(err: &Self::Error) -> Vec<PathSegment> { err.field_path() }
 }
 
 #[cfg(feature = "async-tokio")]
 #[async_trait::async_trait]
-impl<T: Send + Sync + for<'de> Deserialize<'de> + Serialize> serializer::SerializerAsync for Serializer<T> {
+impl<T: Send + Sync + for<'de> Deserialize<'de> + Serialize, O: Send + Sync + Options> serializer::SerializerAsync for Serializer<T, O> {
     #[inline]
     async fn to_writer_async(value: &Self::Target, mut writer: impl Send + std::marker::Unpin + tokio::io::AsyncWrite) -> Result<(), Self::Error> {
         use tokio::io::AsyncWriteExt;
@@ -177,6 +540,28 @@ impl<T: Send + Sync + for<'de> Deserialize<'de> + Serialize> serializer::Seriali
         }
     }
 
+    #[inline]
+    async fn to_writer_pretty_with_async(
+        value: &Self::Target,
+        opts: &PrettyOptions,
+        mut writer: impl Send + std::marker::Unpin + tokio::io::AsyncWrite,
+    ) -> Result<(), Self::Error> {
+        use tokio::io::AsyncWriteExt;
+
+        // Serialize ourselves to a string first
+        let raw: String = <Self as serializer::Serializer>::to_string_pretty_with(value, opts)?;
+
+        // Now write to the writer
+        if let Err(err) = writer.write_all(raw.as_bytes()).await {
+            return Err(Error::Write { err });
+        }
+        // ...making sure its flushed
+        match writer.flush().await {
+            Ok(_) => Ok(()),
+            Err(err) => Err(Error::Flush { err }),
+        }
+    }
+
     #[inline]
     async fn from_reader_async(mut reader: impl Send + std::marker::Unpin + tokio::io::AsyncRead) -> Result<T, Self::Error> {
         use tokio::io::AsyncReadExt;
@@ -0,0 +1,258 @@
+//  JSON5.rs
+//    by Lut99
+//
+//  Created:
+//    16 Oct 2026, 21:13:42
+//  Last edited:
+//    17 Oct 2026, 12:21:44
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Implements [`serializer::Serializer`] and cohorts for a relaxed JSON
+//!   dialect, reading [JSON5](https://json5.org) through [`json5`] while
+//!   writing strict JSON through [`serde_json`].
+//
+
+use std::error;
+use std::fmt::{Display, Formatter, Result as FResult};
+use std::io::{Read, Write};
+use std::marker::PhantomData;
+
+use serde::{Deserialize, Serialize};
+
+use crate::pretty::{self, PrettyOptions};
+use crate::serializer;
+
+
+/***** ERRORS *****/
+/// Defines errors that occur when using the JSON5 [`Serializer`].
+#[derive(Debug)]
+pub enum Error {
+    /// Failed to write to the given writer.
+    Write { err: std::io::Error },
+    /// Failed to read from the given reader.
+    Read { err: std::io::Error },
+    /// Failed to flush the given writer.
+    #[cfg(feature = "async-tokio")]
+    Flush { err: std::io::Error },
+    /// Failed to serialize the object to JSON.
+    Serialize { err: serde_json::Error },
+    /// Failed to deserialize the object from JSON5.
+    Deserialize { err: json5::Error },
+}
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FResult {
+        use Error::*;
+        match self {
+            Write { .. } => write!(f, "Failed to write to given writer"),
+            Read { .. } => write!(f, "Failed to read from given reader"),
+            #[cfg(feature = "async-tokio")]
+            Flush { .. } => write!(f, "Failed to flush the given writer"),
+            Serialize { .. } => write!(f, "Failed to serialize to JSON"),
+            Deserialize { .. } => write!(f, "Failed to deserialize from JSON5"),
+        }
+    }
+}
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        use Error::*;
+        match self {
+            Write { err } => Some(err),
+            Read { err } => Some(err),
+            #[cfg(feature = "async-tokio")]
+            Flush { err } => Some(err),
+            Serialize { err } => Some(err),
+            Deserialize { err } => Some(err),
+        }
+    }
+}
+
+
+
+
+
+/***** LIBRARY *****/
+/// Implements a [`serializer::Serializer`] that leniently reads [JSON5](https://json5.org), but
+/// writes strict JSON.
+///
+/// This is useful for hand-edited configuration files: when deserializing, comments, trailing
+/// commas, unquoted keys and single-quoted strings are accepted. Serializing, on the other hand,
+/// produces exactly the same output as the JSON serializer does, so
+/// other tools can still read the files we write.
+///
+/// # Examples
+/// ```rust
+/// use serde::{Deserialize, Serialize};
+/// use serializable::json5::Serializer;
+/// use serializable::Serializable;
+///
+/// #[derive(Debug, Deserialize, Eq, PartialEq, Serialize)]
+/// struct HelloWorld {
+///     hello: String,
+///     world: String,
+/// }
+/// impl Serializable<Serializer<HelloWorld>> for HelloWorld {}
+///
+/// assert_eq!(
+///     HelloWorld { hello: "Hello".into(), world: "World".into() }.to_string().unwrap(),
+///     "{\"hello\":\"Hello\",\"world\":\"World\"}"
+/// );
+///
+/// assert_eq!(
+///     HelloWorld::from_str("{\n    // Who we're greeting\n    hello: 'Goodbye',\n    \"world\": \"Planet\",\n}").unwrap(),
+///     HelloWorld { hello: "Goodbye".into(), world: "Planet".into() }
+/// )
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct Serializer<T>(PhantomData<T>);
+
+/// Marks the JSON5 [`Serializer`] as a [`Backend`](crate::Backend).
+///
+/// Use it to select this backend in [`SerializableAs`](crate::SerializableAs) (e.g.,
+/// `value.to_string_as::<Json5>()`).
+#[derive(Clone, Copy, Debug)]
+pub struct Json5;
+impl serializer::Backend for Json5 {
+    type Serializer<T> = Serializer<T>;
+}
+
+impl<T: for<'de> Deserialize<'de> + Serialize> serializer::Serializer for Serializer<T> {
+    type Error = Error;
+    type Target = T;
+
+    #[inline]
+    fn to_string(value: &Self::Target) -> Result<String, Self::Error> { serde_json::to_string(value).map_err(|err| Error::Serialize { err }) }
+
+    #[inline]
+    fn to_string_pretty(value: &Self::Target) -> Result<String, Self::Error> {
+        serde_json::to_string_pretty(value).map_err(|err| Error::Serialize { err })
+    }
+
+    #[inline]
+    fn to_string_pretty_with(value: &Self::Target, opts: &PrettyOptions) -> Result<String, Self::Error> {
+        pretty::to_json_string(value, opts).map_err(|err| Error::Serialize { err })
+    }
+
+    #[inline]
+    fn to_bytes(value: &Self::Target) -> Result<Vec<u8>, Self::Error> { serde_json::to_vec(value).map_err(|err| Error::Serialize { err }) }
+
+    #[inline]
+    fn to_bytes_pretty(value: &Self::Target) -> Result<Vec<u8>, Self::Error> {
+        serde_json::to_vec_pretty(value).map_err(|err| Error::Serialize { err })
+    }
+
+    #[inline]
+    fn to_bytes_pretty_with(value: &Self::Target, opts: &PrettyOptions) -> Result<Vec<u8>, Self::Error> {
+        pretty::to_json_string(value, opts).map(String::into_bytes).map_err(|err| Error::Serialize { err })
+    }
+
+    #[inline]
+    fn to_writer(value: &Self::Target, writer: impl Write) -> Result<(), Self::Error> {
+        serde_json::to_writer(writer, value).map_err(|err| Error::Serialize { err })
+    }
+
+    #[inline]
+    fn to_writer_pretty(value: &Self::Target, writer: impl Write) -> Result<(), Self::Error> {
+        serde_json::to_writer_pretty(writer, value).map_err(|err| Error::Serialize { err })
+    }
+
+    #[inline]
+    fn to_writer_pretty_with(value: &Self::Target, opts: &PrettyOptions, writer: impl Write) -> Result<(), Self::Error> {
+        pretty::to_json_writer(value, opts, writer).map_err(|err| Error::Serialize { err })
+    }
+
+    #[inline]
+    fn from_str(raw: impl AsRef<str>) -> Result<Self::Target, Self::Error> { json5::from_str(raw.as_ref()).map_err(|err| Error::Deserialize { err }) }
+
+    #[inline]
+    fn from_reader(mut reader: impl Read) -> Result<Self::Target, Self::Error> {
+        // Simply read the whole reader
+        let mut raw: String = String::new();
+        if let Err(err) = reader.read_to_string(&mut raw) {
+            return Err(Error::Read { err });
+        }
+
+        // Now deserialize using the string edition
+        Self::from_str(&raw)
+    }
+}
+
+#[cfg(feature = "async-tokio")]
+#[async_trait::async_trait]
+impl<T: Send + Sync + for<'de> Deserialize<'de> + Serialize> serializer::SerializerAsync for Serializer<T> {
+    #[inline]
+    async fn to_writer_async(value: &Self::Target, mut writer: impl Send + std::marker::Unpin + tokio::io::AsyncWrite) -> Result<(), Self::Error> {
+        use tokio::io::AsyncWriteExt;
+
+        // Serialize ourselves to a string first
+        let raw: String = <Self as serializer::Serializer>::to_string(value)?;
+
+        // Now write to the writer
+        if let Err(err) = writer.write_all(raw.as_bytes()).await {
+            return Err(Error::Write { err });
+        }
+        // ...making sure its flushed
+        match writer.flush().await {
+            Ok(_) => Ok(()),
+            Err(err) => Err(Error::Flush { err }),
+        }
+    }
+
+    #[inline]
+    async fn to_writer_pretty_async(
+        value: &Self::Target,
+        mut writer: impl Send + std::marker::Unpin + tokio::io::AsyncWrite,
+    ) -> Result<(), Self::Error> {
+        use tokio::io::AsyncWriteExt;
+
+        // Serialize ourselves to a string first
+        let raw: String = <Self as serializer::Serializer>::to_string_pretty(value)?;
+
+        // Now write to the writer
+        if let Err(err) = writer.write_all(raw.as_bytes()).await {
+            return Err(Error::Write { err });
+        }
+        // ...making sure its flushed
+        match writer.flush().await {
+            Ok(_) => Ok(()),
+            Err(err) => Err(Error::Flush { err }),
+        }
+    }
+
+    #[inline]
+    async fn to_writer_pretty_with_async(
+        value: &Self::Target,
+        opts: &PrettyOptions,
+        mut writer: impl Send + std::marker::Unpin + tokio::io::AsyncWrite,
+    ) -> Result<(), Self::Error> {
+        use tokio::io::AsyncWriteExt;
+
+        // Serialize ourselves to a string first
+        let raw: String = <Self as serializer::Serializer>::to_string_pretty_with(value, opts)?;
+
+        // Now write to the writer
+        if let Err(err) = writer.write_all(raw.as_bytes()).await {
+            return Err(Error::Write { err });
+        }
+        // ...making sure its flushed
+        match writer.flush().await {
+            Ok(_) => Ok(()),
+            Err(err) => Err(Error::Flush { err }),
+        }
+    }
+
+    #[inline]
+    async fn from_reader_async(mut reader: impl Send + std::marker::Unpin + tokio::io::AsyncRead) -> Result<T, Self::Error> {
+        use tokio::io::AsyncReadExt;
+
+        // Read the entire buffer first
+        let mut raw: String = String::new();
+        if let Err(err) = reader.read_to_string(&mut raw).await {
+            return Err(Error::Read { err });
+        }
+
+        // Then deserialize as string
+        <Self as serializer::Serializer>::from_str(&raw)
+    }
+}
@@ -4,7 +4,7 @@
 //  Created:
 //    24 Oct 2023, 22:36:08
 //  Last edited:
-//    30 Oct 2023, 12:52:01
+//    17 Oct 2026, 12:08:15
 //  Auto updated?
 //    Yes
 //
@@ -40,7 +40,7 @@
 //!   # Usage
 //!   To use the library, simply implement the `Serializable`-trait for one of your types using a `Serializer` of your choice.
 //!   
-//!   The library provides optional serializers for [`serde_json`](https://github.com/serde-rs/json), [`serde_yaml`](https://github.com/dtolnay/serde-yaml) and [`toml`](https://github.com/toml-rs/toml). There is also a dummy serializer, which doesn't serialize or deserialize any content (mostly used in tests).
+//!   The library provides optional serializers for [`serde_json`](https://github.com/serde-rs/json), [`json5`](https://github.com/callum-oakley/json5-rs), [`serde_yaml`](https://github.com/dtolnay/serde-yaml), [`toml`](https://github.com/toml-rs/toml), [`rmp-serde`](https://github.com/3Hren/msgpack-rust), [`ciborium`](https://github.com/enarx/ciborium), [`ron`](https://github.com/ron-rs/ron), [`bincode`](https://github.com/bincode-org/bincode), [`postcard`](https://github.com/jamesmunns/postcard), [`quick-xml`](https://github.com/tafia/quick-xml), [`csv`](https://github.com/BurntSushi/rust-csv), [`bson`](https://github.com/mongodb/bson-rust) and for INI- and `.env`-files. There is also a dummy serializer, which doesn't serialize or deserialize any content (mostly used in tests).
 //!   
 //!   For example, if you enabled the `serde-json`-feature, you can:
 //!   ```ignore
@@ -99,6 +99,309 @@
 //!   )
 //!   ```
 //!   
+//!   ## Multiple formats per type
+//!   A type can implement `Serializable` for multiple backends at the same time. However, calling, e.g., `to_string()` on such a type fails type inference, as it's unclear which backend to use. In that case, you can use the `SerializableAs`-trait instead, which offers the same functions but with a backend marker (e.g., `serializable::json::Json`) to select the backend explicitly:
+//!   ```rust
+//!   # #[cfg(all(feature = "serde-json", feature = "serde-yaml"))] {
+//!   use serde::{Deserialize, Serialize};
+//!   use serializable::json::{self, Json};
+//!   use serializable::yaml::{self, Yaml};
+//!   use serializable::{Serializable, SerializableAs as _};
+//!   
+//!   #[derive(Debug, Deserialize, Eq, PartialEq, Serialize)]
+//!   struct HelloWorld {
+//!       hello: String,
+//!       world: String,
+//!   }
+//!   impl Serializable<json::Serializer<HelloWorld>> for HelloWorld {}
+//!   impl Serializable<yaml::Serializer<HelloWorld>> for HelloWorld {}
+//!   
+//!   let hello_world = HelloWorld { hello: "Hello".into(), world: "World".into() };
+//!   assert_eq!(hello_world.to_string_as::<Json>().unwrap(), "{\"hello\":\"Hello\",\"world\":\"World\"}");
+//!   assert_eq!(hello_world.to_string_as::<Yaml>().unwrap(), "hello: Hello\nworld: World\n");
+//!   # }
+//!   ```
+//!   
+//!   ## Deriving `Serializable`
+//!   With the `derive`-feature enabled, `Serializable` can also be derived for one or more backends at once. Every backend is given by its module name, optionally together with the preferred settings for that backend (`pretty` and/or `extension`). These are used by the `*_preferred()`-functions, e.g., `to_path_preferred()`:
+//!   ```rust
+//!   # #[cfg(all(feature = "derive", feature = "serde-json", feature = "serde-yaml"))] {
+//!   use serde::{Deserialize, Serialize};
+//!   use serializable::yaml::Yaml;
+//!   use serializable::{Serializable, SerializableAs as _};
+//!   
+//!   #[derive(Debug, Deserialize, Eq, PartialEq, Serialize, Serializable)]
+//!   #[serializable(json(extension = "json"), yaml(pretty, extension = "yml"))]
+//!   struct HelloWorld {
+//!       hello: String,
+//!       world: String,
+//!   }
+//!   
+//!   let hello_world = HelloWorld { hello: "Hello".into(), world: "World".into() };
+//!   assert_eq!(hello_world.to_string_as::<Yaml>().unwrap(), "hello: Hello\nworld: World\n");
+//!   # }
+//!   ```
+//!   Options given at the toplevel (e.g., `#[serializable(json, yaml, pretty)]`) apply to all backends.
+//!   
+//!   ## Pretty-printing
+//!   The layout of pretty output can be tweaked with `PrettyOptions`, which set the indentation, whether keys are sorted, up to how many elements short arrays are written on one line and whether the output ends with a newline. Every backend honours them as far as it can (e.g., JSON supports all of them, but YAML only sorting keys and the trailing newline). Give them per call with, e.g., `to_string_pretty_with()`, or per type by setting `Serializable::PRETTY_OPTIONS` (or `pretty_options` when deriving), which is then used by all pretty functions, including `to_path_pretty()`:
+//!   ```rust
+//!   # #[cfg(feature = "serde-json")] {
+//!   use serializable::json::Serializer;
+//!   use serializable::{PrettyOptions, Serializable};
+//!   # use serde::{Deserialize, Serialize};
+//!   # #[derive(Deserialize, Serialize)]
+//!   # struct HelloWorld {
+//!   #     hello: String,
+//!   #     world: String,
+//!   # }
+//!   # let dir = std::env::temp_dir().join("serializable_doc_pretty");
+//!   # std::fs::create_dir_all(&dir).unwrap();
+//!   # std::env::set_current_dir(dir).unwrap();
+//!   # let hello_world = HelloWorld { hello: "Hello".into(), world: "World".into() };
+//!   
+//!   impl Serializable<Serializer<HelloWorld>> for HelloWorld {
+//!       const PRETTY_OPTIONS: PrettyOptions = PrettyOptions { indent: Some("    "), trailing_newline: true, ..PrettyOptions::DEFAULT };
+//!   }
+//!   
+//!   // Written with four spaces and a trailing newline
+//!   hello_world.to_path_pretty("hello_world.json").unwrap();
+//!   # }
+//!   ```
+//!   
+//!   ## Runtime format selection
+//!   If you don't know which format to use until runtime (e.g., because it is given by the user), you can use the `Format`-enum. It contains a variant for every self-describing backend that is enabled, which can be found based on a file's extension using `Format::from_path()`.
+//!   
+//!   The `SerializableAuto`-trait offers `to_path_auto()`, `to_path_pretty_auto()` and `from_path_auto()`, which select the format based on the extension of the given path. It is implemented for every type that implements `serde`'s `Serialize` and `Deserialize`, so it doesn't need (and doesn't care about) the `Serializable`'s own backend. This also makes it work for types that implement `Serializable` for multiple backends:
+//!   ```rust
+//!   # #[cfg(all(feature = "serde-json", feature = "serde-yaml"))] {
+//!   use serde::{Deserialize, Serialize};
+//!   use serializable::{json, yaml, Serializable, SerializableAuto as _};
+//!   # let dir = std::env::temp_dir().join("serializable_doc_auto");
+//!   # std::fs::create_dir_all(&dir).unwrap();
+//!   # std::env::set_current_dir(dir).unwrap();
+//!   
+//!   #[derive(Debug, Deserialize, Eq, PartialEq, Serialize)]
+//!   struct HelloWorld {
+//!       hello: String,
+//!       world: String,
+//!   }
+//!   impl Serializable<json::Serializer<HelloWorld>> for HelloWorld {}
+//!   impl Serializable<yaml::Serializer<HelloWorld>> for HelloWorld {}
+//!   
+//!   // Written as YAML, based on the extension
+//!   HelloWorld { hello: "Hello".into(), world: "World".into() }.to_path_auto("hello_world.yml").unwrap();
+//!   assert_eq!(
+//!       HelloWorld::from_path_auto("hello_world.yml").unwrap(),
+//!       HelloWorld { hello: "Hello".into(), world: "World".into() }
+//!   );
+//!   # }
+//!   ```
+//!   Unknown extensions (or extensions of disabled formats) result in an `Error::UnknownFormat`.
+//!   
+//!   For input without a file extension (e.g., from `stdin`), `detect_format()` can be used to guess the format from the contents instead. `SerializableAuto::from_bytes_detect()` and `SerializableAuto::from_reader_detect()` do this automatically.
+//!   
+//!   ## Error locations
+//!   If a backend fails to deserialize some input, the `Error::Deserialize*` variants carry a (boxed) `DeserializeContext` as `ctx`. It gives the path to the field that failed as a list of `PathSegment`s and, for text input, the `Location` (line, column and byte offset) at which it failed and the offending line, as far as they are known. They are currently provided by the JSON, TOML and YAML backends, and are mentioned in the error's message. When formatted with `{:#}`, the offending line of the input is printed as well:
+//!   ```text
+//!   Failed to deserialize Config from file 'config.yml' in field 'servers[3].tls.cert_path' (line 14, column 16)
+//!     14 |     cert_path: [42]
+//!        |                ^
+//!   ```
+//!   Custom backends can provide these by implementing `Serializer::location()` and `Serializer::field_path()`.
+//!   
+//!   ## Atomic writes
+//!   By default, `Serializable::to_path()` truncates and overwrites the target file in place, which means that a crash (or a failing serializer) halfway through leaves a corrupt file behind. Use `Serializable::to_path_atomic()` (or its pretty- and `async`-counterparts) instead to serialize to a temporary file in the same directory first, which is then synced to disk and renamed over the target. As such, the target always contains either the old or the new contents. On Unix, the parent directory is synced as well to make the rename itself durable.
+//!   
+//!   ## Backups
+//!   To keep older versions of a file around, use `Serializable::to_path_with_options()` with `WriteOptions::backups` set. Once the new contents have been serialized and right before they replace the file, its existing backups are rotated (i.e., `state.json.1` becomes `state.json.2`, etc.) and its current contents are copied to `state.json.1`. If serializing fails, the file and its backups are left untouched. If the file later turns out to be corrupt, `Serializable::from_path_with_fallback()` returns the newest backup that still deserializes:
+//!   ```rust
+//!   # #[cfg(feature = "serde-json")] {
+//!   use serializable::{Serializable, WriteOptions};
+//!   # use serde::{Deserialize, Serialize};
+//!   # #[derive(Debug, Deserialize, Serialize)]
+//!   # struct State {
+//!   #     counter: u32,
+//!   # }
+//!   # impl Serializable<serializable::json::Serializer<State>> for State {}
+//!   # let dir = std::env::temp_dir().join("serializable_doc_backups");
+//!   # std::fs::create_dir_all(&dir).unwrap();
+//!   # std::env::set_current_dir(dir).unwrap();
+//!   # let state = State { counter: 42 };
+//!   
+//!   let opts = WriteOptions { atomic: true, backups: 3, ..Default::default() };
+//!   state.to_path_with_options("state.json", &opts).unwrap();
+//!   
+//!   let state = State::from_path_with_fallback("state.json", opts.backups).unwrap();
+//!   # }
+//!   ```
+//!   
+//!   ## File locking
+//!   If multiple processes read and write the same file, use `Serializable::to_path_locked()` and `Serializable::from_path_locked()` (or their pretty- and `async`-counterparts). These take an exclusive lock on the file while writing and a shared one while reading, so readers never see a half-written file. Both take an optional timeout, after which they give up with an `Error::FileLock`:
+//!   ```rust
+//!   # #[cfg(feature = "serde-json")] {
+//!   use std::time::Duration;
+//!   
+//!   use serializable::Serializable;
+//!   # use serde::{Deserialize, Serialize};
+//!   # #[derive(Debug, Deserialize, Serialize)]
+//!   # struct State {
+//!   #     counter: u32,
+//!   # }
+//!   # impl Serializable<serializable::json::Serializer<State>> for State {}
+//!   # let dir = std::env::temp_dir().join("serializable_doc_locking");
+//!   # std::fs::create_dir_all(&dir).unwrap();
+//!   # std::env::set_current_dir(dir).unwrap();
+//!   # let state = State { counter: 42 };
+//!   
+//!   state.to_path_locked("state.json", Some(Duration::from_secs(5))).unwrap();
+//!   let state = State::from_path_locked("state.json", None).unwrap();
+//!   # }
+//!   ```
+//!   Note that the locks are advisory (e.g., `flock()` on Unix), and so they are only respected by processes that lock the file as well.
+//!   
+//!   ## Compression
+//!   If a path ends in the extension of a compression codec (i.e., `.gz`, `.xz` or `.zst`), `Serializable::to_path()` and the other path-functions transparently compress what they write with it. This requires the matching `compress-gzip`-, `compress-xz`- or `compress-zstd`-feature. The codec can also be chosen explicitly with `WriteOptions::compression`. When reading, `Serializable::from_path()` and friends decompress files with such an extension too. Files without one are always read as-is, even if they look compressed, so a file written with an explicit `WriteOptions::compression` that doesn't match its extension isn't decompressed when it's read back. Input that fails to decompress results in an `Error::FileDecompress`. `Format::from_path()` skips the codec's extension too, so `to_path_auto()` on `state.json.gz` writes gzip-compressed JSON:
+//!   ```rust
+//!   # #[cfg(all(feature = "serde-json", feature = "compress-gzip", feature = "compress-zstd"))] {
+//!   use serializable::{Compression, Serializable, WriteOptions};
+//!   # use serde::{Deserialize, Serialize};
+//!   # #[derive(Debug, Deserialize, Serialize)]
+//!   # struct State {
+//!   #     counter: u32,
+//!   # }
+//!   # impl Serializable<serializable::json::Serializer<State>> for State {}
+//!   # let dir = std::env::temp_dir().join("serializable_doc_compression");
+//!   # std::fs::create_dir_all(&dir).unwrap();
+//!   # std::env::set_current_dir(dir).unwrap();
+//!   # let state = State { counter: 42 };
+//!   
+//!   state.to_path("state.json.zst").unwrap();
+//!   state.to_path_with_options("state.bin", &WriteOptions { compression: Some(Compression::Gzip), ..Default::default() }).unwrap();
+//!   
+//!   let state = State::from_path("state.json.zst").unwrap();
+//!   # }
+//!   ```
+//!   
+//!   ## Encryption
+//!   To store sensitive data (e.g., credentials caches), wrap a backend in `serializable::encrypted::Encrypted`. It seals the backend's output with an authenticated cipher (AES-256-GCM or ChaCha20-Poly1305, behind the `encrypt-aes-gcm`- and `encrypt-chacha20poly1305`-features). Keys come from a `KeyProvider` that you implement. The output starts with a header that stores the cipher, the ID of the key and the nonce, so keys can be rotated as long as the provider still knows the old ones. Input that doesn't decrypt results in an `encrypted::Error::Decrypt`, which is separate from the backend failing to parse the plaintext. Like any backend error, it arrives nested in the `Error::Deserialize*` variant of the input that failed (e.g., `Error::DeserializeFile`, which also gives the path); use `Error::is_decrypt()` to check for it:
+//!   ```rust
+//!   # #[cfg(all(feature = "serde-json", feature = "encrypt-chacha20poly1305"))] {
+//!   use std::error::Error;
+//!   
+//!   use serializable::encrypted::{Cipher, Encrypted, Key, KeyProvider};
+//!   use serializable::json::Serializer;
+//!   use serializable::Serializable;
+//!   # use serde::{Deserialize, Serialize};
+//!   # #[derive(Debug, Deserialize, Serialize)]
+//!   # struct Credentials {
+//!   #     token: String,
+//!   # }
+//!   # fn load_key(_id: &str) -> Result<[u8; 32], Box<dyn Error + Send + Sync>> { Ok([42; 32]) }
+//!   # let dir = std::env::temp_dir().join("serializable_doc_encryption");
+//!   # std::fs::create_dir_all(&dir).unwrap();
+//!   # std::env::set_current_dir(dir).unwrap();
+//!   # let creds = Credentials { token: "hunter2".into() };
+//!   
+//!   struct Keys;
+//!   impl KeyProvider for Keys {
+//!       const CIPHER: Cipher = Cipher::ChaCha20Poly1305;
+//!   
+//!       fn encryption_key() -> Result<Key, Box<dyn Error + Send + Sync>> { Ok(Key { id: "v1".into(), material: load_key("v1")? }) }
+//!   }
+//!   
+//!   impl Serializable<Encrypted<Serializer<Credentials>, Keys>> for Credentials {}
+//!   
+//!   creds.to_path("credentials.enc").unwrap();
+//!   let creds = Credentials::from_path("credentials.enc").unwrap();
+//!   # }
+//!   ```
+//!   
+//!   ## Checksums
+//!   To tell bit-rot and partially written files apart from genuine syntax errors, set `WriteOptions::checksum` when writing with `Serializable::to_path_with_options()`. This stores the checksum of the file (as written) next to it, using CRC32C (`state.json.crc32c`) or BLAKE3 (`state.json.b3`) behind the `checksum-crc32c`- and `checksum-blake3`-features. `Serializable::from_path()` and friends then verify the file before deserializing it, and fail with an `Error::ChecksumMismatch` if it doesn't match. Files without a checksum file are read as usual, and all other functions that write files (e.g., `Serializable::to_path()`) remove existing checksum files, as they would no longer match. Checksum files are rotated along with backups, so `Serializable::from_path_with_fallback()` skips corrupt backups too:
+//!   ```rust
+//!   # #[cfg(all(feature = "serde-json", feature = "checksum-blake3"))] {
+//!   use serializable::{Checksum, Error, Serializable, WriteOptions};
+//!   # use serde::{Deserialize, Serialize};
+//!   # #[derive(Debug, Deserialize, Serialize)]
+//!   # struct State {
+//!   #     counter: u32,
+//!   # }
+//!   # impl Serializable<serializable::json::Serializer<State>> for State {}
+//!   # let dir = std::env::temp_dir().join("serializable_doc_checksums");
+//!   # std::fs::create_dir_all(&dir).unwrap();
+//!   # std::env::set_current_dir(dir).unwrap();
+//!   # let state = State { counter: 42 };
+//!   
+//!   state.to_path_with_options("state.json", &WriteOptions { checksum: Some(Checksum::Blake3), ..Default::default() }).unwrap();
+//!   
+//!   match State::from_path("state.json") {
+//!       Ok(state) => println!("{state:?}"),
+//!       Err(Error::ChecksumMismatch { path, .. }) => eprintln!("File '{}' is corrupt", path.display()),
+//!       Err(err) => eprintln!("{err}"),
+//!   }
+//!   # }
+//!   ```
+//!   
+//!   ## Signatures
+//!   To make sure files that you distribute (e.g., policy files) haven't been modified, write them with `Serializable::to_path_signed()` and read them with `Serializable::from_path_verified()` (behind the `sign-ed25519`-feature). The former writes the value in its backend's canonical form, signs those bytes with an Ed25519 `SigningKey` and then writes the signature next to the file (`policy.json.sig`), both atomically. The file itself stays readable by other tools. The latter only deserializes the file if it's signed by one of the given trusted `VerifyingKey`s, and fails with an `Error::SignatureUntrusted` otherwise:
+//!   ```rust
+//!   # #[cfg(all(feature = "serde-json", feature = "sign-ed25519"))] {
+//!   use serializable::signature::{SigningKey, VerifyingKey};
+//!   use serializable::{Error, Serializable};
+//!   # use serde::{Deserialize, Serialize};
+//!   # #[derive(Debug, Deserialize, Serialize)]
+//!   # struct Policy {
+//!   #     allow: Vec<String>,
+//!   # }
+//!   # impl Serializable<serializable::json::Serializer<Policy>> for Policy {}
+//!   # fn load_secret() -> [u8; 32] { [42; 32] }
+//!   # fn load_trusted_keys() -> Vec<VerifyingKey> { vec![SigningKey::from_bytes(&load_secret()).verifying_key()] }
+//!   # let dir = std::env::temp_dir().join("serializable_doc_signatures");
+//!   # std::fs::create_dir_all(&dir).unwrap();
+//!   # std::env::set_current_dir(dir).unwrap();
+//!   # let policy = Policy { allow: vec!["read".into()] };
+//!   
+//!   // On the distributing side
+//!   let key = SigningKey::from_bytes(&load_secret());
+//!   policy.to_path_signed("policy.json", &key).unwrap();
+//!   
+//!   // On the receiving side
+//!   let trusted: Vec<VerifyingKey> = load_trusted_keys();
+//!   match Policy::from_path_verified("policy.json", &trusted) {
+//!       Ok(policy) => println!("{policy:?}"),
+//!       Err(Error::SignatureUntrusted { path }) => eprintln!("File '{}' has been tampered with", path.display()),
+//!       Err(err) => eprintln!("{err}"),
+//!   }
+//!   # }
+//!   ```
+//!   For the JSON backend, the canonical form is canonical JSON (see below, regardless of its `json::Options`), so semantically equal values have the same signature. Backends without a canonical form (see `Serializer::to_bytes_canonical()`) sign the exact bytes that they serialize to (before compression) instead.
+//!   
+//!   ## Canonical JSON
+//!   To hash or sign serialized values, their bytes must be stable. Give the JSON serializer the `json::CanonicalOptions` (or your own `json::Options` with `CANONICAL` set) to write canonical JSON as specified by the JSON Canonicalization Scheme ([RFC 8785](https://www.rfc-editor.org/rfc/rfc8785)): object keys are sorted, there is no whitespace, and numbers and strings are written in a single normalized form. Two semantically equal values then serialize to the same bytes, regardless of, e.g., the iteration order of a `HashMap`. With the `hash-sha256`-feature, `Serializable::canonical_hash()` gives the SHA-256 hash of a value's canonical JSON (regardless of the backend it's serialized with otherwise):
+//!   ```rust
+//!   # #[cfg(all(feature = "hash-sha256", feature = "sign-ed25519"))] {
+//!   use serializable::json::{CanonicalOptions, Serializer};
+//!   use serializable::Serializable;
+//!   # use serde::{Deserialize, Serialize};
+//!   # #[derive(Debug, Deserialize, Serialize)]
+//!   # struct Policy {
+//!   #     allow: Vec<String>,
+//!   # }
+//!   # let dir = std::env::temp_dir().join("serializable_doc_canonical");
+//!   # std::fs::create_dir_all(&dir).unwrap();
+//!   # std::env::set_current_dir(dir).unwrap();
+//!   # let policy = Policy { allow: vec!["read".into()] };
+//!   # let key = serializable::signature::SigningKey::from_bytes(&[42; 32]);
+//!   
+//!   impl Serializable<Serializer<Policy, CanonicalOptions>> for Policy {}
+//!   
+//!   // Signs the canonical bytes
+//!   policy.to_path_signed("policy.json", &key).unwrap();
+//!   println!("{}", policy.canonical_hash().unwrap());
+//!   # }
+//!   ```
+//!   
 //!   ## Custom `Serializer`s
 //!   You can also implement your own serializers using the `Serializer` and `SerializerAsync` traits (the latter of which is only available when the `async-tokio`-feature is enabled). For an example of how to do so, check the [dummy serializer](https://github.com/Lut99/serializable-rs/blob/main/src/dummy.rs).
 //!   
@@ -106,9 +409,30 @@
 //!   # Features
 //!   This create has the following features:
 //!   - `async-tokio`: Enables the `SerializableAsync` and `SerializerAsync` traits for `async` contexts. Both of these are based on [`tokio`](https://tokio.rs/) as a backend.
+//!   - `checksum-blake3`: Enables writing and verifying [BLAKE3](https://github.com/BLAKE3-team/BLAKE3) checksums of files through `Checksum::Blake3`. Based on [`blake3`](https://github.com/BLAKE3-team/BLAKE3).
+//!   - `checksum-crc32c`: Enables writing and verifying [CRC32C](https://www.rfc-editor.org/rfc/rfc3720#appendix-B.4) checksums of files through `Checksum::Crc32c`. Based on [`crc32c`](https://github.com/zowens/crc32c).
+//!   - `compress-gzip`: Enables transparent [gzip](https://www.gzip.org) compression of files through `Compression::Gzip`. Based on [`flate2`](https://github.com/rust-lang/flate2-rs).
+//!   - `compress-xz`: Enables transparent [xz](https://tukaani.org/xz) compression of files through `Compression::Xz`. Based on [`xz2`](https://github.com/alexcrichton/xz2-rs).
+//!   - `compress-zstd`: Enables transparent [Zstandard](https://facebook.github.io/zstd) compression of files through `Compression::Zstd`. Based on [`zstd`](https://github.com/gyscos/zstd-rs).
+//!   - `derive`: Enables `#[derive(Serializable)]` for implementing `Serializable` for one or more backends at once. Based on the [`serializable-derive`](./derive) crate.
+//!   - `encrypt-aes-gcm`: Enables `serializable::encrypted::Encrypted` with `Cipher::Aes256Gcm` for encrypting the output of other serializers. Based on [`aes-gcm`](https://github.com/RustCrypto/AEADs).
+//!   - `encrypt-chacha20poly1305`: Enables `serializable::encrypted::Encrypted` with `Cipher::ChaCha20Poly1305` for encrypting the output of other serializers. Based on [`chacha20poly1305`](https://github.com/RustCrypto/AEADs).
+//!   - `hash-sha256`: Enables `Serializable::canonical_hash()` for computing the SHA-256 hash of the canonical JSON of a value. Implies `serde-json`. Based on [`sha2`](https://github.com/RustCrypto/hashes).
 //!   - `serde-json`: Provides the `serializable::json::Serializer`, which can be used to implement `Serializable` for [`serde`](https://serde.rs)-compatible types to serialize/deserialize to [JSON](https://json.org). Based on [`serde_json`](https://github.com/serde-rs/json).
+//!   - `serde-json5`: Provides the `serializable::json5::Serializer`, which can be used to implement `Serializable` for [`serde`](https://serde.rs)-compatible types to deserialize from relaxed [JSON5](https://json5.org) (allowing comments, trailing commas, unquoted keys, etc.) while still serializing to strict [JSON](https://json.org). Based on [`json5`](https://github.com/callum-oakley/json5-rs) and [`serde_json`](https://github.com/serde-rs/json).
 //!   - `serde-yaml`: Provides the `serializable::yaml::Serializer`, which can be used to implement `Serializable` for [`serde`](https://serde.rs)-compatible types to serialize/deserialize to [YAML](https://yaml.org). Based on [`serde_yaml`](https://github.com/dtolnay/serde-yaml).
 //!   - `serde-toml`: Provides the `serializable::toml::Serializer`, which can be used to implement `Serializable` for [`serde`](https://serde.rs)-compatible types to serialize/deserialize to [TOML](https://toml.io). Based on [`toml`](https://github.com/toml-rs/toml).
+//!   - `serde-ini`: Provides the `serializable::ini::Serializer`, which can be used to implement `Serializable` for [`serde`](https://serde.rs)-compatible types to serialize/deserialize to [INI](https://en.wikipedia.org/wiki/INI_file)-files. Only supports structs with scalar fields or with struct-fields of scalars, which map to sections.
+//!   - `serde-dotenv`: Provides the `serializable::dotenv::Serializer`, which can be used to implement `Serializable` for [`serde`](https://serde.rs)-compatible types to serialize/deserialize to `.env`-files (as used by, e.g., [Docker Compose](https://docs.docker.com/compose/environment-variables/env-file/)). Only supports structs with scalar fields or with struct-fields of scalars, which map to `PREFIX_KEY`-variables.
+//!   - `serde-msgpack`: Provides the `serializable::msgpack::Serializer`, which can be used to implement `Serializable` for [`serde`](https://serde.rs)-compatible types to serialize/deserialize to [MessagePack](https://msgpack.org). Based on [`rmp-serde`](https://github.com/3Hren/msgpack-rust).
+//!   - `serde-cbor`: Provides the `serializable::cbor::Serializer`, which can be used to implement `Serializable` for [`serde`](https://serde.rs)-compatible types to serialize/deserialize to [CBOR](https://cbor.io). Based on [`ciborium`](https://github.com/enarx/ciborium).
+//!   - `serde-ron`: Provides the `serializable::ron::Serializer`, which can be used to implement `Serializable` for [`serde`](https://serde.rs)-compatible types to serialize/deserialize to [RON](https://github.com/ron-rs/ron). Based on [`ron`](https://github.com/ron-rs/ron).
+//!   - `serde-bincode`: Provides the `serializable::bincode::Serializer`, which can be used to implement `Serializable` for [`serde`](https://serde.rs)-compatible types to serialize/deserialize to the compact, non-self-describing [bincode](https://github.com/bincode-org/bincode) format. Based on [`bincode`](https://github.com/bincode-org/bincode).
+//!   - `serde-postcard`: Provides the `serializable::postcard::Serializer`, which can be used to implement `Serializable` for [`serde`](https://serde.rs)-compatible types to serialize/deserialize to the compact, non-self-describing [postcard](https://postcard.jamesmunns.com) format. Based on [`postcard`](https://github.com/jamesmunns/postcard).
+//!   - `serde-xml`: Provides the `serializable::xml::Serializer`, which can be used to implement `Serializable` for [`serde`](https://serde.rs)-compatible types to serialize/deserialize to [XML](https://www.w3.org/XML). Based on [`quick-xml`](https://github.com/tafia/quick-xml).
+//!   - `serde-csv`: Provides the `serializable::csv::Serializer`, which is implemented for `Vec`s of flat, [`serde`](https://serde.rs)-compatible records (and can be implemented for other collections through `serializable::csv::Records`) to serialize/deserialize to [CSV](https://www.rfc-editor.org/rfc/rfc4180). Based on [`csv`](https://github.com/BurntSushi/rust-csv).
+//!   - `serde-bson`: Provides the `serializable::bson::Serializer`, which can be used to implement `Serializable` for [`serde`](https://serde.rs)-compatible types to serialize/deserialize to [BSON](https://bsonspec.org). Strings are (relaxed) [extended JSON](https://www.mongodb.com/docs/manual/reference/mongodb-extended-json/) instead. Based on [`bson`](https://github.com/mongodb/bson-rust).
+//!   - `sign-ed25519`: Enables `Serializable::to_path_signed()` and `Serializable::from_path_verified()` for signing files with (and verifying them against) [Ed25519](https://ed25519.cr.yp.to) keys. Based on [`ed25519-dalek`](https://github.com/dalek-cryptography/curve25519-dalek).
 //!   
 //!   
 //!   # License
@@ -121,20 +445,86 @@
 //
 
 // Declare the submodules
+#[cfg(feature = "serde-bincode")]
+pub mod bincode;
+#[cfg(feature = "serde-bson")]
+pub mod bson;
+#[cfg(feature = "serde-cbor")]
+pub mod cbor;
+pub mod checksum;
+pub mod compression;
+#[cfg(feature = "serde-csv")]
+pub mod csv;
+#[cfg(feature = "serde-dotenv")]
+pub mod dotenv;
 pub mod dummy;
+#[cfg(any(feature = "encrypt-aes-gcm", feature = "encrypt-chacha20poly1305"))]
+pub mod encrypted;
+#[cfg(any(feature = "serde-dotenv", feature = "serde-ini"))]
+mod flat;
+#[cfg(any(
+    feature = "serde-bson",
+    feature = "serde-cbor",
+    feature = "serde-dotenv",
+    feature = "serde-ini",
+    feature = "serde-json",
+    feature = "serde-json5",
+    feature = "serde-msgpack",
+    feature = "serde-ron",
+    feature = "serde-toml",
+    feature = "serde-xml",
+    feature = "serde-yaml"
+))]
+pub mod format;
+#[cfg(feature = "serde-ini")]
+pub mod ini;
 #[cfg(feature = "serde-json")]
 pub mod json;
+#[cfg(feature = "serde-json5")]
+pub mod json5;
+mod location;
+#[cfg(feature = "serde-msgpack")]
+pub mod msgpack;
+#[cfg(feature = "serde-postcard")]
+pub mod postcard;
+mod pretty;
+#[cfg(feature = "serde-ron")]
+pub mod ron;
 mod serializable;
 mod serializer;
+#[cfg(feature = "sign-ed25519")]
+pub mod signature;
 #[cfg(feature = "serde-toml")]
 pub mod toml;
+#[cfg(feature = "serde-xml")]
+pub mod xml;
 #[cfg(feature = "serde-yaml")]
 pub mod yaml;
 
 // Bring some of that into the crate namespace
+pub use checksum::Checksum;
+pub use compression::Compression;
+#[cfg(any(
+    feature = "serde-bson",
+    feature = "serde-cbor",
+    feature = "serde-dotenv",
+    feature = "serde-ini",
+    feature = "serde-json",
+    feature = "serde-json5",
+    feature = "serde-msgpack",
+    feature = "serde-ron",
+    feature = "serde-toml",
+    feature = "serde-xml",
+    feature = "serde-yaml"
+))]
+pub use format::{detect_format, Format, SerializableAuto};
+pub use location::{fmt_field_path, DeserializeContext, Location, PathSegment};
+pub use pretty::PrettyOptions;
 #[cfg(feature = "async-tokio")]
 pub use serializable::SerializableAsync;
-pub use serializable::{Error, Serializable};
-pub use serializer::Serializer;
+pub use serializable::{Error, Serializable, SerializableAs, WriteOptions};
+#[cfg(feature = "derive")]
+pub use serializable_derive::Serializable;
+pub use serializer::{Backend, Serializer};
 #[cfg(feature = "async-tokio")]
 pub use serializer::SerializerAsync;
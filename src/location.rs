@@ -0,0 +1,209 @@
+//  LOCATION.rs
+//    by Lut99
+//
+//  Created:
+//    16 Oct 2026, 22:14:31
+//  Last edited:
+//    17 Oct 2026, 09:21:37
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Defines the [`Location`], [`PathSegment`] and [`DeserializeContext`],
+//!   which backends use to report where in their input a deserialization
+//!   error occurred.
+//
+
+use std::fmt::{Display, Formatter, Result as FResult};
+
+
+/***** LIBRARY *****/
+/// Defines a position in the input of a deserializer, e.g., where it failed.
+///
+/// # Examples
+/// ```rust
+/// use serializable::Location;
+///
+/// let raw: &str = "hello: Hello\nworld: [World\n";
+/// let loc: Location = Location::from_offset(raw, 20);
+/// assert_eq!(loc, Location { line: 2, column: 8, byte_offset: Some(20) });
+/// assert_eq!(loc.to_string(), "line 2, column 8");
+/// assert_eq!(loc.snippet(raw), Some("world: [World"));
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Location {
+    /// The line of the position, starting at 1.
+    pub line: usize,
+    /// The column of the position, in bytes, starting at 1.
+    pub column: usize,
+    /// The offset of the position from the start of the input, in bytes, if known.
+    pub byte_offset: Option<usize>,
+}
+impl Location {
+    /// Constructor for the Location that computes it from a byte offset in the given input.
+    ///
+    /// # Arguments
+    /// - `raw`: The input to which the `byte_offset` refers.
+    /// - `byte_offset`: The offset in bytes from the start of `raw`. Offsets beyond the end of
+    ///   `raw` are clamped to its end.
+    ///
+    /// # Returns
+    /// A new Location with the line, column and (clamped) byte offset of the given offset.
+    #[inline]
+    pub fn from_offset(raw: &str, byte_offset: usize) -> Self {
+        let byte_offset: usize = byte_offset.min(raw.len());
+        let prefix: &[u8] = &raw.as_bytes()[..byte_offset];
+        let line_start: usize = prefix.iter().rposition(|b| *b == b'\n').map(|i| i + 1).unwrap_or(0);
+        Self { line: 1 + prefix.iter().filter(|b| **b == b'\n').count(), column: 1 + byte_offset - line_start, byte_offset: Some(byte_offset) }
+    }
+
+    /// Constructor for the Location that computes it from a line and column.
+    ///
+    /// # Arguments
+    /// - `line`: The line of the position, starting at 1.
+    /// - `column`: The column of the position, in bytes, starting at 1.
+    /// - `raw`: The input to which the position refers, if available. If given, it is used to
+    ///   compute the byte offset of the position.
+    ///
+    /// # Returns
+    /// A new Location with the given line and column, and the byte offset if `raw` was given and
+    /// the position is in it.
+    #[inline]
+    pub fn from_line_column(line: usize, column: usize, raw: Option<&str>) -> Self {
+        let byte_offset: Option<usize> = raw.and_then(|raw| {
+            // Find the start of the line, then add the column to it
+            let line_start: usize = if line <= 1 {
+                0
+            } else {
+                raw.bytes().enumerate().filter(|(_, b)| *b == b'\n').nth(line - 2).map(|(i, _)| i + 1)?
+            };
+            Some((line_start + column.saturating_sub(1)).min(raw.len()))
+        });
+        Self { line, column, byte_offset }
+    }
+
+    /// Returns the line in the given input that this Location points to.
+    ///
+    /// # Arguments
+    /// - `raw`: The input to which this Location refers.
+    ///
+    /// # Returns
+    /// The line (without its newline), or [`None`] if it's not in the given input.
+    #[inline]
+    pub fn snippet<'r>(&self, raw: &'r str) -> Option<&'r str> {
+        raw.split('\n').nth(self.line.checked_sub(1)?).map(|line| line.strip_suffix('\r').unwrap_or(line))
+    }
+}
+impl Display for Location {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FResult { write!(f, "line {}, column {}", self.line, self.column) }
+}
+
+
+
+/// Describes where a deserializer failed, as far as it knows.
+///
+/// This is carried by the `Deserialize*` variants of [`Error`](crate::Error), boxed to keep the
+/// error small.
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct DeserializeContext {
+    /// The path to the field that failed, or empty if it's unknown.
+    pub field_path: Vec<PathSegment>,
+    /// The [`Location`] in the input at which it failed, if known.
+    pub loc: Option<Location>,
+    /// The offending line of the input, if known.
+    pub snippet: Option<String>,
+}
+
+
+
+/// Defines a single step in the path to the field at which a deserializer failed.
+///
+/// Use [`fmt_field_path()`] to format a whole path (e.g., `servers[3].tls.cert_path`).
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum PathSegment {
+    /// A field of a struct, or a key of a map.
+    Field(String),
+    /// An index of a sequence.
+    Index(usize),
+    /// A variant of an enum.
+    Variant(String),
+    /// Some step that the deserializer couldn't identify (e.g., a non-string map key).
+    Unknown,
+}
+#[cfg(any(feature = "serde-json", feature = "serde-toml", feature = "serde-yaml"))]
+impl From<&serde_path_to_error::Segment> for PathSegment {
+    #[inline]
+    fn from(value: &serde_path_to_error::Segment) -> Self {
+        match value {
+            serde_path_to_error::Segment::Seq { index } => Self::Index(*index),
+            serde_path_to_error::Segment::Map { key } => Self::Field(key.clone()),
+            serde_path_to_error::Segment::Enum { variant } => Self::Variant(variant.clone()),
+            serde_path_to_error::Segment::Unknown => Self::Unknown,
+        }
+    }
+}
+
+/// Deserializes a value with the given deserializer, keeping track of the field it fails at.
+///
+/// # Arguments
+/// - `de`: The [`serde::Deserializer`] to deserialize with.
+///
+/// # Returns
+/// The deserialized value.
+///
+/// # Errors
+/// This function errors if the deserializer failed. In that case, the path to the field at which
+/// it failed is returned alongside its error.
+#[cfg(any(feature = "serde-json", feature = "serde-toml", feature = "serde-yaml"))]
+pub(crate) fn deserialize_tracked<'de, D: serde::Deserializer<'de>, T: serde::Deserialize<'de>>(de: D) -> Result<T, (Vec<PathSegment>, D::Error)> {
+    let mut track: serde_path_to_error::Track = serde_path_to_error::Track::new();
+    match T::deserialize(serde_path_to_error::Deserializer::new(de, &mut track)) {
+        Ok(res) => Ok(res),
+        Err(err) => Err((track.path().iter().map(PathSegment::from).collect(), err)),
+    }
+}
+
+/// Formats a path of [`PathSegment`]s the way it's written in most formats.
+///
+/// Fields and variants are separated by dots, and indices are given between square brackets.
+/// Unknown segments are written as `?`.
+///
+/// # Arguments
+/// - `path`: The path to format.
+///
+/// # Returns
+/// A [`Display`]able object that writes the path.
+///
+/// # Examples
+/// ```rust
+/// use serializable::{fmt_field_path, PathSegment};
+///
+/// let path: Vec<PathSegment> =
+///     vec![PathSegment::Field("servers".into()), PathSegment::Index(3), PathSegment::Field("tls".into()), PathSegment::Field("cert_path".into())];
+/// assert_eq!(fmt_field_path(&path).to_string(), "servers[3].tls.cert_path");
+/// ```
+#[inline]
+pub fn fmt_field_path(path: &[PathSegment]) -> impl '_ + Display {
+    /// Implements the formatter returned by [`fmt_field_path()`].
+    struct FieldPathFormatter<'p>(&'p [PathSegment]);
+    impl Display for FieldPathFormatter<'_> {
+        fn fmt(&self, f: &mut Formatter<'_>) -> FResult {
+            for (i, segment) in self.0.iter().enumerate() {
+                match segment {
+                    PathSegment::Field(name) | PathSegment::Variant(name) => {
+                        if i > 0 {
+                            write!(f, ".")?;
+                        }
+                        write!(f, "{name}")?;
+                    },
+                    PathSegment::Index(index) => write!(f, "[{index}]")?,
+                    PathSegment::Unknown => write!(f, "{}?", if i > 0 { "." } else { "" })?,
+                }
+            }
+            Ok(())
+        }
+    }
+
+    FieldPathFormatter(path)
+}
@@ -0,0 +1,195 @@
+//  MSGPACK.rs
+//    by Lut99
+//
+//  Created:
+//    16 Oct 2026, 21:06:50
+//  Last edited:
+//    16 Oct 2026, 21:57:14
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Implements [`serializer::Serializer`] and cohorts for [`rmp_serde`].
+//
+
+use std::error;
+use std::fmt::{Display, Formatter, Result as FResult};
+use std::io::{Read, Write};
+use std::marker::PhantomData;
+
+use serde::{Deserialize, Serialize};
+
+use crate::serializer;
+
+
+/***** ERRORS *****/
+/// Defines errors that occur when using the MessagePack [`Serializer`].
+#[derive(Debug)]
+pub enum Error {
+    /// Failed to write to the given writer.
+    #[cfg(feature = "async-tokio")]
+    Write { err: std::io::Error },
+    /// Failed to read from the given reader.
+    #[cfg(feature = "async-tokio")]
+    Read { err: std::io::Error },
+    /// Failed to flush the given writer.
+    #[cfg(feature = "async-tokio")]
+    Flush { err: std::io::Error },
+    /// MessagePack is a binary format, and cannot be represented as a string.
+    NotText,
+    /// Failed to serialize the object to MessagePack.
+    Serialize { err: rmp_serde::encode::Error },
+    /// Failed to deserialize the object from MessagePack.
+    Deserialize { err: rmp_serde::decode::Error },
+}
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FResult {
+        use Error::*;
+        match self {
+            #[cfg(feature = "async-tokio")]
+            Write { .. } => write!(f, "Failed to write to given writer"),
+            #[cfg(feature = "async-tokio")]
+            Read { .. } => write!(f, "Failed to read from given reader"),
+            #[cfg(feature = "async-tokio")]
+            Flush { .. } => write!(f, "Failed to flush the given writer"),
+            NotText => write!(f, "MessagePack is a binary format and cannot be serialized to or deserialized from a string"),
+            Serialize { .. } => write!(f, "Failed to serialize to MessagePack"),
+            Deserialize { .. } => write!(f, "Failed to deserialize from MessagePack"),
+        }
+    }
+}
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        use Error::*;
+        match self {
+            #[cfg(feature = "async-tokio")]
+            Write { err } => Some(err),
+            #[cfg(feature = "async-tokio")]
+            Read { err } => Some(err),
+            #[cfg(feature = "async-tokio")]
+            Flush { err } => Some(err),
+            NotText => None,
+            Serialize { err } => Some(err),
+            Deserialize { err } => Some(err),
+        }
+    }
+}
+
+
+
+
+
+/***** LIBRARY *****/
+/// Implements a [`serializer::Serializer`] for [`rmp_serde`].
+///
+/// Structs are serialized as maps (i.e., with their field names), which keeps the output
+/// self-describing and readable by other MessagePack implementations.
+///
+/// Note that MessagePack is a binary format. As such, [`serializer::Serializer::to_string()`] and
+/// [`serializer::Serializer::from_str()`] always fail with an [`Error::NotText`]; use the byte-,
+/// writer- and reader-functions instead. Further, this serializer has no pretty version available,
+/// so [`serializer::Serializer::to_bytes_pretty()`] and [`serializer::Serializer::to_writer_pretty()`]
+/// are simply aliases for [`serializer::Serializer::to_bytes()`] and
+/// [`serializer::Serializer::to_writer()`], respectively.
+///
+/// # Examples
+/// ```rust
+/// use serde::{Deserialize, Serialize};
+/// use serializable::msgpack::Serializer;
+/// use serializable::Serializable;
+///
+/// #[derive(Debug, Deserialize, Eq, PartialEq, Serialize)]
+/// struct HelloWorld {
+///     hello: String,
+///     world: String,
+/// }
+/// impl Serializable<Serializer<HelloWorld>> for HelloWorld {}
+///
+/// assert_eq!(
+///     HelloWorld { hello: "Hello".into(), world: "World".into() }.to_bytes().unwrap(),
+///     b"\x82\xa5hello\xa5Hello\xa5world\xa5World"
+/// );
+///
+/// assert_eq!(
+///     HelloWorld::from_bytes(b"\x82\xa5hello\xa7Goodbye\xa5world\xa6Planet").unwrap(),
+///     HelloWorld { hello: "Goodbye".into(), world: "Planet".into() }
+/// );
+///
+/// // Strings are not supported
+/// assert!(HelloWorld { hello: "Hello".into(), world: "World".into() }.to_string().is_err());
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct Serializer<T>(PhantomData<T>);
+
+/// Marks the MessagePack [`Serializer`] as a [`Backend`](crate::Backend).
+///
+/// Use it to select this backend in [`SerializableAs`](crate::SerializableAs) (e.g.,
+/// `value.to_string_as::<MessagePack>()`).
+#[derive(Clone, Copy, Debug)]
+pub struct MessagePack;
+impl serializer::Backend for MessagePack {
+    type Serializer<T> = Serializer<T>;
+}
+
+impl<T: for<'de> Deserialize<'de> + Serialize> serializer::Serializer for Serializer<T> {
+    type Error = Error;
+    type Target = T;
+
+    #[inline]
+    fn to_string(_value: &Self::Target) -> Result<String, Self::Error> { Err(Error::NotText) }
+
+    #[inline]
+    fn to_bytes(value: &Self::Target) -> Result<Vec<u8>, Self::Error> { rmp_serde::to_vec_named(value).map_err(|err| Error::Serialize { err }) }
+
+    #[inline]
+    fn to_writer(value: &Self::Target, mut writer: impl Write) -> Result<(), Self::Error> {
+        rmp_serde::encode::write_named(&mut writer, value).map_err(|err| Error::Serialize { err })
+    }
+
+    #[inline]
+    fn from_str(_raw: impl AsRef<str>) -> Result<Self::Target, Self::Error> { Err(Error::NotText) }
+
+    #[inline]
+    fn from_bytes(raw: impl AsRef<[u8]>) -> Result<Self::Target, Self::Error> {
+        rmp_serde::from_slice(raw.as_ref()).map_err(|err| Error::Deserialize { err })
+    }
+
+    #[inline]
+    fn from_reader(reader: impl Read) -> Result<Self::Target, Self::Error> { rmp_serde::from_read(reader).map_err(|err| Error::Deserialize { err }) }
+}
+
+#[cfg(feature = "async-tokio")]
+#[async_trait::async_trait]
+impl<T: Send + Sync + for<'de> Deserialize<'de> + Serialize> serializer::SerializerAsync for Serializer<T> {
+    #[inline]
+    async fn to_writer_async(value: &Self::Target, mut writer: impl Send + std::marker::Unpin + tokio::io::AsyncWrite) -> Result<(), Self::Error> {
+        use tokio::io::AsyncWriteExt;
+
+        // Serialize ourselves to a buffer first
+        let raw: Vec<u8> = <Self as serializer::Serializer>::to_bytes(value)?;
+
+        // Now write to the writer
+        if let Err(err) = writer.write_all(&raw).await {
+            return Err(Error::Write { err });
+        }
+        // ...making sure its flushed
+        match writer.flush().await {
+            Ok(_) => Ok(()),
+            Err(err) => Err(Error::Flush { err }),
+        }
+    }
+
+    #[inline]
+    async fn from_reader_async(mut reader: impl Send + std::marker::Unpin + tokio::io::AsyncRead) -> Result<T, Self::Error> {
+        use tokio::io::AsyncReadExt;
+
+        // Read the entire buffer first
+        let mut raw: Vec<u8> = Vec::new();
+        if let Err(err) = reader.read_to_end(&mut raw).await {
+            return Err(Error::Read { err });
+        }
+
+        // Then deserialize as bytes
+        <Self as serializer::Serializer>::from_bytes(&raw)
+    }
+}
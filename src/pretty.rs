@@ -0,0 +1,273 @@
+//  PRETTY.rs
+//    by Lut99
+//
+//  Created:
+//    17 Oct 2026, 02:06:18
+//  Last edited:
+//    17 Oct 2026, 12:21:44
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Defines the [`PrettyOptions`] with which the pretty output of the
+//!   backends can be tweaked, together with some helpers to apply them.
+//
+
+#[cfg(any(feature = "serde-json", feature = "serde-json5"))]
+use std::io::Write;
+
+#[cfg(any(feature = "serde-json", feature = "serde-json5"))]
+use serde::Serialize;
+
+
+/***** HELPERS *****/
+/// Recursively sorts the keys of all objects in the given JSON value.
+///
+/// # Arguments
+/// - `value`: The [`serde_json::Value`] to sort.
+///
+/// # Returns
+/// The same value, but with the keys of all its objects in lexicographical order.
+#[cfg(any(feature = "serde-json", feature = "serde-json5"))]
+fn sort_json_keys(value: serde_json::Value) -> serde_json::Value {
+    use serde_json::Value;
+    match value {
+        Value::Object(values) => {
+            // NOTE: Objects are not necessarily sorted already, as `serde_json/preserve_order` may be enabled
+            let mut entries: Vec<(String, Value)> = values.into_iter().map(|(key, value)| (key, sort_json_keys(value))).collect();
+            entries.sort_by(|(lhs, _), (rhs, _)| lhs.cmp(rhs));
+            Value::Object(entries.into_iter().collect())
+        },
+        Value::Array(values) => Value::Array(values.into_iter().map(sort_json_keys).collect()),
+        value => value,
+    }
+}
+
+/// Serializes the given value to pretty JSON with the given indentation.
+///
+/// # Arguments
+/// - `value`: The value to serialize.
+/// - `indent`: The string to indent every level with.
+/// - `sort_keys`: Whether to sort the keys of all objects first (see [`sort_json_keys()`]).
+/// - `writer`: The [`Write`]r to write the serialized value to.
+///
+/// # Errors
+/// This function errors if the value failed to serialize to JSON, or if we failed to write it.
+#[cfg(any(feature = "serde-json", feature = "serde-json5"))]
+fn write_json<T: ?Sized + Serialize>(value: &T, indent: &str, sort_keys: bool, writer: impl Write) -> Result<(), serde_json::Error> {
+    let mut ser = serde_json::Serializer::with_formatter(writer, serde_json::ser::PrettyFormatter::with_indent(indent.as_bytes()));
+    if sort_keys {
+        sort_json_keys(serde_json::to_value(value)?).serialize(&mut ser)
+    } else {
+        value.serialize(&mut ser)
+    }
+}
+
+/// Puts the arrays in the given pretty JSON on one line if they're short enough.
+///
+/// Only arrays of at most `max_len` elements that are all scalars (or empty arrays or objects) are
+/// put on one line. This relies on the layout of [`serde_json::ser::PrettyFormatter`], which
+/// writes every element on its own line and never writes newlines in strings.
+///
+/// # Arguments
+/// - `raw`: The pretty JSON to put short arrays on one line in.
+/// - `indent`: The string with which `raw` is indented.
+/// - `max_len`: The maximum number of elements of arrays to put on one line.
+///
+/// # Returns
+/// The same JSON, with short arrays on one line (e.g., `[1, 2, 3]`).
+#[cfg(any(feature = "serde-json", feature = "serde-json5"))]
+fn inline_json_arrays(raw: &str, indent: &str, max_len: usize) -> String {
+    /// Returns the indentation (i.e., the repetitions of `indent`) at the start of `line`.
+    fn indentation<'l>(line: &'l str, indent: &str) -> &'l str {
+        if indent.is_empty() {
+            return "";
+        }
+        let mut rest: &str = line;
+        while let Some(next) = rest.strip_prefix(indent) {
+            rest = next;
+        }
+        &line[..line.len() - rest.len()]
+    }
+
+    let lines: Vec<&str> = raw.lines().collect();
+    let mut res: String = String::with_capacity(raw.len());
+    let mut i: usize = 0;
+    while i < lines.len() {
+        if i > 0 {
+            res.push('\n');
+        }
+        let line: &str = lines[i];
+        i += 1;
+        if !line.ends_with('[') {
+            res.push_str(line);
+            continue;
+        }
+
+        // Collect the elements of the array, as long as they're scalars
+        let outer: &str = indentation(line, indent);
+        let mut elems: Vec<&str> = Vec::new();
+        let mut close: Option<&str> = None;
+        for next in &lines[i..] {
+            if let Some(suffix) = next.strip_prefix(outer).and_then(|next| next.strip_prefix(']')) {
+                close = Some(suffix);
+                break;
+            }
+            match next.strip_prefix(outer).and_then(|next| next.strip_prefix(indent)) {
+                Some(elem) if elems.len() < max_len && !elem.ends_with('[') && !elem.ends_with('{') => elems.push(elem),
+                _ => break,
+            }
+        }
+        let Some(close) = close else {
+            res.push_str(line);
+            continue;
+        };
+
+        // Write them on one line
+        res.push_str(line);
+        for (j, elem) in elems.iter().enumerate() {
+            if j > 0 {
+                res.push(' ');
+            }
+            res.push_str(elem);
+        }
+        res.push(']');
+        res.push_str(close);
+        i += elems.len() + 1;
+    }
+    res
+}
+
+
+
+
+
+/***** LIBRARY *****/
+/// Configures the pretty output of the backends.
+///
+/// Every backend honours these options as far as it can, and ignores the ones it can't (e.g.,
+/// [YAML](https://yaml.org) has a fixed indentation). In particular:
+/// - [JSON](crate::json) and [JSON5](crate::json5) honour all of them;
+/// - [YAML](crate::yaml) and [TOML](crate::toml) honour `sort_keys` and `trailing_newline`;
+/// - [RON](crate::ron) honours `indent` and `trailing_newline`;
+/// - [XML](crate::xml) honours `trailing_newline`, and `indent` if it consists of a single
+///   repeated character; and
+/// - the other backends ignore them.
+///
+/// Use [`PrettyOptions::DEFAULT`] to get every backend's own style, and override the fields you
+/// need. To have a type always be pretty-printed with certain options, set
+/// [`Serializable::PRETTY_OPTIONS`](crate::Serializable::PRETTY_OPTIONS).
+///
+/// # Examples
+/// ```rust
+/// use serializable::PrettyOptions;
+///
+/// // Four-space indentation and a trailing newline
+/// const OPTS: PrettyOptions = PrettyOptions { indent: Some("    "), trailing_newline: true, ..PrettyOptions::DEFAULT };
+/// assert!(!OPTS.sort_keys);
+/// assert_eq!(PrettyOptions::default(), PrettyOptions::DEFAULT);
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct PrettyOptions {
+    /// The string to indent every level with (e.g., `"\t"`). If [`None`], then the backend's
+    /// default is used (e.g., two spaces for JSON).
+    pub indent: Option<&'static str>,
+    /// Whether to sort the keys of maps (and the fields of structs) lexicographically, instead of
+    /// writing them in the order they're serialized in.
+    pub sort_keys: bool,
+    /// The maximum number of elements of arrays to write on one line (e.g., `[1, 2, 3]`).
+    ///
+    /// Only arrays that contain no nested arrays or objects are put on one line. If [`None`], then
+    /// arrays are written like the backend does by default.
+    pub max_inline_array_len: Option<usize>,
+    /// Whether to make sure the output ends with a newline.
+    ///
+    /// Note that some backends always write one (e.g., YAML). If false, then the output is left
+    /// as the backend writes it.
+    pub trailing_newline: bool,
+}
+impl PrettyOptions {
+    /// The default options, which leave the pretty output of every backend as-is.
+    pub const DEFAULT: Self = Self { indent: None, sort_keys: false, max_inline_array_len: None, trailing_newline: false };
+}
+impl Default for PrettyOptions {
+    #[inline]
+    fn default() -> Self { Self::DEFAULT }
+}
+
+
+
+/// Applies the [`PrettyOptions::trailing_newline`] to the given output.
+///
+/// # Arguments
+/// - `raw`: The pretty output of a backend.
+/// - `opts`: The [`PrettyOptions`] to apply.
+///
+/// # Returns
+/// `raw`, with a newline appended if asked for and if it didn't end with one yet.
+#[cfg(any(
+    feature = "serde-json",
+    feature = "serde-json5",
+    feature = "serde-ron",
+    feature = "serde-toml",
+    feature = "serde-xml",
+    feature = "serde-yaml"
+))]
+#[inline]
+pub(crate) fn finish(mut raw: String, opts: &PrettyOptions) -> String {
+    if opts.trailing_newline && !raw.ends_with('\n') {
+        raw.push('\n');
+    }
+    raw
+}
+
+/// Serializes the given value to pretty JSON, honouring the given [`PrettyOptions`].
+///
+/// # Arguments
+/// - `value`: The value to serialize.
+/// - `opts`: The [`PrettyOptions`] to honour.
+///
+/// # Returns
+/// The serialized value.
+///
+/// # Errors
+/// This function errors if the value failed to serialize to JSON.
+#[cfg(feature = "serde-json5")]
+pub(crate) fn to_json_string<T: ?Sized + Serialize>(value: &T, opts: &PrettyOptions) -> Result<String, serde_json::Error> {
+    let mut raw: Vec<u8> = Vec::new();
+    to_json_writer(value, opts, &mut raw)?;
+    Ok(String::from_utf8(raw).expect("serde_json wrote invalid UTF-8"))
+}
+
+/// Serializes the given value to pretty JSON in the given writer, honouring the given
+/// [`PrettyOptions`].
+///
+/// The output is streamed to `writer`, unless short arrays have to be put on one line (see
+/// [`PrettyOptions::max_inline_array_len`]), in which case it's serialized in memory first.
+///
+/// # Arguments
+/// - `value`: The value to serialize.
+/// - `opts`: The [`PrettyOptions`] to honour.
+/// - `writer`: The [`Write`]r to write the serialized value to.
+///
+/// # Errors
+/// This function errors if the value failed to serialize to JSON, or if we failed to write it.
+#[cfg(any(feature = "serde-json", feature = "serde-json5"))]
+pub(crate) fn to_json_writer<T: ?Sized + Serialize>(value: &T, opts: &PrettyOptions, mut writer: impl Write) -> Result<(), serde_json::Error> {
+    let indent: &str = opts.indent.unwrap_or("  ");
+    let Some(max_len) = opts.max_inline_array_len else {
+        // Nothing to post-process, so write directly (the formatter never ends with a newline)
+        write_json(value, indent, opts.sort_keys, &mut writer)?;
+        if opts.trailing_newline {
+            writer.write_all(b"\n").map_err(serde_json::Error::io)?;
+        }
+        return Ok(());
+    };
+
+    // Otherwise, serialize to memory first to put the short arrays on one line
+    let mut raw: Vec<u8> = Vec::new();
+    write_json(value, indent, opts.sort_keys, &mut raw)?;
+    let raw: String = String::from_utf8(raw).expect("serde_json wrote invalid UTF-8");
+    let raw: String = finish(inline_json_arrays(&raw, indent, max_len), opts);
+    writer.write_all(raw.as_bytes()).map_err(serde_json::Error::io)
+}
@@ -0,0 +1,276 @@
+//  RON.rs
+//    by Lut99
+//
+//  Created:
+//    16 Oct 2026, 21:10:58
+//  Last edited:
+//    17 Oct 2026, 02:06:18
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Implements [`serializer::Serializer`] and cohorts for [`ron`].
+//
+
+use std::error;
+use std::fmt::{Display, Formatter, Result as FResult};
+use std::io::{Read, Write};
+use std::marker::PhantomData;
+
+use ron::ser::PrettyConfig;
+use serde::{Deserialize, Serialize};
+
+use crate::pretty::{self, PrettyOptions};
+use crate::serializer;
+
+
+/***** ERRORS *****/
+/// Defines errors that occur when using the RON [`Serializer`].
+#[derive(Debug)]
+pub enum Error {
+    /// Failed to write to the given writer.
+    Write { err: std::io::Error },
+    /// Failed to read from the given reader.
+    #[cfg(feature = "async-tokio")]
+    Read { err: std::io::Error },
+    /// Failed to flush the given writer.
+    #[cfg(feature = "async-tokio")]
+    Flush { err: std::io::Error },
+    /// Failed to serialize the object to RON.
+    Serialize { err: ron::Error },
+    /// Failed to deserialize the object from RON.
+    Deserialize { err: ron::error::SpannedError },
+}
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FResult {
+        use Error::*;
+        match self {
+            Write { .. } => write!(f, "Failed to write to given writer"),
+            #[cfg(feature = "async-tokio")]
+            Read { .. } => write!(f, "Failed to read from given reader"),
+            #[cfg(feature = "async-tokio")]
+            Flush { .. } => write!(f, "Failed to flush the given writer"),
+            Serialize { .. } => write!(f, "Failed to serialize to RON"),
+            Deserialize { .. } => write!(f, "Failed to deserialize from RON"),
+        }
+    }
+}
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        use Error::*;
+        match self {
+            Write { err } => Some(err),
+            #[cfg(feature = "async-tokio")]
+            Read { err } => Some(err),
+            #[cfg(feature = "async-tokio")]
+            Flush { err } => Some(err),
+            Serialize { err } => Some(err),
+            Deserialize { err } => Some(err),
+        }
+    }
+}
+
+
+
+
+
+/***** HELPERS *****/
+/// Serializes the given value to pretty RON, honouring the given [`PrettyOptions`].
+///
+/// Only [`PrettyOptions::indent`] and [`PrettyOptions::trailing_newline`] are supported.
+///
+/// # Arguments
+/// - `value`: The value to serialize.
+/// - `opts`: The [`PrettyOptions`] to honour.
+///
+/// # Returns
+/// The serialized value.
+///
+/// # Errors
+/// This function errors if the value failed to serialize to RON.
+fn serialize_pretty<T: Serialize>(value: &T, opts: &PrettyOptions) -> Result<String, Error> {
+    let mut config: PrettyConfig = PrettyConfig::default();
+    if let Some(indent) = opts.indent {
+        config = config.indentor(indent.into());
+    }
+    let raw: String = ron::ser::to_string_pretty(value, config).map_err(|err| Error::Serialize { err })?;
+    Ok(pretty::finish(raw, opts))
+}
+
+
+
+
+
+/***** LIBRARY *****/
+/// Implements a [`serializer::Serializer`] for [`ron`].
+///
+/// The compact functions serialize without any whitespace, whereas the pretty functions use RON's
+/// default [`PrettyConfig`]. Of the [`PrettyOptions`], only [`PrettyOptions::indent`] and
+/// [`PrettyOptions::trailing_newline`] are honoured.
+///
+/// # Examples
+/// ```rust
+/// use serde::{Deserialize, Serialize};
+/// use serializable::ron::Serializer;
+/// use serializable::Serializable;
+///
+/// #[derive(Debug, Deserialize, Eq, PartialEq, Serialize)]
+/// struct HelloWorld {
+///     hello: String,
+///     world: String,
+/// }
+/// impl Serializable<Serializer<HelloWorld>> for HelloWorld {}
+///
+/// assert_eq!(
+///     HelloWorld { hello: "Hello".into(), world: "World".into() }.to_string().unwrap(),
+///     "(hello:\"Hello\",world:\"World\")"
+/// );
+/// assert_eq!(
+///     HelloWorld { hello: "Hello".into(), world: "World".into() }.to_string_pretty().unwrap(),
+///     "(\n    hello: \"Hello\",\n    world: \"World\",\n)"
+/// );
+///
+/// assert_eq!(
+///     HelloWorld::from_str("(hello: \"Goodbye\", world: \"Planet\")").unwrap(),
+///     HelloWorld { hello: "Goodbye".into(), world: "Planet".into() }
+/// )
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct Serializer<T>(PhantomData<T>);
+
+/// Marks the RON [`Serializer`] as a [`Backend`](crate::Backend).
+///
+/// Use it to select this backend in [`SerializableAs`](crate::SerializableAs) (e.g.,
+/// `value.to_string_as::<Ron>()`).
+#[derive(Clone, Copy, Debug)]
+pub struct Ron;
+impl serializer::Backend for Ron {
+    type Serializer<T> = Serializer<T>;
+}
+
+impl<T: for<'de> Deserialize<'de> + Serialize> serializer::Serializer for Serializer<T> {
+    type Error = Error;
+    type Target = T;
+
+    #[inline]
+    fn to_string(value: &Self::Target) -> Result<String, Self::Error> { ron::to_string(value).map_err(|err| Error::Serialize { err }) }
+
+    #[inline]
+    fn to_string_pretty(value: &Self::Target) -> Result<String, Self::Error> {
+        ron::ser::to_string_pretty(value, PrettyConfig::default()).map_err(|err| Error::Serialize { err })
+    }
+
+    #[inline]
+    fn to_string_pretty_with(value: &Self::Target, opts: &PrettyOptions) -> Result<String, Self::Error> { serialize_pretty(value, opts) }
+
+    #[inline]
+    fn to_bytes_pretty_with(value: &Self::Target, opts: &PrettyOptions) -> Result<Vec<u8>, Self::Error> {
+        serialize_pretty(value, opts).map(String::into_bytes)
+    }
+
+    #[inline]
+    fn to_writer(value: &Self::Target, writer: impl Write) -> Result<(), Self::Error> {
+        ron::ser::to_writer(writer, value).map_err(|err| Error::Serialize { err })
+    }
+
+    #[inline]
+    fn to_writer_pretty(value: &Self::Target, writer: impl Write) -> Result<(), Self::Error> {
+        ron::ser::to_writer_pretty(writer, value, PrettyConfig::default()).map_err(|err| Error::Serialize { err })
+    }
+
+    #[inline]
+    fn to_writer_pretty_with(value: &Self::Target, opts: &PrettyOptions, mut writer: impl Write) -> Result<(), Self::Error> {
+        let raw: String = serialize_pretty(value, opts)?;
+        writer.write_all(raw.as_bytes()).map_err(|err| Error::Write { err })
+    }
+
+    #[inline]
+    fn from_str(raw: impl AsRef<str>) -> Result<Self::Target, Self::Error> { ron::from_str(raw.as_ref()).map_err(|err| Error::Deserialize { err }) }
+
+    #[inline]
+    fn from_bytes(raw: impl AsRef<[u8]>) -> Result<Self::Target, Self::Error> {
+        ron::de::from_bytes(raw.as_ref()).map_err(|err| Error::Deserialize { err })
+    }
+
+    #[inline]
+    fn from_reader(reader: impl Read) -> Result<Self::Target, Self::Error> { ron::de::from_reader(reader).map_err(|err| Error::Deserialize { err }) }
+}
+
+#[cfg(feature = "async-tokio")]
+#[async_trait::async_trait]
+impl<T: Send + Sync + for<'de> Deserialize<'de> + Serialize> serializer::SerializerAsync for Serializer<T> {
+    #[inline]
+    async fn to_writer_async(value: &Self::Target, mut writer: impl Send + std::marker::Unpin + tokio::io::AsyncWrite) -> Result<(), Self::Error> {
+        use tokio::io::AsyncWriteExt;
+
+        // Serialize ourselves to a string first
+        let raw: String = <Self as serializer::Serializer>::to_string(value)?;
+
+        // Now write to the writer
+        if let Err(err) = writer.write_all(raw.as_bytes()).await {
+            return Err(Error::Write { err });
+        }
+        // ...making sure its flushed
+        match writer.flush().await {
+            Ok(_) => Ok(()),
+            Err(err) => Err(Error::Flush { err }),
+        }
+    }
+
+    #[inline]
+    async fn to_writer_pretty_async(
+        value: &Self::Target,
+        mut writer: impl Send + std::marker::Unpin + tokio::io::AsyncWrite,
+    ) -> Result<(), Self::Error> {
+        use tokio::io::AsyncWriteExt;
+
+        // Serialize ourselves to a string first
+        let raw: String = <Self as serializer::Serializer>::to_string_pretty(value)?;
+
+        // Now write to the writer
+        if let Err(err) = writer.write_all(raw.as_bytes()).await {
+            return Err(Error::Write { err });
+        }
+        // ...making sure its flushed
+        match writer.flush().await {
+            Ok(_) => Ok(()),
+            Err(err) => Err(Error::Flush { err }),
+        }
+    }
+
+    #[inline]
+    async fn to_writer_pretty_with_async(
+        value: &Self::Target,
+        opts: &PrettyOptions,
+        mut writer: impl Send + std::marker::Unpin + tokio::io::AsyncWrite,
+    ) -> Result<(), Self::Error> {
+        use tokio::io::AsyncWriteExt;
+
+        // Serialize ourselves to a string first
+        let raw: String = <Self as serializer::Serializer>::to_string_pretty_with(value, opts)?;
+
+        // Now write to the writer
+        if let Err(err) = writer.write_all(raw.as_bytes()).await {
+            return Err(Error::Write { err });
+        }
+        // ...making sure its flushed
+        match writer.flush().await {
+            Ok(_) => Ok(()),
+            Err(err) => Err(Error::Flush { err }),
+        }
+    }
+
+    #[inline]
+    async fn from_reader_async(mut reader: impl Send + std::marker::Unpin + tokio::io::AsyncRead) -> Result<T, Self::Error> {
+        use tokio::io::AsyncReadExt;
+
+        // Read the entire buffer first
+        let mut raw: String = String::new();
+        if let Err(err) = reader.read_to_string(&mut raw).await {
+            return Err(Error::Read { err });
+        }
+
+        // Then deserialize as string
+        <Self as serializer::Serializer>::from_str(&raw)
+    }
+}
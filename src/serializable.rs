@@ -4,7 +4,7 @@
 //  Created:
 //    28 Oct 2023, 11:28:42
 //  Last edited:
-//    30 Oct 2023, 12:28:53
+//    17 Oct 2026, 12:08:15
 //  Auto updated?
 //    Yes
 //
@@ -17,11 +17,18 @@
 use std::any::type_name;
 use std::error;
 use std::fmt::{Display, Formatter, Result as FResult};
-use std::fs::File;
+use std::ffi::OsString;
+use std::fs::{File, OpenOptions};
 use std::io::{Read, Write};
 use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::{Duration, Instant};
 
-use crate::serializer::Serializer;
+use crate::checksum::{Checksum, Hasher, Hashing};
+use crate::compression::{Compression, Decoder, Encoder};
+use crate::location::{fmt_field_path, DeserializeContext, Location, PathSegment};
+use crate::pretty::PrettyOptions;
+use crate::serializer::{Backend, Serializer};
 
 
 
@@ -29,29 +36,111 @@ use crate::serializer::Serializer;
 
 /***** ERRORS **** */
 /// Defines errors that are occuring when using the [`Serializable`]-trait.
+///
+/// Deserialization errors with a known [`Location`] mention it in their
+/// message. Additionally, when formatted with the alternate flag (i.e.,
+/// `{:#}`), they also print the offending line of the input with a caret
+/// pointing at the error.
+///
+/// # Examples
+/// ```rust
+/// # #[cfg(feature = "serde-yaml")] {
+/// use serde::{Deserialize, Serialize};
+/// use serializable::yaml::Serializer;
+/// use serializable::Serializable;
+///
+/// #[derive(Debug, Deserialize, Serialize)]
+/// struct HelloWorld {
+///     hello: String,
+///     world: String,
+/// }
+/// impl Serializable<Serializer<HelloWorld>> for HelloWorld {}
+///
+/// let err = HelloWorld::from_str("hello: Hello\nworld: [World\n").unwrap_err();
+/// assert!(format!("{err}").ends_with("from the given string in field 'world' (line 2, column 8)"));
+/// assert!(format!("{err:#}").ends_with("from the given string in field 'world' (line 2, column 8)\n  2 | world: [World\n    |        ^"));
+/// # }
+/// ```
+///
+/// New variants may be added in the future, which is why this enum is
+/// `#[non_exhaustive]`.
 #[derive(Debug)]
+#[non_exhaustive]
 pub enum Error<E> {
     /// Failed to create a new file.
     FileCreate { path: PathBuf, err: std::io::Error },
     /// Failed to open a new file.
     FileOpen { path: PathBuf, err: std::io::Error },
+    /// Failed to read from the given file.
+    FileRead { path: PathBuf, err: std::io::Error },
+    /// Failed to write to the given file.
+    FileWrite { path: PathBuf, err: std::io::Error },
     /// Failed to flush the given file.
     #[cfg(feature = "async-tokio")]
     FileFlush { path: PathBuf, err: std::io::Error },
+    /// Failed to compress the contents of the given file.
+    FileCompress { path: PathBuf, err: std::io::Error },
+    /// Failed to decompress the contents of the given file.
+    FileDecompress { path: PathBuf, err: std::io::Error },
+    /// Failed to sync the given file to disk.
+    FileSync { path: PathBuf, err: std::io::Error },
+    /// Failed to move a (temporary) file to its final destination.
+    FileRename { from: PathBuf, to: PathBuf, err: std::io::Error },
+    /// Failed to sync the given directory to disk.
+    DirSync { path: PathBuf, err: std::io::Error },
+    /// Failed to rotate a file to (or between) its backups.
+    FileBackup { path: PathBuf, backup: PathBuf, err: std::io::Error },
+    /// Failed to lock the given file (or timed out waiting for it).
+    FileLock { path: PathBuf, err: std::io::Error },
+    /// Failed to remove a stale file (e.g., an outdated checksum file).
+    FileRemove { path: PathBuf, err: std::io::Error },
+    /// The given file does not match its checksum file, i.e., it's corrupt or incompletely written.
+    ChecksumMismatch { path: PathBuf, expected: String, actual: String },
+    /// The signature file of the given file is not a valid signature.
+    #[cfg(feature = "sign-ed25519")]
+    SignatureMalformed { path: PathBuf },
+    /// The given file is not signed by any of the trusted keys, i.e., it's been tampered with or signed by someone else.
+    #[cfg(feature = "sign-ed25519")]
+    SignatureUntrusted { path: PathBuf },
+    /// Failed to read from the given reader.
+    ReaderRead { err: std::io::Error },
+    /// Failed to find a format to use for the given file.
+    UnknownFormat { path: PathBuf },
+    /// Failed to detect the format of the given input.
+    UndetectedFormat,
 
     /// Failed to serialize the type to a string.
     SerializeString { what: &'static str, err: E },
+    /// Failed to serialize the type to a byte buffer.
+    SerializeBytes { what: &'static str, err: E },
     /// Failed to serialize the type to a writer.
     SerializeWriter { what: &'static str, err: E },
     /// Failed to serialize the type to a file.
     SerializeFile { what: &'static str, path: PathBuf, err: E },
 
     /// Failed to deserialize the type from a string.
-    DeserializeString { what: &'static str, err: E },
+    ///
+    /// `ctx` gives the path to the field that failed, the [`Location`] in the
+    /// input and the offending line of the input, as far as the backend
+    /// knows them.
+    DeserializeString { what: &'static str, ctx: Box<DeserializeContext>, err: E },
+    /// Failed to deserialize the type from a byte buffer.
+    ///
+    /// `ctx` gives the path to the field that failed, if the backend knows
+    /// it. As the input needn't be text, it has no [`Location`].
+    DeserializeBytes { what: &'static str, ctx: Box<DeserializeContext>, err: E },
     /// Failed to deserialize the type from a reader.
-    DeserializeReader { what: &'static str, err: E },
+    ///
+    /// `ctx` gives the path to the field that failed and the [`Location`] in
+    /// the input, as far as the backend knows them. As the input has been
+    /// consumed, its `snippet` is typically [`None`].
+    DeserializeReader { what: &'static str, ctx: Box<DeserializeContext>, err: E },
     /// Failed to deserialize the type from a file.
-    DeserializeFile { what: &'static str, path: PathBuf, err: E },
+    ///
+    /// `ctx` gives the path to the field that failed, the [`Location`] in the
+    /// file and the offending line of the file, as far as the backend knows
+    /// them.
+    DeserializeFile { what: &'static str, path: PathBuf, ctx: Box<DeserializeContext>, err: E },
 }
 impl<E: Display> Display for Error<E> {
     fn fmt(&self, f: &mut Formatter<'_>) -> FResult {
@@ -59,23 +148,51 @@ impl<E: Display> Display for Error<E> {
         match self {
             FileCreate { path, .. } => write!(f, "Failed to create output file '{}'", path.display()),
             FileOpen { path, .. } => write!(f, "Failed to open input file '{}'", path.display()),
+            FileRead { path, .. } => write!(f, "Failed to read from input file '{}'", path.display()),
+            FileWrite { path, .. } => write!(f, "Failed to write to output file '{}'", path.display()),
             #[cfg(feature = "async-tokio")]
             FileFlush { path, .. } => write!(f, "Failed to flush output file '{}'", path.display()),
+            FileCompress { path, .. } => write!(f, "Failed to compress output file '{}'", path.display()),
+            FileDecompress { path, .. } => write!(f, "Failed to decompress input file '{}'", path.display()),
+            FileSync { path, .. } => write!(f, "Failed to sync output file '{}' to disk", path.display()),
+            FileRename { from, to, .. } => write!(f, "Failed to move file '{}' to '{}'", from.display(), to.display()),
+            DirSync { path, .. } => write!(f, "Failed to sync directory '{}' to disk", path.display()),
+            FileBackup { path, backup, .. } => write!(f, "Failed to back up file '{}' to '{}'", path.display(), backup.display()),
+            FileLock { path, .. } => write!(f, "Failed to lock file '{}'", path.display()),
+            FileRemove { path, .. } => write!(f, "Failed to remove stale file '{}'", path.display()),
+            ChecksumMismatch { path, expected, actual } => {
+                write!(f, "Input file '{}' does not match its checksum (expected {expected}, got {actual})", path.display())
+            },
+            #[cfg(feature = "sign-ed25519")]
+            SignatureMalformed { path } => write!(f, "Signature file '{}' does not contain a valid Ed25519 signature", path.display()),
+            #[cfg(feature = "sign-ed25519")]
+            SignatureUntrusted { path } => write!(f, "Input file '{}' is not signed by any trusted key", path.display()),
+            ReaderRead { .. } => write!(f, "Failed to read from the given reader"),
+            UnknownFormat { path } => write!(f, "Failed to find a format for file '{}' (unknown or disabled extension)", path.display()),
+            UndetectedFormat => write!(f, "Failed to detect the format of the given input"),
 
             SerializeString { what, .. } => write!(f, "Failed to serialize {what} to a string"),
+            SerializeBytes { what, .. } => write!(f, "Failed to serialize {what} to a byte buffer"),
             SerializeWriter { what, .. } => {
                 write!(f, "Failed to serialize {what} to the given writer")
             },
             SerializeFile { what, path, .. } => write!(f, "Failed to serialize {what} to file '{}'", path.display()),
 
-            DeserializeString { what, .. } => {
-                write!(f, "Failed to deserialize {what} from the given string")
+            DeserializeString { what, ctx, .. } => {
+                write!(f, "Failed to deserialize {what} from the given string")?;
+                fmt_context(f, ctx)
+            },
+            DeserializeBytes { what, ctx, .. } => {
+                write!(f, "Failed to deserialize {what} from the given byte buffer")?;
+                fmt_context(f, ctx)
             },
-            DeserializeReader { what, .. } => {
-                write!(f, "Failed to deserialize {what} from the given reader")
+            DeserializeReader { what, ctx, .. } => {
+                write!(f, "Failed to deserialize {what} from the given reader")?;
+                fmt_context(f, ctx)
             },
-            DeserializeFile { what, path, .. } => {
-                write!(f, "Failed to deserialize {what} from file '{}'", path.display())
+            DeserializeFile { what, path, ctx, .. } => {
+                write!(f, "Failed to deserialize {what} from file '{}'", path.display())?;
+                fmt_context(f, ctx)
             },
         }
     }
@@ -86,14 +203,34 @@ impl<E: 'static + error::Error> error::Error for Error<E> {
         match self {
             FileCreate { err, .. } => Some(err),
             FileOpen { err, .. } => Some(err),
+            FileRead { err, .. } => Some(err),
+            FileWrite { err, .. } => Some(err),
             #[cfg(feature = "async-tokio")]
             FileFlush { err, .. } => Some(err),
+            FileCompress { err, .. } => Some(err),
+            FileDecompress { err, .. } => Some(err),
+            FileSync { err, .. } => Some(err),
+            FileRename { err, .. } => Some(err),
+            DirSync { err, .. } => Some(err),
+            FileBackup { err, .. } => Some(err),
+            FileLock { err, .. } => Some(err),
+            FileRemove { err, .. } => Some(err),
+            ChecksumMismatch { .. } => None,
+            #[cfg(feature = "sign-ed25519")]
+            SignatureMalformed { .. } => None,
+            #[cfg(feature = "sign-ed25519")]
+            SignatureUntrusted { .. } => None,
+            ReaderRead { err } => Some(err),
+            UnknownFormat { .. } => None,
+            UndetectedFormat => None,
 
             SerializeString { err, .. } => Some(err),
+            SerializeBytes { err, .. } => Some(err),
             SerializeWriter { err, .. } => Some(err),
             SerializeFile { err, .. } => Some(err),
 
             DeserializeString { err, .. } => Some(err),
+            DeserializeBytes { err, .. } => Some(err),
             DeserializeReader { err, .. } => Some(err),
             DeserializeFile { err, .. } => Some(err),
         }
@@ -104,45 +241,2759 @@ impl<E: 'static + error::Error> error::Error for Error<E> {
 
 
 
-/***** LIBRARY **** */
-/// Conveniently implements functions to serialize- or deserialize a struct using serde (or other serializers).
-///
-/// # Examples
-/// ```rust
-/// use serializable::dummy::Serializer;
-/// use serializable::Serializable;
-///
-/// #[derive(Debug, Default, Eq, PartialEq)]
-/// struct HelloWorld {
-///     hello: String,
-///     world: String,
-/// }
-/// impl Serializable<Serializer<HelloWorld>> for HelloWorld {}
-///
-/// // Note: the dummy serializer doesn't actually serialize/deserialize any content. Check the features for proper ones!
-/// assert_eq!(
-///     HelloWorld { hello: "Hello".into(), world: "World".into() }.to_string().unwrap(),
-///     "<dummy_text>"
-/// );
-/// assert_eq!(HelloWorld::from_str("<dummy_text>").unwrap(), HelloWorld::default());
-/// ```
-pub trait Serializable<T: Serializer<Target = Self>> {
-    // Serializer backend aliases
-    /// Serializes this object to a string.
+/***** HELPERS **** */
+/// Counts the temporary files created by this process, to give each a unique name.
+static TEMP_COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+/// The number of times we try to find an unused name for a temporary file before giving up.
+const TEMP_ATTEMPTS: usize = 64;
+
+/// The time we initially wait before trying to lock a file again.
+const LOCK_BACKOFF_MIN: Duration = Duration::from_millis(1);
+/// The maximum time we wait before trying to lock a file again.
+const LOCK_BACKOFF_MAX: Duration = Duration::from_millis(50);
+
+
+
+/// Returns the directory containing the given path.
+///
+/// # Arguments
+/// - `path`: The path to return the directory of.
+///
+/// # Returns
+/// The parent of `path`, or `.` if it has none (i.e., it's a bare file name).
+#[inline]
+fn parent_dir(path: &Path) -> &Path {
+    match path.parent() {
+        Some(dir) if !dir.as_os_str().is_empty() => dir,
+        _ => Path::new("."),
+    }
+}
+
+/// Returns a new path for a temporary file next to the given one.
+///
+/// The file is hidden (on Unix) and named after the given one, the current process and
+/// [`TEMP_COUNTER`], e.g., `.state.json.1234.0.tmp`.
+///
+/// # Arguments
+/// - `path`: The path of the file that the temporary file will replace.
+///
+/// # Returns
+/// A path in the same directory as `path`.
+#[inline]
+fn temp_path(path: &Path) -> PathBuf {
+    let mut name: OsString = OsString::from(".");
+    name.push(path.file_name().unwrap_or_default());
+    name.push(format!(".{}.{}.tmp", std::process::id(), TEMP_COUNTER.fetch_add(1, Ordering::Relaxed)));
+    parent_dir(path).join(name)
+}
+
+/// Creates a new temporary file next to the given one.
+///
+/// If the given file already exists, then its permissions are copied to the temporary file (if
+/// possible), so they're kept when it's replaced.
+///
+/// # Arguments
+/// - `path`: The path of the file that the temporary file will replace.
+///
+/// # Returns
+/// The path of the new temporary file, and a handle to it.
+///
+/// # Errors
+/// This function errors with an [`Error::FileCreate`] if we failed to create the file.
+fn create_temp<E>(path: &Path) -> Result<(PathBuf, File), Error<E>> {
+    let mut attempts: usize = 0;
+    loop {
+        let temp: PathBuf = temp_path(path);
+        match OpenOptions::new().write(true).create_new(true).open(&temp) {
+            Ok(handle) => {
+                // Best-effort, as not every filesystem supports it
+                if let Ok(meta) = std::fs::metadata(path) {
+                    let _ = handle.set_permissions(meta.permissions());
+                }
+                return Ok((temp, handle));
+            },
+            Err(err) if err.kind() == std::io::ErrorKind::AlreadyExists && attempts + 1 < TEMP_ATTEMPTS => attempts += 1,
+            Err(err) => return Err(Error::FileCreate { path: temp, err }),
+        }
+    }
+}
+
+/// Syncs the given directory to disk, which persists any renames in it.
+///
+/// This is a no-op on non-Unix platforms, where directories cannot be synced.
+///
+/// # Arguments
+/// - `dir`: The directory to sync.
+///
+/// # Errors
+/// This function errors with an [`Error::DirSync`] if we failed to open or sync the directory.
+#[cfg(unix)]
+#[inline]
+fn sync_dir<E>(dir: &Path) -> Result<(), Error<E>> {
+    match File::open(dir).and_then(|handle| handle.sync_all()) {
+        Ok(_) => Ok(()),
+        Err(err) => Err(Error::DirSync { path: dir.into(), err }),
+    }
+}
+#[cfg(not(unix))]
+#[inline]
+fn sync_dir<E>(_dir: &Path) -> Result<(), Error<E>> { Ok(()) }
+
+/// Serializes to the given file handle, compressing the output with the given codec.
+///
+/// # Arguments
+/// - `handle`: The handle to the file to write to.
+/// - `path`: The path of `handle`, for error reporting.
+/// - `compression`: The [`Compression`] to compress with, or [`None`] to not compress.
+/// - `write`: A closure that serializes to the writer it is given.
+///
+/// # Errors
+/// This function errors with an [`Error::FileCompress`] if we failed to compress the output, or
+/// with whatever `write` errors with. In the latter case, [`Error::SerializeWriter`]s are
+/// converted to [`Error::SerializeFile`]s.
+pub(crate) fn write_compressed<W: Write, E>(
+    handle: W,
+    path: &Path,
+    compression: Option<Compression>,
+    write: impl FnOnce(&mut Encoder<W>) -> Result<(), Error<E>>,
+) -> Result<(), Error<E>> {
+    let mut enc: Encoder<W> = match Encoder::new(handle, compression) {
+        Ok(enc) => enc,
+        Err(err) => return Err(Error::FileCompress { path: path.into(), err }),
+    };
+    match write(&mut enc) {
+        Ok(_) => {},
+        Err(Error::SerializeWriter { what, err }) => return Err(Error::SerializeFile { what, path: path.into(), err }),
+        Err(err) => return Err(err),
+    }
+    match enc.finish() {
+        Ok(_) => Ok(()),
+        Err(err) => Err(Error::FileCompress { path: path.into(), err }),
+    }
+}
+
+/// Writes the given file atomically by writing to a temporary file first.
+///
+/// See [`Serializable::to_path_atomic()`] for how this works.
+///
+/// # Arguments
+/// - `path`: The path of the file to write.
+/// - `backups`: The number of backups of the file to keep (see [`rotate_backups()`]). They are
+///   only rotated once the temporary file has been written, right before it replaces the file.
+/// - `write`: A closure that writes the contents of the file to the (temporary) file it is given.
+///
+/// # Errors
+/// This function errors with an [`Error::FileCreate`], [`Error::FileSync`],
+/// [`Error::FileRename`] or [`Error::DirSync`] if we failed to create, sync or move the
+/// temporary file, an [`Error::FileBackup`] if we failed to rotate the backups, or with whatever
+/// `write` errors with.
+fn write_atomic<E>(path: &Path, backups: usize, write: impl FnOnce(&mut File) -> Result<(), Error<E>>) -> Result<(), Error<E>> {
+    let (temp, mut handle): (PathBuf, File) = create_temp(path)?;
+
+    // Write to the temporary file and make sure it's on disk
+    let res: Result<(), Error<E>> = match write(&mut handle) {
+        Ok(_) => handle.sync_all().map_err(|err| Error::FileSync { path: temp.clone(), err }),
+        Err(err) => Err(err),
+    };
+    drop(handle);
+    if let Err(err) = res {
+        // Best-effort, as the original error is more important
+        let _ = std::fs::remove_file(&temp);
+        return Err(err);
+    }
+
+    // Then move it over the target (after backing it up) and make sure that's on disk too
+    if let Err(err) = rotate_backups(path, backups) {
+        let _ = std::fs::remove_file(&temp);
+        return Err(err);
+    }
+    if let Err(err) = std::fs::rename(&temp, path) {
+        let _ = std::fs::remove_file(&temp);
+        return Err(Error::FileRename { from: temp, to: path.into(), err });
+    }
+    sync_dir(parent_dir(path))
+}
+
+/// Reads the given file as a string, decompressing it if necessary.
+///
+/// Used to find the offending line of deserialization errors.
+///
+/// # Arguments
+/// - `path`: The path of the file to read.
+///
+/// # Returns
+/// The (decompressed) contents of the file, or [`None`] if we failed to read it (or it isn't
+/// valid UTF-8).
+#[inline]
+pub(crate) fn read_raw(path: &Path) -> Option<String> {
+    let mut raw: String = String::new();
+    Decoder::new(File::open(path).ok()?, Compression::from_path(path)).read_to_string(&mut raw).ok()?;
+    Some(raw)
+}
+
+/// Returns the path of the `n`th backup of the given file.
+///
+/// # Arguments
+/// - `path`: The path of the file to return the backup of.
+/// - `n`: The number of the backup, where `1` is the newest.
+///
+/// # Returns
+/// The given path with `.<n>` appended to it (e.g., `state.json.1`).
+#[inline]
+fn backup_path(path: &Path, n: usize) -> PathBuf {
+    let mut backup: OsString = path.as_os_str().to_owned();
+    backup.push(format!(".{n}"));
+    backup.into()
+}
+
+/// Rotates the backups of the given file, then copies the file itself to the newest backup.
+///
+/// That is, `path.N-1` is moved to `path.N` (replacing it), ..., `path.1` to `path.2`, and `path`
+/// is then copied to `path.1`. Missing backups are skipped. If `path` doesn't exist, nothing is
+/// rotated. The checksum files of the file and its backups are rotated along with them.
+///
+/// # Arguments
+/// - `path`: The path of the file to back up.
+/// - `backups`: The number of backups to keep (`N`). If `0`, this function does nothing.
+///
+/// # Errors
+/// This function errors with an [`Error::FileBackup`] if we failed to move any of the backups or
+/// failed to copy `path`.
+fn rotate_backups<E>(path: &Path, backups: usize) -> Result<(), Error<E>> {
+    if backups == 0 {
+        return Ok(());
+    }
+    match std::fs::symlink_metadata(path) {
+        Ok(_) => {},
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(()),
+        Err(err) => return Err(Error::FileBackup { path: path.into(), backup: backup_path(path, 1), err }),
+    }
+
+    // Shift the existing backups, oldest first so we don't overwrite any we still need
+    for n in (1..backups).rev() {
+        let (from, to): (PathBuf, PathBuf) = (backup_path(path, n), backup_path(path, n + 1));
+        match std::fs::rename(&from, &to) {
+            Ok(_) => rotate_checksums(&from, &to, false)?,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => {},
+            Err(err) => return Err(Error::FileBackup { path: from, backup: to, err }),
+        }
+    }
+
+    // Then copy (not move) the file itself, so it's never missing
+    let backup: PathBuf = backup_path(path, 1);
+    match std::fs::copy(path, &backup) {
+        Ok(_) => rotate_checksums(path, &backup, true),
+        Err(err) => Err(Error::FileBackup { path: path.into(), backup, err }),
+    }
+}
+
+/// Moves (or copies) the checksum files of one file to those of another.
+///
+/// Checksum files that `from` doesn't have are removed for `to`, so that it never ends up with
+/// a stale one.
+///
+/// # Arguments
+/// - `from`: The path of the file whose checksum files to move.
+/// - `to`: The path of the file to move them to.
+/// - `copy`: Whether to copy the checksum files instead of moving them.
+///
+/// # Errors
+/// This function errors with an [`Error::FileBackup`] if we failed to move, copy or remove any of
+/// the checksum files.
+fn rotate_checksums<E>(from: &Path, to: &Path, copy: bool) -> Result<(), Error<E>> {
+    for checksum in Checksum::ALL {
+        let (from, to): (PathBuf, PathBuf) = (checksum.path(from), checksum.path(to));
+        let res: std::io::Result<()> = if copy { std::fs::copy(&from, &to).map(|_| ()) } else { std::fs::rename(&from, &to) };
+        match res.or_else(|err| if err.kind() == std::io::ErrorKind::NotFound { std::fs::remove_file(&to) } else { Err(err) }) {
+            Ok(_) => {},
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => {},
+            Err(err) => return Err(Error::FileBackup { path: from, backup: to, err }),
+        }
+    }
+    Ok(())
+}
+
+/// Reads the checksum file of the given file, if it has one.
+///
+/// If the file has checksum files for multiple algorithms, then the first one in
+/// [`Checksum::ALL`] is used.
+///
+/// # Arguments
+/// - `path`: The path of the file whose checksum file to read.
+///
+/// # Returns
+/// The [`Checksum`] used and the checksum itself (as lowercase hexadecimal), or [`None`] if the
+/// file has no checksum file.
+///
+/// # Errors
+/// This function errors with an [`Error::FileRead`] if a checksum file exists but we failed to
+/// read it.
+fn read_checksum<E>(path: &Path) -> Result<Option<(Checksum, String)>, Error<E>> {
+    for checksum in Checksum::ALL {
+        let sum_path: PathBuf = checksum.path(path);
+        match std::fs::read_to_string(&sum_path) {
+            Ok(raw) => return Ok(Some((*checksum, raw.split_whitespace().next().unwrap_or("").to_ascii_lowercase()))),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => continue,
+            Err(err) => return Err(Error::FileRead { path: sum_path, err }),
+        }
+    }
+    Ok(None)
+}
+
+/// Checks the given contents of a file against the given checksum.
+///
+/// # Arguments
+/// - `path`: The path of the file, for error reporting.
+/// - `checksum`: The [`Checksum`] to compute.
+/// - `expected`: The checksum the contents should have, in lowercase hexadecimal.
+/// - `raw`: The contents of the file (as stored on disk).
+///
+/// # Errors
+/// This function errors with an [`Error::ChecksumMismatch`] if the contents don't have the
+/// expected checksum.
+#[inline]
+fn check_checksum<E>(path: &Path, checksum: Checksum, expected: String, raw: &[u8]) -> Result<(), Error<E>> {
+    let actual: String = checksum.compute(raw);
+    if actual != expected {
+        return Err(Error::ChecksumMismatch { path: path.into(), expected, actual });
+    }
+    Ok(())
+}
+
+/// Reads the given file handle to memory and verifies it, if the file has a checksum file.
+///
+/// # Arguments
+/// - `handle`: The handle to the file to read.
+/// - `path`: The path of `handle`.
+///
+/// # Returns
+/// The contents of the file (as stored on disk), or [`None`] if it has no checksum file (in which
+/// case nothing is read from `handle`).
+///
+/// # Errors
+/// This function errors with an [`Error::FileRead`] if we failed to read the file or its checksum
+/// file, or an [`Error::ChecksumMismatch`] if they don't match.
+pub(crate) fn read_verified<E>(mut handle: impl Read, path: &Path) -> Result<Option<Vec<u8>>, Error<E>> {
+    let Some((checksum, expected)) = read_checksum(path)? else { return Ok(None) };
+    let mut raw: Vec<u8> = Vec::new();
+    if let Err(err) = handle.read_to_end(&mut raw) {
+        return Err(Error::FileRead { path: path.into(), err });
+    }
+    check_checksum(path, checksum, expected, &raw)?;
+    Ok(Some(raw))
+}
+
+/// Reads the given file handle through a [`Decoder`] for its path's extension.
+///
+/// # Arguments
+/// - `handle`: The handle to the (possibly compressed) file to read.
+/// - `path`: The path of `handle`.
+/// - `read`: A closure that reads the decompressed contents from the given [`Decoder`].
+///
+/// # Returns
+/// Whatever `read` returned, unless the codec failed to decompress the file.
+///
+/// # Errors
+/// This function errors with an [`Error::FileDecompress`] if `read` failed because the codec failed
+/// to decompress the file.
+pub(crate) fn read_decoded<R: Read, V, X, E>(handle: R, path: &Path, read: impl FnOnce(&mut Decoder<R>) -> Result<V, X>) -> Result<Result<V, X>, Error<E>> {
+    let mut dec: Decoder<R> = Decoder::new(handle, Compression::from_path(path));
+    let res: Result<V, X> = read(&mut dec);
+    match dec.take_err() {
+        Some(err) if res.is_err() => Err(Error::FileDecompress { path: path.into(), err }),
+        _ => Ok(res),
+    }
+}
+
+/// Writes the checksum file of the given file, removing its checksum files of any other
+/// algorithms.
+///
+/// # Arguments
+/// - `path`: The path of the file whose checksum file to write.
+/// - `sum`: The [`Checksum`] used and the checksum itself (as lowercase hexadecimal), or [`None`]
+///   to only remove existing checksum files.
+/// - `atomic`: Whether to write the checksum file atomically (see [`write_atomic()`]).
+///
+/// # Errors
+/// This function errors with an [`Error::FileWrite`] if we failed to write the checksum file, or
+/// an [`Error::FileRemove`] if we failed to remove any of the others. If written atomically, it
+/// may also error like [`write_atomic()`].
+pub(crate) fn write_checksum<E>(path: &Path, sum: Option<(Checksum, &str)>, atomic: bool) -> Result<(), Error<E>> {
+    for checksum in Checksum::ALL {
+        let sum_path: PathBuf = checksum.path(path);
+        match sum {
+            Some((used, sum)) if used == *checksum => {
+                let raw: String = format!("{sum}\n");
+                if atomic {
+                    write_atomic(&sum_path, 0, |handle| handle.write_all(raw.as_bytes()).map_err(|err| Error::FileWrite { path: sum_path.clone(), err }))?;
+                } else if let Err(err) = std::fs::write(&sum_path, raw) {
+                    return Err(Error::FileWrite { path: sum_path, err });
+                }
+            },
+            _ => match std::fs::remove_file(&sum_path) {
+                Ok(_) => {},
+                Err(err) if err.kind() == std::io::ErrorKind::NotFound => {},
+                Err(err) => return Err(Error::FileRemove { path: sum_path, err }),
+            },
+        }
+    }
+    Ok(())
+}
+
+/// Reads the given file handle to memory, verifying it against its checksum file (if it has one)
+/// and decompressing it if necessary.
+///
+/// This is the synchronous counterpart of `read_decompressed_async()`.
+///
+/// # Arguments
+/// - `handle`: The handle to the file to read.
+/// - `path`: The path of `handle`.
+///
+/// # Returns
+/// The (decompressed) contents of the file.
+///
+/// # Errors
+/// This function errors with an [`Error::FileRead`] if we failed to read the file or its checksum
+/// file, an [`Error::ChecksumMismatch`] if they don't match, or an [`Error::FileDecompress`] if we
+/// failed to decompress it.
+#[cfg(feature = "sign-ed25519")]
+fn read_decompressed<E>(mut handle: impl Read, path: &Path) -> Result<Vec<u8>, Error<E>> {
+    let raw: Vec<u8> = match read_verified(&mut handle, path)? {
+        Some(raw) => raw,
+        None => {
+            let mut raw: Vec<u8> = Vec::new();
+            if let Err(err) = handle.read_to_end(&mut raw) {
+                return Err(Error::FileRead { path: path.into(), err });
+            }
+            raw
+        },
+    };
+    match crate::compression::decompress(raw, Compression::from_path(path)) {
+        Ok(raw) => Ok(raw),
+        Err(err) => Err(Error::FileDecompress { path: path.into(), err }),
+    }
+}
+
+/// Verifies the given contents of a file against its signature.
+///
+/// # Arguments
+/// - `path`: The path of the file, for error reporting.
+/// - `sig`: The contents of the file's signature file.
+/// - `trusted`: The keys of the signers to trust.
+/// - `raw`: The (decompressed) contents of the file.
+///
+/// # Errors
+/// This function errors with an [`Error::SignatureMalformed`] if `sig` isn't a signature, or an
+/// [`Error::SignatureUntrusted`] if it isn't a signature of `raw` by any of the `trusted` keys.
+#[cfg(feature = "sign-ed25519")]
+fn check_signature<E>(path: &Path, sig: &str, trusted: &[crate::signature::VerifyingKey], raw: &[u8]) -> Result<(), Error<E>> {
+    let Some(sig) = crate::signature::parse(sig) else { return Err(Error::SignatureMalformed { path: crate::signature::path(path) }) };
+    if !crate::signature::verify(trusted, raw, &sig) {
+        return Err(Error::SignatureUntrusted { path: path.into() });
+    }
+    Ok(())
+}
+
+/// Deserializes a value from the verified contents of a file.
+///
+/// # Arguments
+/// - `raw`: The (decompressed) contents of the file.
+/// - `path`: The path of the file, for error reporting.
+///
+/// # Returns
+/// The deserialized value.
+///
+/// # Errors
+/// This function errors like [`Serializable::from_reader()`], except that
+/// [`Error::DeserializeReader`]s are converted to [`Error::DeserializeFile`]s.
+#[cfg(feature = "sign-ed25519")]
+fn from_verified<V, T>(raw: &[u8], path: &Path) -> Result<V, Error<T::Error>>
+where
+    V: Serializable<T>,
+    T: Serializer<Target = V>,
+{
+    match V::from_reader(raw) {
+        Ok(res) => Ok(res),
+        Err(Error::DeserializeReader { what, ctx, err }) => {
+            // Use the input to find the offending line (and the location, for backends that need the input for that)
+            let raw: Option<&str> = std::str::from_utf8(raw).ok();
+            let loc: Option<Location> = T::location(&err, raw).or(ctx.loc);
+            Err(Error::DeserializeFile { what, path: path.into(), ctx: context(ctx.field_path, loc, raw), err })
+        },
+        Err(err) => Err(err),
+    }
+}
+
+/// Attempts to lock the given file without blocking.
+///
+/// # Arguments
+/// - `handle`: The [`File`] to lock.
+/// - `shared`: Whether to take a shared lock (for reading) or an exclusive one (for writing).
+///
+/// # Returns
+/// True if we got the lock, or false if someone else is holding a conflicting one.
+///
+/// # Errors
+/// This function errors if the OS failed to lock the file for any other reason.
+#[inline]
+fn try_lock_file(handle: &File, shared: bool) -> std::io::Result<bool> {
+    match if shared { handle.try_lock_shared() } else { handle.try_lock() } {
+        Ok(_) => Ok(true),
+        Err(std::fs::TryLockError::WouldBlock) => Ok(false),
+        Err(std::fs::TryLockError::Error(err)) => Err(err),
+    }
+}
+
+/// Returns the error for when we timed out waiting for a lock.
+///
+/// # Arguments
+/// - `path`: The path of the file we tried to lock.
+/// - `timeout`: The time we waited.
+///
+/// # Returns
+/// An [`Error::FileLock`] with an [`std::io::ErrorKind::TimedOut`]-error.
+#[inline]
+fn lock_timeout<E>(path: &Path, timeout: Duration) -> Error<E> {
+    Error::FileLock { path: path.into(), err: std::io::Error::new(std::io::ErrorKind::TimedOut, format!("timed out after {timeout:?}")) }
+}
+
+/// Locks the given file, waiting for others to release it if necessary.
+///
+/// The lock is advisory, and is released when `handle` is closed.
+///
+/// # Arguments
+/// - `handle`: The [`File`] to lock.
+/// - `path`: The path of `handle`, for error reporting.
+/// - `shared`: Whether to take a shared lock (for reading) or an exclusive one (for writing).
+/// - `timeout`: The maximum time to wait for the lock, or [`None`] to wait indefinitely.
+///
+/// # Errors
+/// This function errors with an [`Error::FileLock`] if we failed to lock the file or timed out.
+fn lock_file<E>(handle: &File, path: &Path, shared: bool, timeout: Option<Duration>) -> Result<(), Error<E>> {
+    let Some(timeout) = timeout else {
+        return if shared { handle.lock_shared() } else { handle.lock() }.map_err(|err| Error::FileLock { path: path.into(), err });
+    };
+
+    // Poll until we get it or run out of time
+    let start: Instant = Instant::now();
+    let mut backoff: Duration = LOCK_BACKOFF_MIN;
+    loop {
+        match try_lock_file(handle, shared) {
+            Ok(true) => return Ok(()),
+            Ok(false) => {},
+            Err(err) => return Err(Error::FileLock { path: path.into(), err }),
+        }
+        let elapsed: Duration = start.elapsed();
+        if elapsed >= timeout {
+            return Err(lock_timeout(path, timeout));
+        }
+        std::thread::sleep(backoff.min(timeout - elapsed));
+        backoff = (backoff * 2).min(LOCK_BACKOFF_MAX);
+    }
+}
+
+/// Creates a new temporary file next to the given one asynchronously.
+///
+/// This is the `async` counterpart of [`create_temp()`].
+///
+/// # Arguments
+/// - `path`: The path of the file that the temporary file will replace.
+///
+/// # Returns
+/// The path of the new temporary file, and a handle to it.
+///
+/// # Errors
+/// This function errors with an [`Error::FileCreate`] if we failed to create the file.
+#[cfg(feature = "async-tokio")]
+async fn create_temp_async<E>(path: &Path) -> Result<(PathBuf, tokio::fs::File), Error<E>> {
+    let mut attempts: usize = 0;
+    loop {
+        let temp: PathBuf = temp_path(path);
+        match tokio::fs::OpenOptions::new().write(true).create_new(true).open(&temp).await {
+            Ok(handle) => {
+                // Best-effort, as not every filesystem supports it
+                if let Ok(meta) = tokio::fs::metadata(path).await {
+                    let _ = handle.set_permissions(meta.permissions()).await;
+                }
+                return Ok((temp, handle));
+            },
+            Err(err) if err.kind() == std::io::ErrorKind::AlreadyExists && attempts + 1 < TEMP_ATTEMPTS => attempts += 1,
+            Err(err) => return Err(Error::FileCreate { path: temp, err }),
+        }
+    }
+}
+
+/// Syncs the given directory to disk asynchronously, which persists any renames in it.
+///
+/// This is the `async` counterpart of [`sync_dir()`].
+///
+/// # Arguments
+/// - `dir`: The directory to sync.
+///
+/// # Errors
+/// This function errors with an [`Error::DirSync`] if we failed to open or sync the directory.
+#[cfg(all(feature = "async-tokio", unix))]
+#[inline]
+async fn sync_dir_async<E>(dir: &Path) -> Result<(), Error<E>> {
+    let res: std::io::Result<()> = match tokio::fs::File::open(dir).await {
+        Ok(handle) => handle.sync_all().await,
+        Err(err) => Err(err),
+    };
+    match res {
+        Ok(_) => Ok(()),
+        Err(err) => Err(Error::DirSync { path: dir.into(), err }),
+    }
+}
+#[cfg(all(feature = "async-tokio", not(unix)))]
+#[inline]
+async fn sync_dir_async<E>(_dir: &Path) -> Result<(), Error<E>> { Ok(()) }
+
+/// Locks the given file asynchronously, waiting for others to release it if necessary.
+///
+/// This is the `async` counterpart of [`lock_file()`]. Instead of blocking, it polls the lock
+/// until it gets it (or times out).
+///
+/// # Arguments
+/// - `handle`: The [`tokio::fs::File`] to lock.
+/// - `path`: The path of `handle`, for error reporting.
+/// - `shared`: Whether to take a shared lock (for reading) or an exclusive one (for writing).
+/// - `timeout`: The maximum time to wait for the lock, or [`None`] to wait indefinitely.
+///
+/// # Returns
+/// The given `handle`, now locked.
+///
+/// # Errors
+/// This function errors with an [`Error::FileLock`] if we failed to lock the file or timed out.
+#[cfg(feature = "async-tokio")]
+async fn lock_file_async<E>(handle: tokio::fs::File, path: &Path, shared: bool, timeout: Option<Duration>) -> Result<tokio::fs::File, Error<E>> {
+    let handle: File = handle.into_std().await;
+    let start: Instant = Instant::now();
+    let mut backoff: Duration = LOCK_BACKOFF_MIN;
+    loop {
+        match try_lock_file(&handle, shared) {
+            Ok(true) => return Ok(tokio::fs::File::from_std(handle)),
+            Ok(false) => {},
+            Err(err) => return Err(Error::FileLock { path: path.into(), err }),
+        }
+        let mut wait: Duration = backoff;
+        if let Some(timeout) = timeout {
+            let elapsed: Duration = start.elapsed();
+            if elapsed >= timeout {
+                return Err(lock_timeout(path, timeout));
+            }
+            wait = wait.min(timeout - elapsed);
+        }
+        tokio::time::sleep(wait).await;
+        backoff = (backoff * 2).min(LOCK_BACKOFF_MAX);
+    }
+}
+
+/// Serializes the given value to the given file handle asynchronously, compressing the output
+/// with the given codec.
+///
+/// If the output is compressed (or its checksum computed), then it is serialized to (and
+/// compressed in) memory first.
+///
+/// # Arguments
+/// - `value`: The value to serialize.
+/// - `handle`: The handle to the file to write to.
+/// - `path`: The path of `handle`, for error reporting.
+/// - `pretty`: Whether to use the pretty formatter or not.
+/// - `compression`: The [`Compression`] to compress with, or [`None`] to not compress.
+/// - `hasher`: A [`Hasher`] to update with the output as written, if any.
+///
+/// # Errors
+/// This function errors with an [`Error::SerializeFile`] if the backend failed to serialize, an
+/// [`Error::FileCompress`] if we failed to compress the output, or an [`Error::FileWrite`] or
+/// [`Error::FileFlush`] if we failed to write it.
+#[cfg(feature = "async-tokio")]
+async fn write_compressed_async<V, T>(
+    value: &V,
+    mut handle: impl Send + std::marker::Unpin + tokio::io::AsyncWrite,
+    path: &Path,
+    pretty: bool,
+    compression: Option<Compression>,
+    hasher: Option<&mut Hasher>,
+) -> Result<(), Error<T::Error>>
+where
+    V: ?Sized + Send + Sync + SerializableAsync<T>,
+    T: Send + Sync + Serializer<Target = V> + crate::serializer::SerializerAsync,
+{
+    use tokio::io::AsyncWriteExt as _;
+
+    // Serialize directly if we don't compress (or hash), or else to memory first
+    if compression.is_none() && hasher.is_none() {
+        return match value.to_writer_pretty_opt_async(handle, pretty).await {
+            Ok(_) => Ok(()),
+            Err(Error::SerializeWriter { what, err }) => Err(Error::SerializeFile { what, path: path.into(), err }),
+            Err(err) => Err(err),
+        };
+    }
+    let mut raw: Vec<u8> = Vec::new();
+    match value.to_writer_pretty_opt_async(&mut raw, pretty).await {
+        Ok(_) => {},
+        Err(Error::SerializeWriter { what, err }) => return Err(Error::SerializeFile { what, path: path.into(), err }),
+        Err(err) => return Err(err),
+    }
+
+    // Then compress and write that
+    let raw: Vec<u8> = match crate::compression::compress(raw, compression) {
+        Ok(raw) => raw,
+        Err(err) => return Err(Error::FileCompress { path: path.into(), err }),
+    };
+    if let Some(hasher) = hasher {
+        hasher.update(&raw);
+    }
+    if let Err(err) = handle.write_all(&raw).await {
+        return Err(Error::FileWrite { path: path.into(), err });
+    }
+    match handle.flush().await {
+        Ok(_) => Ok(()),
+        Err(err) => Err(Error::FileFlush { path: path.into(), err }),
+    }
+}
+
+/// Serializes the given value to the given file asynchronously and atomically, by writing to a
+/// temporary file first.
+///
+/// This is the `async` counterpart of [`write_atomic()`].
+///
+/// # Arguments
+/// - `value`: The value to serialize.
+/// - `path`: The path of the file to write.
+/// - `backups`: The number of backups of the file to keep (see [`rotate_backups()`]). They are
+///   only rotated once the temporary file has been written, right before it replaces the file.
+/// - `pretty`: Whether to use the pretty formatter or not.
+/// - `compression`: The [`Compression`] to compress with, or [`None`] to not compress.
+/// - `hasher`: A [`Hasher`] to update with the output as written, if any.
+///
+/// # Errors
+/// This function errors with an [`Error::FileCreate`], [`Error::FileFlush`], [`Error::FileSync`],
+/// [`Error::FileRename`] or [`Error::DirSync`] if we failed to create, flush, sync or move the
+/// temporary file, an [`Error::FileBackup`] if we failed to rotate the backups, or like
+/// [`write_compressed_async()`] if we failed to write it.
+#[cfg(feature = "async-tokio")]
+async fn write_atomic_async<V, T>(
+    value: &V,
+    path: &Path,
+    backups: usize,
+    pretty: bool,
+    compression: Option<Compression>,
+    hasher: Option<&mut Hasher>,
+) -> Result<(), Error<T::Error>>
+where
+    V: ?Sized + Send + Sync + SerializableAsync<T>,
+    T: Send + Sync + Serializer<Target = V> + crate::serializer::SerializerAsync,
+    T::Error: Send,
+{
+    use tokio::io::AsyncWriteExt as _;
+
+    let (temp, mut handle): (PathBuf, tokio::fs::File) = create_temp_async(path).await?;
+
+    // Write to the temporary file and make sure it's on disk
+    let mut res: Result<(), Error<T::Error>> = write_compressed_async(value, &mut handle, path, pretty, compression, hasher).await;
+    if res.is_ok() {
+        res = handle.flush().await.map_err(|err| Error::FileFlush { path: temp.clone(), err });
+    }
+    if res.is_ok() {
+        res = handle.sync_all().await.map_err(|err| Error::FileSync { path: temp.clone(), err });
+    }
+    drop(handle);
+    if let Err(err) = res {
+        // Best-effort, as the original error is more important
+        let _ = tokio::fs::remove_file(&temp).await;
+        return Err(err);
+    }
+
+    // Then move it over the target (after backing it up) and make sure that's on disk too
+    if let Err(err) = rotate_backups_async::<T::Error>(path, backups).await {
+        let _ = tokio::fs::remove_file(&temp).await;
+        return Err(err);
+    }
+    if let Err(err) = tokio::fs::rename(&temp, path).await {
+        let _ = tokio::fs::remove_file(&temp).await;
+        return Err(Error::FileRename { from: temp, to: path.into(), err });
+    }
+    sync_dir_async(parent_dir(path)).await
+}
+
+/// Reads the given file handle to memory asynchronously, verifying it against its checksum file
+/// (if it has one) and decompressing it if necessary.
+///
+/// # Arguments
+/// - `handle`: The handle to the file to read.
+/// - `path`: The path of `handle`.
+///
+/// # Returns
+/// The (decompressed) contents of the file.
+///
+/// # Errors
+/// This function errors with an [`Error::FileRead`] if we failed to read the file or its checksum
+/// file, an [`Error::ChecksumMismatch`] if they don't match, or an [`Error::FileDecompress`] if we
+/// failed to decompress it.
+#[cfg(feature = "async-tokio")]
+async fn read_decompressed_async<E>(mut handle: impl std::marker::Unpin + tokio::io::AsyncRead, path: &Path) -> Result<Vec<u8>, Error<E>> {
+    use tokio::io::AsyncReadExt as _;
+
+    let mut raw: Vec<u8> = Vec::new();
+    if let Err(err) = handle.read_to_end(&mut raw).await {
+        return Err(Error::FileRead { path: path.into(), err });
+    }
+    if let Some((checksum, expected)) = read_checksum_async(path).await? {
+        check_checksum(path, checksum, expected, &raw)?;
+    }
+    match crate::compression::decompress(raw, Compression::from_path(path)) {
+        Ok(raw) => Ok(raw),
+        Err(err) => Err(Error::FileDecompress { path: path.into(), err }),
+    }
+}
+
+/// Rotates the backups of the given file asynchronously.
+///
+/// This is the `async` counterpart of [`rotate_backups()`].
+///
+/// # Arguments
+/// - `path`: The path of the file to back up.
+/// - `backups`: The number of backups to keep. If `0`, this function does nothing.
+///
+/// # Errors
+/// This function errors with an [`Error::FileBackup`] if we failed to move any of the backups or
+/// failed to copy `path`.
+#[cfg(feature = "async-tokio")]
+async fn rotate_backups_async<E>(path: &Path, backups: usize) -> Result<(), Error<E>> {
+    if backups == 0 {
+        return Ok(());
+    }
+    match tokio::fs::symlink_metadata(path).await {
+        Ok(_) => {},
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(()),
+        Err(err) => return Err(Error::FileBackup { path: path.into(), backup: backup_path(path, 1), err }),
+    }
+
+    // Shift the existing backups, oldest first so we don't overwrite any we still need
+    for n in (1..backups).rev() {
+        let (from, to): (PathBuf, PathBuf) = (backup_path(path, n), backup_path(path, n + 1));
+        match tokio::fs::rename(&from, &to).await {
+            Ok(_) => rotate_checksums_async(&from, &to, false).await?,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => {},
+            Err(err) => return Err(Error::FileBackup { path: from, backup: to, err }),
+        }
+    }
+
+    // Then copy (not move) the file itself, so it's never missing
+    let backup: PathBuf = backup_path(path, 1);
+    match tokio::fs::copy(path, &backup).await {
+        Ok(_) => rotate_checksums_async(path, &backup, true).await,
+        Err(err) => Err(Error::FileBackup { path: path.into(), backup, err }),
+    }
+}
+
+/// Moves (or copies) the checksum files of one file to those of another asynchronously.
+///
+/// This is the `async` counterpart of [`rotate_checksums()`].
+///
+/// # Arguments
+/// - `from`: The path of the file whose checksum files to move.
+/// - `to`: The path of the file to move them to.
+/// - `copy`: Whether to copy the checksum files instead of moving them.
+///
+/// # Errors
+/// This function errors with an [`Error::FileBackup`] if we failed to move, copy or remove any of
+/// the checksum files.
+#[cfg(feature = "async-tokio")]
+async fn rotate_checksums_async<E>(from: &Path, to: &Path, copy: bool) -> Result<(), Error<E>> {
+    for checksum in Checksum::ALL {
+        let (from, to): (PathBuf, PathBuf) = (checksum.path(from), checksum.path(to));
+        let mut res: std::io::Result<()> = if copy { tokio::fs::copy(&from, &to).await.map(|_| ()) } else { tokio::fs::rename(&from, &to).await };
+        if matches!(&res, Err(err) if err.kind() == std::io::ErrorKind::NotFound) {
+            res = tokio::fs::remove_file(&to).await;
+        }
+        match res {
+            Ok(_) => {},
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => {},
+            Err(err) => return Err(Error::FileBackup { path: from, backup: to, err }),
+        }
+    }
+    Ok(())
+}
+
+/// Reads the checksum file of the given file asynchronously, if it has one.
+///
+/// This is the `async` counterpart of [`read_checksum()`].
+///
+/// # Arguments
+/// - `path`: The path of the file whose checksum file to read.
+///
+/// # Returns
+/// The [`Checksum`] used and the checksum itself (as lowercase hexadecimal), or [`None`] if the
+/// file has no checksum file.
+///
+/// # Errors
+/// This function errors with an [`Error::FileRead`] if a checksum file exists but we failed to
+/// read it.
+#[cfg(feature = "async-tokio")]
+async fn read_checksum_async<E>(path: &Path) -> Result<Option<(Checksum, String)>, Error<E>> {
+    for checksum in Checksum::ALL {
+        let sum_path: PathBuf = checksum.path(path);
+        match tokio::fs::read_to_string(&sum_path).await {
+            Ok(raw) => return Ok(Some((*checksum, raw.split_whitespace().next().unwrap_or("").to_ascii_lowercase()))),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => continue,
+            Err(err) => return Err(Error::FileRead { path: sum_path, err }),
+        }
+    }
+    Ok(None)
+}
+
+/// Writes the given bytes to a file asynchronously and atomically, i.e., by writing them to a
+/// temporary file first.
+///
+/// This is the `async` counterpart of [`write_atomic()`] for small files (e.g., checksum files)
+/// that are already in memory.
+///
+/// # Arguments
+/// - `path`: The path of the file to write.
+/// - `raw`: The contents to write to it.
+///
+/// # Errors
+/// This function errors with an [`Error::FileCreate`], [`Error::FileWrite`], [`Error::FileSync`],
+/// [`Error::FileRename`] or [`Error::DirSync`] if we failed to create, write, sync or move the
+/// temporary file.
+#[cfg(feature = "async-tokio")]
+async fn write_bytes_atomic_async<E>(path: &Path, raw: &[u8]) -> Result<(), Error<E>> {
+    use tokio::io::AsyncWriteExt as _;
+
+    // NOTE: We don't keep an `Error<E>` around while awaiting, as that would require `E: Send`
+    let (temp, mut handle): (PathBuf, tokio::fs::File) = create_temp_async(path).await?;
+    if let Err(err) = handle.write_all(raw).await {
+        drop(handle);
+        let _ = tokio::fs::remove_file(&temp).await;
+        return Err(Error::FileWrite { path: temp, err });
+    }
+    if let Err(err) = handle.sync_all().await {
+        drop(handle);
+        let _ = tokio::fs::remove_file(&temp).await;
+        return Err(Error::FileSync { path: temp, err });
+    }
+    drop(handle);
+    if let Err(err) = tokio::fs::rename(&temp, path).await {
+        let _ = tokio::fs::remove_file(&temp).await;
+        return Err(Error::FileRename { from: temp, to: path.into(), err });
+    }
+    sync_dir_async(parent_dir(path)).await
+}
+
+/// Writes the checksum file of the given file asynchronously, removing its checksum files of any
+/// other algorithms.
+///
+/// This is the `async` counterpart of [`write_checksum()`].
+///
+/// # Arguments
+/// - `path`: The path of the file whose checksum file to write.
+/// - `sum`: The [`Checksum`] used and the checksum itself (as lowercase hexadecimal), or [`None`]
+///   to only remove existing checksum files.
+/// - `atomic`: Whether to write the checksum file atomically (see [`write_atomic()`]).
+///
+/// # Errors
+/// This function errors with an [`Error::FileCreate`], [`Error::FileWrite`], [`Error::FileSync`],
+/// [`Error::FileRename`] or [`Error::DirSync`] if we failed to write the checksum file, or an
+/// [`Error::FileRemove`] if we failed to remove any of the others.
+#[cfg(feature = "async-tokio")]
+async fn write_checksum_async<E>(path: &Path, sum: Option<(Checksum, &str)>, atomic: bool) -> Result<(), Error<E>> {
+    for checksum in Checksum::ALL {
+        let sum_path: PathBuf = checksum.path(path);
+        match sum {
+            Some((used, sum)) if used == *checksum => {
+                let raw: String = format!("{sum}\n");
+                if !atomic {
+                    if let Err(err) = tokio::fs::write(&sum_path, raw).await {
+                        return Err(Error::FileWrite { path: sum_path, err });
+                    }
+                    continue;
+                }
+
+                write_bytes_atomic_async(&sum_path, raw.as_bytes()).await?;
+            },
+            _ => match tokio::fs::remove_file(&sum_path).await {
+                Ok(_) => {},
+                Err(err) if err.kind() == std::io::ErrorKind::NotFound => {},
+                Err(err) => return Err(Error::FileRemove { path: sum_path, err }),
+            },
+        }
+    }
+    Ok(())
+}
+
+/// Builds the [`DeserializeContext`] of a deserialization error.
+///
+/// # Arguments
+/// - `field_path`: The path to the field that failed, or empty if unknown.
+/// - `loc`: The [`Location`] of the error, if known.
+/// - `raw`: The input that was being deserialized, if available. Used to find the offending line.
+///
+/// # Returns
+/// A boxed [`DeserializeContext`], with the line of `raw` at `loc` as snippet (if both are known).
+#[inline]
+pub(crate) fn context(field_path: Vec<PathSegment>, loc: Option<Location>, raw: Option<&str>) -> Box<DeserializeContext> {
+    let snippet: Option<String> = loc.and_then(|loc| loc.snippet(raw?).map(String::from));
+    Box::new(DeserializeContext { field_path, loc, snippet })
+}
+
+/// Writes the field path and location of a deserialization error to the given formatter.
+///
+/// If the formatter is in alternate mode (`{:#}`) and the offending line is known, then that line
+/// is written too, with a caret underneath pointing at the error's column.
+///
+/// # Arguments
+/// - `f`: The [`Formatter`] to write to.
+/// - `ctx`: The [`DeserializeContext`] of the error. Nothing is written for the parts of it that
+///   are unknown.
+///
+/// # Errors
+/// This function errors if it failed to write to the given formatter.
+fn fmt_context(f: &mut Formatter<'_>, ctx: &DeserializeContext) -> FResult {
+    let DeserializeContext { field_path, loc, snippet } = ctx;
+    if !field_path.is_empty() {
+        write!(f, " in field '{}'", fmt_field_path(field_path))?;
+    }
+    let Some(loc) = loc else {
+        return Ok(());
+    };
+    write!(f, " ({loc})")?;
+    if let (true, Some(snippet)) = (f.alternate(), snippet) {
+        // Indent the caret with the characters before the column (keeping tabs, so it lines up)
+        let indent: String = snippet.char_indices().take_while(|(i, _)| *i + 1 < loc.column).map(|(_, c)| if c == '\t' { '\t' } else { ' ' }).collect();
+        let gutter: String = loc.line.to_string();
+        write!(f, "\n  {gutter} | {snippet}\n  {:width$} | {indent}^", "", width = gutter.len())?;
+    }
+    Ok(())
+}
+
+
+
+
+
+/***** LIBRARY **** */
+/// Configures how [`Serializable::to_path_with_options()`] writes a file.
+///
+/// Use [`WriteOptions::default()`] to get the same behaviour as
+/// [`Serializable::to_path()`], and override the fields you need.
+///
+/// # Examples
+/// ```rust
+/// use serializable::WriteOptions;
+///
+/// // Write `state.json` atomically, keeping `state.json.1` up to `state.json.3`
+/// let opts = WriteOptions { atomic: true, backups: 3, ..Default::default() };
+/// assert!(!opts.pretty);
+/// ```
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct WriteOptions {
+    /// Whether to use the pretty backend (if it's available).
+    pub pretty: bool,
+    /// Whether to write the file atomically (see [`Serializable::to_path_atomic()`]).
+    pub atomic: bool,
+    /// The number of backups to keep of the file.
+    ///
+    /// If non-zero, then right before the file is overwritten (i.e., after
+    /// the new contents have been serialized), its existing backups are
+    /// rotated (i.e., `<path>.1` becomes `<path>.2`, etc., up to
+    /// `<path>.<backups>`) and its current contents are copied to
+    /// `<path>.1`. Use [`Serializable::from_path_with_fallback()`] to read
+    /// the newest valid backup if the file itself turns out to be corrupt.
+    pub backups: usize,
+    /// The codec to compress the file with.
+    ///
+    /// If [`None`], then it's chosen by the extension of the path (e.g.,
+    /// `state.json.gz`), and the file is not compressed if that's not the
+    /// extension of any of the enabled codecs. Note that files are only
+    /// decompressed when read if their extension says so.
+    pub compression: Option<Compression>,
+    /// The algorithm with which to write a checksum file next to the file.
+    ///
+    /// If given, the checksum of the file (as written) is stored in
+    /// `<path>.<ext>` (e.g., `state.json.b3`), against which
+    /// [`Serializable::from_path()`] and friends verify the file. If
+    /// [`None`], then any existing checksum files of the file are removed,
+    /// like the other functions that write files (e.g.,
+    /// [`Serializable::to_path()`]) do.
+    pub checksum: Option<Checksum>,
+}
+
+
+
+
+
+/// Conveniently implements functions to serialize- or deserialize a struct using serde (or other serializers).
+///
+/// # Examples
+/// ```rust
+/// use serializable::dummy::Serializer;
+/// use serializable::Serializable;
+///
+/// #[derive(Debug, Default, Eq, PartialEq)]
+/// struct HelloWorld {
+///     hello: String,
+///     world: String,
+/// }
+/// impl Serializable<Serializer<HelloWorld>> for HelloWorld {}
+///
+/// // Note: the dummy serializer doesn't actually serialize/deserialize any content. Check the features for proper ones!
+/// assert_eq!(
+///     HelloWorld { hello: "Hello".into(), world: "World".into() }.to_string().unwrap(),
+///     "<dummy_text>"
+/// );
+/// assert_eq!(HelloWorld::from_str("<dummy_text>").unwrap(), HelloWorld::default());
+/// ```
+pub trait Serializable<T: Serializer<Target = Self>> {
+    // Configuration
+    /// Whether this type prefers to be serialized using the pretty backend.
+    ///
+    /// This is used by the `*_preferred()`-functions (e.g.,
+    /// [`Self::to_string_preferred()`](Serializable::to_string_preferred())).
+    /// Defaults to `false`.
+    const PRETTY: bool = false;
+    /// The preferred file extension (without leading dot) for files that
+    /// contain this type in this backend, if any.
+    ///
+    /// This is used by
+    /// [`Self::preferred_path()`](Serializable::preferred_path()). Defaults
+    /// to [`None`].
+    const EXTENSION: Option<&'static str> = None;
+    /// The options with which this type is pretty-printed, as far as the
+    /// backend supports them.
+    ///
+    /// This is used by the `*_pretty()`-functions (e.g.,
+    /// [`Self::to_string_pretty()`](Serializable::to_string_pretty())), and
+    /// hence also when writing files with
+    /// [`Self::to_path_pretty()`](Serializable::to_path_pretty()). Defaults
+    /// to [`PrettyOptions::DEFAULT`], i.e., the backend's own style.
+    const PRETTY_OPTIONS: PrettyOptions = PrettyOptions::DEFAULT;
+
+
+
+    // Serializer backend aliases
+    /// Serializes this object to a string.
+    ///
+    /// # Returns
+    /// A string representing this object.
+    ///
+    /// # Errors
+    /// This function may error with an [`Error::SerializeString`] if the
+    /// backend serializer failed to serialize.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use serializable::dummy::Serializer;
+    /// use serializable::Serializable;
+    ///
+    /// #[derive(Default)]
+    /// struct HelloWorld {
+    ///     hello: String,
+    ///     world: String,
+    /// }
+    /// impl Serializable<Serializer<HelloWorld>> for HelloWorld {}
+    ///
+    /// // Note: the dummy serializer doesn't actually serialize/deserialize any content. Check the features for proper ones!
+    /// assert_eq!(HelloWorld { hello: "Hello".into(), world: "World".into() }.to_string().unwrap(), "<dummy_text>");
+    /// ```
+    #[inline]
+    fn to_string(&self) -> Result<String, Error<T::Error>> {
+        match T::to_string(self) {
+            Ok(res) => Ok(res),
+            Err(err) => Err(Error::SerializeString { what: type_name::<T::Target>(), err }),
+        }
+    }
+
+    /// Serializes this object to a string, using a pretty backend if it's
+    /// available.
+    ///
+    /// If not, then this is equivalent to calling
+    /// [`Self::to_string()`](Serializable::to_string()). The backend honours
+    /// [`Self::PRETTY_OPTIONS`](Serializable::PRETTY_OPTIONS) as far as it
+    /// can.
+    ///
+    /// # Returns
+    /// A string representing this object.
+    ///
+    /// # Errors
+    /// This function may error with an [`Error::SerializeString`] if the
+    /// backend serializer failed to serialize.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use serializable::dummy::Serializer;
+    /// use serializable::Serializable;
+    ///
+    /// #[derive(Default)]
+    /// struct HelloWorld {
+    ///     hello: String,
+    ///     world: String,
+    /// }
+    /// impl Serializable<Serializer<HelloWorld>> for HelloWorld {}
+    ///
+    /// // Note: the dummy serializer doesn't actually serialize/deserialize any content. Check the features for proper ones!
+    /// assert_eq!(HelloWorld { hello: "Hello".into(), world: "World".into() }.to_string_pretty().unwrap(), "Dummy Text");
+    /// ```
+    #[inline]
+    fn to_string_pretty(&self) -> Result<String, Error<T::Error>> { self.to_string_pretty_with(&Self::PRETTY_OPTIONS) }
+
+    /// Serializes this object to a string, using a pretty backend if
+    /// it's available and honouring the given [`PrettyOptions`] as far as it
+    /// can.
+    ///
+    /// # Arguments
+    /// - `opts`: The [`PrettyOptions`] to use instead of
+    ///   [`Self::PRETTY_OPTIONS`](Serializable::PRETTY_OPTIONS).
+    ///
+    /// # Returns
+    /// A string representing this object.
+    ///
+    /// # Errors
+    /// This function may error with an [`Error::SerializeString`] if the
+    /// backend serializer failed to serialize.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use serializable::dummy::Serializer;
+    /// use serializable::{PrettyOptions, Serializable};
+    ///
+    /// #[derive(Default)]
+    /// struct HelloWorld {
+    ///     hello: String,
+    ///     world: String,
+    /// }
+    /// impl Serializable<Serializer<HelloWorld>> for HelloWorld {}
+    ///
+    /// // Note: the dummy serializer doesn't actually serialize/deserialize any content. Check the features for proper ones!
+    /// let opts = PrettyOptions { sort_keys: true, ..Default::default() };
+    /// assert_eq!(HelloWorld { hello: "Hello".into(), world: "World".into() }.to_string_pretty_with(&opts).unwrap(), "Dummy Text");
+    /// ```
+    #[inline]
+    fn to_string_pretty_with(&self, opts: &PrettyOptions) -> Result<String, Error<T::Error>> {
+        match T::to_string_pretty_with(self, opts) {
+            Ok(res) => Ok(res),
+            Err(err) => Err(Error::SerializeString { what: type_name::<T::Target>(), err }),
+        }
+    }
+
+    /// Serializes this object to a byte buffer.
+    ///
+    /// # Returns
+    /// A buffer with the serialized representation of this object.
+    ///
+    /// # Errors
+    /// This function may error with an [`Error::SerializeBytes`] if the
+    /// backend serializer failed to serialize.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use serializable::dummy::Serializer;
+    /// use serializable::Serializable;
+    ///
+    /// #[derive(Default)]
+    /// struct HelloWorld {
+    ///     hello: String,
+    ///     world: String,
+    /// }
+    /// impl Serializable<Serializer<HelloWorld>> for HelloWorld {}
+    ///
+    /// // Note: the dummy serializer doesn't actually serialize/deserialize any content. Check the features for proper ones!
+    /// assert_eq!(HelloWorld { hello: "Hello".into(), world: "World".into() }.to_bytes().unwrap(), b"<dummy_text>");
+    /// ```
+    #[inline]
+    fn to_bytes(&self) -> Result<Vec<u8>, Error<T::Error>> {
+        match T::to_bytes(self) {
+            Ok(res) => Ok(res),
+            Err(err) => Err(Error::SerializeBytes { what: type_name::<T::Target>(), err }),
+        }
+    }
+
+    /// Serializes this object to a byte buffer, using a pretty backend if
+    /// it's available.
+    ///
+    /// If not, then this is equivalent to calling
+    /// [`Self::to_bytes()`](Serializable::to_bytes()). The backend honours
+    /// [`Self::PRETTY_OPTIONS`](Serializable::PRETTY_OPTIONS) as far as it
+    /// can.
+    ///
+    /// # Returns
+    /// A buffer with the serialized representation of this object.
+    ///
+    /// # Errors
+    /// This function may error with an [`Error::SerializeBytes`] if the
+    /// backend serializer failed to serialize.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use serializable::dummy::Serializer;
+    /// use serializable::Serializable;
+    ///
+    /// #[derive(Default)]
+    /// struct HelloWorld {
+    ///     hello: String,
+    ///     world: String,
+    /// }
+    /// impl Serializable<Serializer<HelloWorld>> for HelloWorld {}
+    ///
+    /// // Note: the dummy serializer doesn't actually serialize/deserialize any content. Check the features for proper ones!
+    /// assert_eq!(HelloWorld { hello: "Hello".into(), world: "World".into() }.to_bytes_pretty().unwrap(), b"Dummy Text");
+    /// ```
+    #[inline]
+    fn to_bytes_pretty(&self) -> Result<Vec<u8>, Error<T::Error>> { self.to_bytes_pretty_with(&Self::PRETTY_OPTIONS) }
+
+    /// Serializes this object to a byte buffer, using a pretty backend if
+    /// it's available and honouring the given [`PrettyOptions`] as far as it
+    /// can.
+    ///
+    /// # Arguments
+    /// - `opts`: The [`PrettyOptions`] to use instead of
+    ///   [`Self::PRETTY_OPTIONS`](Serializable::PRETTY_OPTIONS).
+    ///
+    /// # Returns
+    /// A buffer with the serialized representation of this object.
+    ///
+    /// # Errors
+    /// This function may error with an [`Error::SerializeBytes`] if the
+    /// backend serializer failed to serialize.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use serializable::dummy::Serializer;
+    /// use serializable::{PrettyOptions, Serializable};
+    ///
+    /// #[derive(Default)]
+    /// struct HelloWorld {
+    ///     hello: String,
+    ///     world: String,
+    /// }
+    /// impl Serializable<Serializer<HelloWorld>> for HelloWorld {}
+    ///
+    /// // Note: the dummy serializer doesn't actually serialize/deserialize any content. Check the features for proper ones!
+    /// let opts = PrettyOptions { sort_keys: true, ..Default::default() };
+    /// assert_eq!(HelloWorld { hello: "Hello".into(), world: "World".into() }.to_bytes_pretty_with(&opts).unwrap(), b"Dummy Text");
+    /// ```
+    #[inline]
+    fn to_bytes_pretty_with(&self, opts: &PrettyOptions) -> Result<Vec<u8>, Error<T::Error>> {
+        match T::to_bytes_pretty_with(self, opts) {
+            Ok(res) => Ok(res),
+            Err(err) => Err(Error::SerializeBytes { what: type_name::<T::Target>(), err }),
+        }
+    }
+
+    /// Serializes this object to the given writer.
+    ///
+    /// # Arguments
+    /// - `writer`: A [`Write`]r that we will serialize to.
+    ///
+    /// # Errors
+    /// This function may error with an [`Error::SerializeWriter`] if the
+    /// backend serializer failed to serialize. This may also be because it
+    /// failed to write to the `writer`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use serializable::dummy::Serializer;
+    /// use serializable::Serializable;
+    ///
+    /// #[derive(Default)]
+    /// struct HelloWorld {
+    ///     hello: String,
+    ///     world: String,
+    /// }
+    /// impl Serializable<Serializer<HelloWorld>> for HelloWorld {}
+    ///
+    /// // Note: the dummy serializer doesn't actually serialize/deserialize any content. Check the features for proper ones!
+    /// let mut buf: [u8; 12] = [0; 12];
+    /// HelloWorld { hello: "Hello".into(), world: "World".into() }.to_writer(&mut buf[..]).unwrap();
+    /// assert_eq!(String::from_utf8_lossy(&buf), "<dummy_text>");
+    ///
+    /// let mut buf: [u8; 0] = [];
+    /// assert!(matches!(HelloWorld { hello: "Hello".into(), world: "World".into() }.to_writer(&mut buf[..]), Err(serializable::Error::SerializeWriter { .. })));
+    /// ```
+    #[inline]
+    fn to_writer(&self, writer: impl Write) -> Result<(), Error<T::Error>> {
+        match T::to_writer(self, writer) {
+            Ok(_) => Ok(()),
+            Err(err) => Err(Error::SerializeWriter { what: type_name::<T::Target>(), err }),
+        }
+    }
+
+    /// Serializes this object to the given writer, using a pretty backend if
+    /// it's available.
+    ///
+    /// If not, then this is equivalent to calling
+    /// [`Self::to_writer()`](Serializable::to_writer()). The backend honours
+    /// [`Self::PRETTY_OPTIONS`](Serializable::PRETTY_OPTIONS) as far as it
+    /// can.
+    ///
+    /// # Arguments
+    /// - `writer`: A [`Write`]r that we will serialize to.
+    ///
+    /// # Errors
+    /// This function may error with an [`Error::SerializeWriter`] if the
+    /// backend serializer failed to serialize. This may also be because it
+    /// failed to write to the `writer`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use serializable::dummy::Serializer;
+    /// use serializable::Serializable;
+    ///
+    /// #[derive(Default)]
+    /// struct HelloWorld {
+    ///     hello: String,
+    ///     world: String,
+    /// }
+    /// impl Serializable<Serializer<HelloWorld>> for HelloWorld {}
+    ///
+    /// // Note: the dummy serializer doesn't actually serialize/deserialize any content. Check the features for proper ones!
+    /// let mut buf: [u8; 10] = [0; 10];
+    /// HelloWorld { hello: "Hello".into(), world: "World".into() }.to_writer_pretty(&mut buf[..]).unwrap();
+    /// assert_eq!(String::from_utf8_lossy(&buf), "Dummy Text");
+    ///
+    /// let mut buf: [u8; 0] = [];
+    /// assert!(matches!(HelloWorld { hello: "Hello".into(), world: "World".into() }.to_writer_pretty(&mut buf[..]), Err(serializable::Error::SerializeWriter { .. })));
+    /// ```
+    #[inline]
+    fn to_writer_pretty(&self, writer: impl Write) -> Result<(), Error<T::Error>> { self.to_writer_pretty_with(&Self::PRETTY_OPTIONS, writer) }
+
+    /// Serializes this object to the given writer, using a pretty backend if
+    /// it's available and honouring the given [`PrettyOptions`] as far as it
+    /// can.
+    ///
+    /// # Arguments
+    /// - `opts`: The [`PrettyOptions`] to use instead of
+    ///   [`Self::PRETTY_OPTIONS`](Serializable::PRETTY_OPTIONS).
+    /// - `writer`: A [`Write`]r that we will serialize to.
+    ///
+    /// # Errors
+    /// This function may error with an [`Error::SerializeWriter`] if the
+    /// backend serializer failed to serialize. This may also be because it
+    /// failed to write to the `writer`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use serializable::dummy::Serializer;
+    /// use serializable::{PrettyOptions, Serializable};
+    ///
+    /// #[derive(Default)]
+    /// struct HelloWorld {
+    ///     hello: String,
+    ///     world: String,
+    /// }
+    /// impl Serializable<Serializer<HelloWorld>> for HelloWorld {}
+    ///
+    /// // Note: the dummy serializer doesn't actually serialize/deserialize any content. Check the features for proper ones!
+    /// let opts = PrettyOptions { sort_keys: true, ..Default::default() };
+    /// let mut buf: [u8; 10] = [0; 10];
+    /// HelloWorld { hello: "Hello".into(), world: "World".into() }.to_writer_pretty_with(&opts, &mut buf[..]).unwrap();
+    /// assert_eq!(String::from_utf8_lossy(&buf), "Dummy Text");
+    /// ```
+    #[inline]
+    fn to_writer_pretty_with(&self, opts: &PrettyOptions, writer: impl Write) -> Result<(), Error<T::Error>> {
+        match T::to_writer_pretty_with(self, opts, writer) {
+            Ok(_) => Ok(()),
+            Err(err) => Err(Error::SerializeWriter { what: type_name::<T::Target>(), err }),
+        }
+    }
+
+    /// Deserializes this object from the given string.
+    ///
+    /// # Arguments
+    /// - `raw`: The raw string that provides a serialized instantiation of
+    ///   Self.
+    ///
+    /// # Returns
+    /// A new Self that represents the deserialized counterpart.
+    ///
+    /// # Errors
+    /// This function may error with an [`Error::DeserializeString`] if the
+    /// backend deserializer failed to deserialize. This may be because the
+    /// serialized representation was illegal for this type and backend.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use serializable::dummy::Serializer;
+    /// use serializable::Serializable;
+    ///
+    /// #[derive(Debug, Default, Eq, PartialEq)]
+    /// struct HelloWorld {
+    ///     hello: String,
+    ///     world: String,
+    /// }
+    /// impl Serializable<Serializer<HelloWorld>> for HelloWorld {}
+    ///
+    /// // Note: the dummy serializer doesn't actually serialize/deserialize any content. Check the features for proper ones!
+    /// assert_eq!(HelloWorld::from_str("<dummy_text>").unwrap(), HelloWorld::default());
+    /// ```
+    #[inline]
+    fn from_str(raw: impl AsRef<str>) -> Result<Self, Error<T::Error>>
+    where
+        Self: Sized,
+    {
+        let raw: &str = raw.as_ref();
+        match T::from_str(raw) {
+            Ok(res) => Ok(res),
+            Err(err) => {
+                let loc: Option<Location> = T::location(&err, Some(raw));
+                Err(Error::DeserializeString { what: type_name::<T::Target>(), ctx: context(T::field_path(&err), loc, Some(raw)), err })
+            },
+        }
+    }
+
+    /// Deserializes this object from the given byte buffer.
+    ///
+    /// # Arguments
+    /// - `raw`: The raw buffer that provides a serialized instantiation of
+    ///   Self.
+    ///
+    /// # Returns
+    /// A new Self that represents the deserialized counterpart.
+    ///
+    /// # Errors
+    /// This function may error with an [`Error::DeserializeBytes`] if the
+    /// backend deserializer failed to deserialize. This may be because the
+    /// serialized representation was illegal for this type and backend.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use serializable::dummy::Serializer;
+    /// use serializable::Serializable;
+    ///
+    /// #[derive(Debug, Default, Eq, PartialEq)]
+    /// struct HelloWorld {
+    ///     hello: String,
+    ///     world: String,
+    /// }
+    /// impl Serializable<Serializer<HelloWorld>> for HelloWorld {}
+    ///
+    /// // Note: the dummy serializer doesn't actually serialize/deserialize any content. Check the features for proper ones!
+    /// assert_eq!(HelloWorld::from_bytes(b"<dummy_text>").unwrap(), HelloWorld::default());
+    /// ```
+    #[inline]
+    fn from_bytes(raw: impl AsRef<[u8]>) -> Result<Self, Error<T::Error>>
+    where
+        Self: Sized,
+    {
+        match T::from_bytes(raw) {
+            Ok(res) => Ok(res),
+            Err(err) => Err(Error::DeserializeBytes { what: type_name::<T::Target>(), ctx: context(T::field_path(&err), None, None), err }),
+        }
+    }
+
+    /// Deserializes this object from the given reader.
+    ///
+    /// # Arguments
+    /// - `reader`: The [`Read`]er that provides a serialized instantiation of
+    ///   Self.
+    ///
+    /// # Returns
+    /// A new Self that represents the deserialized counterpart.
+    ///
+    /// # Errors
+    /// This function may error with an [`Error::DeserializeReader`] if the
+    /// backend deserializer failed to deserialize. This may be because the
+    /// backend failed to read from the given `reader`, or because the
+    /// serialized representation was illegal for this type and backend.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use serializable::dummy::Serializer;
+    /// use serializable::Serializable;
+    ///
+    /// #[derive(Debug, Default, Eq, PartialEq)]
+    /// struct HelloWorld {
+    ///     hello: String,
+    ///     world: String,
+    /// }
+    /// impl Serializable<Serializer<HelloWorld>> for HelloWorld {}
+    ///
+    /// // Note: the dummy serializer doesn't actually serialize/deserialize any content. Check the features for proper ones!
+    /// assert_eq!(HelloWorld::from_reader("<dummy_text>".as_bytes()).unwrap(), HelloWorld::default());
+    /// ```
+    #[inline]
+    fn from_reader(reader: impl Read) -> Result<Self, Error<T::Error>>
+    where
+        Self: Sized,
+    {
+        match T::from_reader(reader) {
+            Ok(res) => Ok(res),
+            Err(err) => {
+                Err(Error::DeserializeReader { what: type_name::<T::Target>(), ctx: context(T::field_path(&err), T::location(&err, None), None), err })
+            },
+        }
+    }
+
+
+
+    // Convenience functions
+    /// Convenience function for serializing this object to a file.
+    ///
+    /// # Arguments
+    /// - `path`: The path to serialize this object to.
+    ///
+    /// # Errors
+    /// This function may error with an [`Error::FileCreate`] if it failed to
+    /// create a new file, or an [`Error::SerializeFile`] if the
+    /// backend serializer failed to serialize. This may also be because it
+    /// failed to write to the file.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use std::path::PathBuf;
+    /// use serializable::dummy::Serializer;
+    /// use serializable::Serializable;
+    ///
+    /// #[derive(Default)]
+    /// struct HelloWorld {
+    ///     hello: String,
+    ///     world: String,
+    /// }
+    /// impl Serializable<Serializer<HelloWorld>> for HelloWorld {}
+    ///
+    /// // Note: the dummy serializer doesn't actually serialize/deserialize any content. Check the features for proper ones!
+    /// let path: PathBuf = std::env::temp_dir().join("test.txt");
+    /// HelloWorld { hello: "Hello".into(), world: "World".into() }.to_path(&path).unwrap();
+    /// assert_eq!(std::fs::read_to_string(path).unwrap(), "<dummy_text>");
+    /// ```
+    #[inline]
+    fn to_path(&self, path: impl AsRef<Path>) -> Result<(), Error<T::Error>> {
+        // Open the file as a writer
+        let path: &Path = path.as_ref();
+        let handle: File = match File::create(path) {
+            Ok(handle) => handle,
+            Err(err) => {
+                return Err(Error::FileCreate { path: path.into(), err });
+            },
+        };
+
+        // Pass to the writer impl (compressing if the extension says so)
+        write_compressed(handle, path, Compression::from_path(path), |w| self.to_writer(w))?;
+
+        // Remove any checksum files, as they no longer match
+        write_checksum(path, None, false)
+    }
+    /// Convenience function for serializing this object to a file, using a pretty backend if it's available.
+    ///
+    /// If not, then this is equivalent to calling
+    /// [`Self::to_path()`](Serializable::to_path()).
+    ///
+    /// # Arguments
+    /// - `path`: The path to serialize this object to.
+    ///
+    /// # Errors
+    /// This function may error with an [`Error::FileCreate`] if it failed to
+    /// create a new file, or an [`Error::SerializeFile`] if the
+    /// backend serializer failed to serialize. This may also be because it
+    /// failed to write to the file.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use std::path::PathBuf;
+    /// use serializable::dummy::Serializer;
+    /// use serializable::Serializable;
+    ///
+    /// #[derive(Default)]
+    /// struct HelloWorld {
+    ///     hello: String,
+    ///     world: String,
+    /// }
+    /// impl Serializable<Serializer<HelloWorld>> for HelloWorld {}
+    ///
+    /// // Note: the dummy serializer doesn't actually serialize/deserialize any content. Check the features for proper ones!
+    /// let path: PathBuf = std::env::temp_dir().join("test.txt");
+    /// HelloWorld { hello: "Hello".into(), world: "World".into() }.to_path_pretty(&path).unwrap();
+    /// assert_eq!(std::fs::read_to_string(path).unwrap(), "Dummy Text");
+    /// ```
+    #[inline]
+    fn to_path_pretty(&self, path: impl AsRef<Path>) -> Result<(), Error<T::Error>> {
+        // Open the file as a writer
+        let path: &Path = path.as_ref();
+        let handle: File = match File::create(path) {
+            Ok(handle) => handle,
+            Err(err) => {
+                return Err(Error::FileCreate { path: path.into(), err });
+            },
+        };
+
+        // Pass to the writer impl (compressing if the extension says so)
+        write_compressed(handle, path, Compression::from_path(path), |w| self.to_writer_pretty(w))?;
+
+        // Remove any checksum files, as they no longer match
+        write_checksum(path, None, false)
+    }
+    /// Convenience function for deserializing this object from a file.
+    ///
+    /// # Arguments
+    /// - `path`: The path to deserialize this object from.
+    ///
+    /// # Returns
+    /// A new Self that represents the deserialized object.
+    ///
+    /// # Errors
+    /// This function may error with an [`Error::FileOpen`] if it failed to
+    /// create a new file, or an [`Error::DeserializeFile`] if the
+    /// backend serializer failed to serialize. This may also be because it
+    /// failed to write to the file.
+    ///
+    /// If the file has a checksum file (see [`WriteOptions::checksum`]), then
+    /// it is verified before it's deserialized, and this function errors with
+    /// an [`Error::ChecksumMismatch`] if it doesn't match.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use std::path::PathBuf;
+    /// use serializable::dummy::Serializer;
+    /// use serializable::Serializable;
+    ///
+    /// #[derive(Debug, Default, Eq, PartialEq)]
+    /// struct HelloWorld {
+    ///     hello: String,
+    ///     world: String,
+    /// }
+    /// impl Serializable<Serializer<HelloWorld>> for HelloWorld {}
+    ///
+    /// // Note: the dummy serializer doesn't actually serialize/deserialize any content. Check the features for proper ones!
+    /// let path: PathBuf = std::env::temp_dir().join("test.txt");
+    /// std::fs::write(&path, "<dummy_text>").unwrap();
+    /// assert_eq!(HelloWorld::from_path(path).unwrap(), HelloWorld::default());
+    /// ```
+    #[inline]
+    fn from_path(path: impl AsRef<Path>) -> Result<Self, Error<T::Error>>
+    where
+        Self: Sized,
+    {
+        // Open the file as a writer
+        let path: &Path = path.as_ref();
+        let handle: File = match File::open(path) {
+            Ok(handle) => handle,
+            Err(err) => {
+                return Err(Error::FileOpen { path: path.into(), err });
+            },
+        };
+
+        // Verify the file if it has a checksum, then pass to the reader impl (decompressing if needed)
+        let res: Result<Self, Error<T::Error>> = match read_verified(&handle, path)? {
+            Some(raw) => read_decoded(raw.as_slice(), path, |dec| Self::from_reader(dec))?,
+            None => read_decoded(handle, path, |dec| Self::from_reader(dec))?,
+        };
+        match res {
+            Ok(res) => Ok(res),
+            Err(Error::DeserializeReader { what, ctx, err }) => {
+                // Read the file again to find the offending line (and the location, for backends that need the input for that)
+                let raw: Option<String> = read_raw(path);
+                let loc: Option<Location> = T::location(&err, raw.as_deref()).or(ctx.loc);
+                Err(Error::DeserializeFile { what, path: path.into(), ctx: context(ctx.field_path, loc, raw.as_deref()), err })
+            },
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Convenience function for serializing this object to a string using
+    /// dynamic prettyness.
+    ///
+    /// # Arguments
+    /// - `pretty`: Whether to use the pretty formatter or not.
+    ///
+    /// # Returns
+    /// A string representing this object.
+    ///
+    /// # Errors
+    /// This function may error with an [`Error::SerializeString`] if the
+    /// backend serializer failed to serialize.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use serializable::dummy::Serializer;
+    /// use serializable::Serializable;
+    ///
+    /// #[derive(Default)]
+    /// struct HelloWorld {
+    ///     hello: String,
+    ///     world: String,
+    /// }
+    /// impl Serializable<Serializer<HelloWorld>> for HelloWorld {}
+    ///
+    /// // Note: the dummy serializer doesn't actually serialize/deserialize any content. Check the features for proper ones!
+    /// fn to_string(hello_world: HelloWorld, pretty: bool) -> String {
+    ///     hello_world.to_string_pretty_opt(pretty).unwrap()
+    /// }
+    ///
+    /// assert_eq!(to_string(HelloWorld { hello: "Hello".into(), world: "World".into() }, false), "<dummy_text>");
+    /// assert_eq!(to_string(HelloWorld { hello: "Hello".into(), world: "World".into() }, true), "Dummy Text");
+    /// ```
+    #[inline]
+    fn to_string_pretty_opt(&self, pretty: bool) -> Result<String, Error<T::Error>> {
+        if pretty { self.to_string_pretty() } else { self.to_string() }
+    }
+    /// Convenience function for serializing this object to a byte buffer
+    /// using dynamic prettyness.
+    ///
+    /// # Arguments
+    /// - `pretty`: Whether to use the pretty formatter or not.
+    ///
+    /// # Returns
+    /// A buffer with the serialized representation of this object.
+    ///
+    /// # Errors
+    /// This function may error with an [`Error::SerializeBytes`] if the
+    /// backend serializer failed to serialize.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use serializable::dummy::Serializer;
+    /// use serializable::Serializable;
+    ///
+    /// #[derive(Default)]
+    /// struct HelloWorld {
+    ///     hello: String,
+    ///     world: String,
+    /// }
+    /// impl Serializable<Serializer<HelloWorld>> for HelloWorld {}
+    ///
+    /// // Note: the dummy serializer doesn't actually serialize/deserialize any content. Check the features for proper ones!
+    /// fn to_bytes(hello_world: HelloWorld, pretty: bool) -> Vec<u8> {
+    ///     hello_world.to_bytes_pretty_opt(pretty).unwrap()
+    /// }
+    ///
+    /// assert_eq!(to_bytes(HelloWorld { hello: "Hello".into(), world: "World".into() }, false), b"<dummy_text>");
+    /// assert_eq!(to_bytes(HelloWorld { hello: "Hello".into(), world: "World".into() }, true), b"Dummy Text");
+    /// ```
+    #[inline]
+    fn to_bytes_pretty_opt(&self, pretty: bool) -> Result<Vec<u8>, Error<T::Error>> {
+        if pretty { self.to_bytes_pretty() } else { self.to_bytes() }
+    }
+    /// Convenience function for serializing this object to a writer using
+    /// dynamic prettyness.
+    ///
+    /// # Arguments
+    /// - `writer`: A [`Write`]r that we will serialize to.
+    /// - `pretty`: Whether to use the pretty formatter or not.
+    ///
+    /// # Errors
+    /// This function may error with an [`Error::SerializeWriter`] if the
+    /// backend serializer failed to serialize. This may also be because it
+    /// failed to write to the `writer`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use std::io::Write;
+    /// use serializable::dummy::Serializer;
+    /// use serializable::Serializable;
+    ///
+    /// #[derive(Default)]
+    /// struct HelloWorld {
+    ///     hello: String,
+    ///     world: String,
+    /// }
+    /// impl Serializable<Serializer<HelloWorld>> for HelloWorld {}
+    ///
+    /// // Note: the dummy serializer doesn't actually serialize/deserialize any content. Check the features for proper ones!
+    /// fn to_writer(hello_world: HelloWorld, writer: impl Write, pretty: bool) -> Result<(), serializable::Error<serializable::dummy::Error>> {
+    ///     hello_world.to_writer_pretty_opt(writer, pretty)
+    /// }
+    ///
+    /// let mut buf: [u8; 12] = [0; 12];
+    /// to_writer(HelloWorld { hello: "Hello".into(), world: "World".into() }, &mut buf[..], false).unwrap();
+    /// assert_eq!(String::from_utf8_lossy(&buf), "<dummy_text>");
+    ///
+    /// let mut buf: [u8; 10] = [0; 10];
+    /// to_writer(HelloWorld { hello: "Hello".into(), world: "World".into() }, &mut buf[..], true).unwrap();
+    /// assert_eq!(String::from_utf8_lossy(&buf), "Dummy Text");
+    ///
+    /// let mut buf: [u8; 0] = [];
+    /// assert!(matches!(to_writer(HelloWorld { hello: "Hello".into(), world: "World".into() }, &mut buf[..], false), Err(serializable::Error::SerializeWriter { .. })));
+    /// assert!(matches!(to_writer(HelloWorld { hello: "Hello".into(), world: "World".into() }, &mut buf[..], true), Err(serializable::Error::SerializeWriter { .. })));
+    /// ```
+    #[inline]
+    fn to_writer_pretty_opt(&self, writer: impl Write, pretty: bool) -> Result<(), Error<T::Error>> {
+        if pretty { self.to_writer_pretty(writer) } else { self.to_writer(writer) }
+    }
+    /// Convenience function for serializing this object to a path using
+    /// dynamic prettyness.
+    ///
+    /// # Arguments
+    /// - `path`: The path to serialize this object to.
+    /// - `pretty`: Whether to use the pretty formatter or not.
+    ///
+    /// # Errors
+    /// This function may error with an [`Error::FileCreate`] if it failed to
+    /// create a new file, or an [`Error::SerializeFile`] if the
+    /// backend serializer failed to serialize. This may also be because it
+    /// failed to write to the file.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use std::path::PathBuf;
+    /// use serializable::dummy::Serializer;
+    /// use serializable::Serializable;
+    ///
+    /// #[derive(Default)]
+    /// struct HelloWorld {
+    ///     hello: String,
+    ///     world: String,
+    /// }
+    /// impl Serializable<Serializer<HelloWorld>> for HelloWorld {}
+    ///
+    /// // Note: the dummy serializer doesn't actually serialize/deserialize any content. Check the features for proper ones!
+    /// fn to_path(hello_world: HelloWorld, pretty: bool) -> String {
+    ///     let path: PathBuf = std::env::temp_dir().join(format!("test_{}.txt", if pretty { "pretty" } else { "ugly" }));
+    ///     hello_world.to_path_pretty_opt(&path, pretty).unwrap();
+    ///     std::fs::read_to_string(path).unwrap()
+    /// }
+    ///
+    /// assert_eq!(to_path(HelloWorld { hello: "Hello".into(), world: "World".into() }, false), "<dummy_text>");
+    /// assert_eq!(to_path(HelloWorld { hello: "Hello".into(), world: "World".into() }, true), "Dummy Text");
+    /// ```
+    #[inline]
+    fn to_path_pretty_opt(&self, path: impl AsRef<Path>, pretty: bool) -> Result<(), Error<T::Error>> {
+        if pretty { self.to_path_pretty(path) } else { self.to_path(path) }
+    }
+
+    /// Convenience function for serializing this object to a string, using
+    /// the pretty backend if [`Self::PRETTY`](Serializable::PRETTY) is set.
+    ///
+    /// # Returns
+    /// A string representing this object.
+    ///
+    /// # Errors
+    /// This function may error with an [`Error::SerializeString`] if the
+    /// backend serializer failed to serialize.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use serializable::dummy::Serializer;
+    /// use serializable::Serializable;
+    ///
+    /// #[derive(Default)]
+    /// struct HelloWorld {
+    ///     hello: String,
+    ///     world: String,
+    /// }
+    /// impl Serializable<Serializer<HelloWorld>> for HelloWorld {
+    ///     const PRETTY: bool = true;
+    /// }
+    ///
+    /// // Note: the dummy serializer doesn't actually serialize/deserialize any content. Check the features for proper ones!
+    /// assert_eq!(HelloWorld { hello: "Hello".into(), world: "World".into() }.to_string_preferred().unwrap(), "Dummy Text");
+    /// ```
+    #[inline]
+    fn to_string_preferred(&self) -> Result<String, Error<T::Error>> { self.to_string_pretty_opt(Self::PRETTY) }
+    /// Convenience function for serializing this object to a byte buffer,
+    /// using the pretty backend if [`Self::PRETTY`](Serializable::PRETTY) is
+    /// set.
+    ///
+    /// # Returns
+    /// A buffer with the serialized representation of this object.
+    ///
+    /// # Errors
+    /// This function may error with an [`Error::SerializeBytes`] if the
+    /// backend serializer failed to serialize.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use serializable::dummy::Serializer;
+    /// use serializable::Serializable;
+    ///
+    /// #[derive(Default)]
+    /// struct HelloWorld {
+    ///     hello: String,
+    ///     world: String,
+    /// }
+    /// impl Serializable<Serializer<HelloWorld>> for HelloWorld {}
+    ///
+    /// // Note: the dummy serializer doesn't actually serialize/deserialize any content. Check the features for proper ones!
+    /// assert_eq!(HelloWorld { hello: "Hello".into(), world: "World".into() }.to_bytes_preferred().unwrap(), b"<dummy_text>");
+    /// ```
+    #[inline]
+    fn to_bytes_preferred(&self) -> Result<Vec<u8>, Error<T::Error>> { self.to_bytes_pretty_opt(Self::PRETTY) }
+    /// Convenience function for serializing this object to a writer, using
+    /// the pretty backend if [`Self::PRETTY`](Serializable::PRETTY) is set.
+    ///
+    /// # Arguments
+    /// - `writer`: A [`Write`]r that we will serialize to.
+    ///
+    /// # Errors
+    /// This function may error with an [`Error::SerializeWriter`] if the
+    /// backend serializer failed to serialize. This may also be because it
+    /// failed to write to the `writer`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use serializable::dummy::Serializer;
+    /// use serializable::Serializable;
+    ///
+    /// #[derive(Default)]
+    /// struct HelloWorld {
+    ///     hello: String,
+    ///     world: String,
+    /// }
+    /// impl Serializable<Serializer<HelloWorld>> for HelloWorld {
+    ///     const PRETTY: bool = true;
+    /// }
+    ///
+    /// // Note: the dummy serializer doesn't actually serialize/deserialize any content. Check the features for proper ones!
+    /// let mut buf: [u8; 10] = [0; 10];
+    /// HelloWorld { hello: "Hello".into(), world: "World".into() }.to_writer_preferred(&mut buf[..]).unwrap();
+    /// assert_eq!(String::from_utf8_lossy(&buf), "Dummy Text");
+    /// ```
+    #[inline]
+    fn to_writer_preferred(&self, writer: impl Write) -> Result<(), Error<T::Error>> { self.to_writer_pretty_opt(writer, Self::PRETTY) }
+    /// Convenience function for serializing this object to a path, using the
+    /// pretty backend if [`Self::PRETTY`](Serializable::PRETTY) is set.
+    ///
+    /// Note that this does not change the extension of `path`. Use
+    /// [`Self::preferred_path()`](Serializable::preferred_path()) for that.
+    ///
+    /// # Arguments
+    /// - `path`: The path to serialize this object to.
+    ///
+    /// # Errors
+    /// This function may error with an [`Error::FileCreate`] if it failed to
+    /// create a new file, or an [`Error::SerializeFile`] if the
+    /// backend serializer failed to serialize. This may also be because it
+    /// failed to write to the file.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use std::path::PathBuf;
+    /// use serializable::dummy::Serializer;
+    /// use serializable::Serializable;
+    ///
+    /// #[derive(Default)]
+    /// struct HelloWorld {
+    ///     hello: String,
+    ///     world: String,
+    /// }
+    /// impl Serializable<Serializer<HelloWorld>> for HelloWorld {
+    ///     const PRETTY: bool = true;
+    /// }
+    ///
+    /// // Note: the dummy serializer doesn't actually serialize/deserialize any content. Check the features for proper ones!
+    /// let path: PathBuf = std::env::temp_dir().join("test_preferred.txt");
+    /// HelloWorld { hello: "Hello".into(), world: "World".into() }.to_path_preferred(&path).unwrap();
+    /// assert_eq!(std::fs::read_to_string(path).unwrap(), "Dummy Text");
+    /// ```
+    #[inline]
+    fn to_path_preferred(&self, path: impl AsRef<Path>) -> Result<(), Error<T::Error>> { self.to_path_pretty_opt(path, Self::PRETTY) }
+    /// Returns the given path with [`Self::EXTENSION`](Serializable::EXTENSION)
+    /// as its extension, if it doesn't have one yet.
+    ///
+    /// # Arguments
+    /// - `path`: The path to add the extension to.
+    ///
+    /// # Returns
+    /// A new [`PathBuf`] that is `path` with the preferred extension. If
+    /// `path` already has an extension or there is no preferred extension,
+    /// then this is simply `path`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use std::path::PathBuf;
+    ///
+    /// use serializable::dummy::Serializer;
+    /// use serializable::Serializable;
+    ///
+    /// #[derive(Default)]
+    /// struct HelloWorld {
+    ///     hello: String,
+    ///     world: String,
+    /// }
+    /// impl Serializable<Serializer<HelloWorld>> for HelloWorld {
+    ///     const EXTENSION: Option<&'static str> = Some("dummy");
+    /// }
+    ///
+    /// assert_eq!(HelloWorld::preferred_path("hello_world"), PathBuf::from("hello_world.dummy"));
+    /// assert_eq!(HelloWorld::preferred_path("hello_world.txt"), PathBuf::from("hello_world.txt"));
+    /// ```
+    #[inline]
+    fn preferred_path(path: impl AsRef<Path>) -> PathBuf {
+        let path: &Path = path.as_ref();
+        match Self::EXTENSION {
+            Some(ext) if path.extension().is_none() => path.with_extension(ext),
+            _ => path.into(),
+        }
+    }
+
+
+    // Atomic writes
+    /// Convenience function for serializing this object to a file
+    /// atomically.
+    ///
+    /// Instead of writing to `path` directly, this writes to a temporary file
+    /// in the same directory first. Only once that has succeeded and is synced
+    /// to disk, it is moved over `path` (after which the directory is synced
+    /// too). As such, `path` either has its old contents or the new ones, even
+    /// if serialization fails or the process crashes halfway.
+    ///
+    /// # Arguments
+    /// - `path`: The path to serialize this object to.
+    ///
+    /// # Errors
+    /// This function may error with an [`Error::FileCreate`] if it failed to
+    /// create the temporary file, an [`Error::SerializeFile`] if the backend
+    /// serializer failed to serialize (or to write to the file), an
+    /// [`Error::FileSync`] if it failed to sync the temporary file, an
+    /// [`Error::FileRename`] if it failed to move it over `path`, or an
+    /// [`Error::DirSync`] if it failed to sync the directory. In all but the
+    /// last case, `path` is left untouched.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use std::path::PathBuf;
+    /// use serializable::dummy::Serializer;
+    /// use serializable::Serializable;
+    ///
+    /// #[derive(Default)]
+    /// struct HelloWorld {
+    ///     hello: String,
+    ///     world: String,
+    /// }
+    /// impl Serializable<Serializer<HelloWorld>> for HelloWorld {}
+    ///
+    /// // Note: the dummy serializer doesn't actually serialize/deserialize any content. Check the features for proper ones!
+    /// let path: PathBuf = std::env::temp_dir().join("test_atomic.txt");
+    /// HelloWorld { hello: "Hello".into(), world: "World".into() }.to_path_atomic(&path).unwrap();
+    /// assert_eq!(std::fs::read_to_string(path).unwrap(), "<dummy_text>");
+    /// ```
+    #[inline]
+    fn to_path_atomic(&self, path: impl AsRef<Path>) -> Result<(), Error<T::Error>> { self.to_path_pretty_opt_atomic(path, false) }
+    /// Convenience function for serializing this object to a file
+    /// atomically, using a pretty backend if it's available.
+    ///
+    /// If not, then this is equivalent to calling
+    /// [`Self::to_path_atomic()`](Serializable::to_path_atomic()). See that
+    /// function for how the file is written atomically.
+    ///
+    /// # Arguments
+    /// - `path`: The path to serialize this object to.
+    ///
+    /// # Errors
+    /// This function may error with an [`Error::FileCreate`] if it failed to
+    /// create the temporary file, an [`Error::SerializeFile`] if the backend
+    /// serializer failed to serialize (or to write to the file), an
+    /// [`Error::FileSync`] if it failed to sync the temporary file, an
+    /// [`Error::FileRename`] if it failed to move it over `path`, or an
+    /// [`Error::DirSync`] if it failed to sync the directory. In all but the
+    /// last case, `path` is left untouched.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use std::path::PathBuf;
+    /// use serializable::dummy::Serializer;
+    /// use serializable::Serializable;
+    ///
+    /// #[derive(Default)]
+    /// struct HelloWorld {
+    ///     hello: String,
+    ///     world: String,
+    /// }
+    /// impl Serializable<Serializer<HelloWorld>> for HelloWorld {}
+    ///
+    /// // Note: the dummy serializer doesn't actually serialize/deserialize any content. Check the features for proper ones!
+    /// let path: PathBuf = std::env::temp_dir().join("test_atomic.txt");
+    /// HelloWorld { hello: "Hello".into(), world: "World".into() }.to_path_pretty_atomic(&path).unwrap();
+    /// assert_eq!(std::fs::read_to_string(path).unwrap(), "Dummy Text");
+    /// ```
+    #[inline]
+    fn to_path_pretty_atomic(&self, path: impl AsRef<Path>) -> Result<(), Error<T::Error>> { self.to_path_pretty_opt_atomic(path, true) }
+    /// Convenience function for serializing this object to a file
+    /// atomically, using dynamic prettyness.
+    ///
+    /// See [`Self::to_path_atomic()`](Serializable::to_path_atomic()) for how
+    /// the file is written atomically.
+    ///
+    /// # Arguments
+    /// - `path`: The path to serialize this object to.
+    /// - `pretty`: Whether to use the pretty formatter or not.
+    ///
+    /// # Errors
+    /// This function may error with an [`Error::FileCreate`] if it failed to
+    /// create the temporary file, an [`Error::SerializeFile`] if the backend
+    /// serializer failed to serialize (or to write to the file), an
+    /// [`Error::FileSync`] if it failed to sync the temporary file, an
+    /// [`Error::FileRename`] if it failed to move it over `path`, or an
+    /// [`Error::DirSync`] if it failed to sync the directory. In all but the
+    /// last case, `path` is left untouched.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use std::path::PathBuf;
+    /// use serializable::dummy::Serializer;
+    /// use serializable::Serializable;
+    ///
+    /// #[derive(Default)]
+    /// struct HelloWorld {
+    ///     hello: String,
+    ///     world: String,
+    /// }
+    /// impl Serializable<Serializer<HelloWorld>> for HelloWorld {}
+    ///
+    /// // Note: the dummy serializer doesn't actually serialize/deserialize any content. Check the features for proper ones!
+    /// let path: PathBuf = std::env::temp_dir().join("test_atomic.txt");
+    /// HelloWorld { hello: "Hello".into(), world: "World".into() }.to_path_pretty_opt_atomic(&path, true).unwrap();
+    /// assert_eq!(std::fs::read_to_string(path).unwrap(), "Dummy Text");
+    /// ```
+    #[inline]
+    fn to_path_pretty_opt_atomic(&self, path: impl AsRef<Path>, pretty: bool) -> Result<(), Error<T::Error>> {
+        let path: &Path = path.as_ref();
+        write_atomic(path, 0, |handle| write_compressed(handle, path, Compression::from_path(path), |w| self.to_writer_pretty_opt(w, pretty)))?;
+        write_checksum(path, None, true)
+    }
+
+
+    // Configurable writes
+    /// Convenience function for serializing this object to a file, using the
+    /// given [`WriteOptions`].
+    ///
+    /// Depending on the options, this writes the file like
+    /// [`Self::to_path_pretty_opt()`](Serializable::to_path_pretty_opt())
+    /// or [`Self::to_path_pretty_opt_atomic()`](Serializable::to_path_pretty_opt_atomic()).
+    /// The object is serialized (to the temporary file if atomic, or to
+    /// memory otherwise) before anything else, and the file's backups are
+    /// only rotated once the new contents are ready to replace it. As such,
+    /// a failing serializer leaves both the file and its backups untouched.
+    /// Finally, it writes the file's checksum file (or removes stale ones).
+    ///
+    /// # Arguments
+    /// - `path`: The path to serialize this object to.
+    /// - `opts`: The [`WriteOptions`] that determine how to write it.
+    ///
+    /// # Errors
+    /// This function may error with an [`Error::FileBackup`] if it failed to
+    /// rotate the backups of the file, or an [`Error::FileWrite`] or
+    /// [`Error::FileRemove`] if it failed to write or remove its checksum
+    /// files. Otherwise, it errors like the function it uses to write the
+    /// file.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use std::path::PathBuf;
+    /// use serializable::dummy::Serializer;
+    /// use serializable::{Serializable, WriteOptions};
+    ///
+    /// #[derive(Default)]
+    /// struct HelloWorld {
+    ///     hello: String,
+    ///     world: String,
+    /// }
+    /// impl Serializable<Serializer<HelloWorld>> for HelloWorld {}
+    ///
+    /// // Note: the dummy serializer doesn't actually serialize/deserialize any content. Check the features for proper ones!
+    /// let path: PathBuf = std::env::temp_dir().join("test_backups.txt");
+    /// std::fs::write(&path, "Old Text").unwrap();
+    /// let opts = WriteOptions { atomic: true, backups: 2, ..Default::default() };
+    /// HelloWorld { hello: "Hello".into(), world: "World".into() }.to_path_with_options(&path, &opts).unwrap();
+    /// assert_eq!(std::fs::read_to_string(&path).unwrap(), "<dummy_text>");
+    /// assert_eq!(std::fs::read_to_string(std::env::temp_dir().join("test_backups.txt.1")).unwrap(), "Old Text");
+    /// ```
+    ///
+    /// If the object fails to serialize, neither the file nor its backups are touched:
+    /// ```rust
+    /// # #[cfg(feature = "serde-json")] {
+    /// use std::collections::HashMap;
+    /// use std::path::PathBuf;
+    ///
+    /// use serializable::json::Serializer;
+    /// use serializable::{Serializable, WriteOptions};
+    ///
+    /// // JSON only supports string keys
+    /// #[derive(serde::Deserialize, serde::Serialize)]
+    /// struct Grid(HashMap<(u8, u8), u8>);
+    /// impl Serializable<Serializer<Grid>> for Grid {}
+    ///
+    /// let path: PathBuf = std::env::temp_dir().join("test_backups_failed.json");
+    /// std::fs::write(&path, "{}").unwrap();
+    /// let _ = std::fs::remove_file(std::env::temp_dir().join("test_backups_failed.json.1"));
+    /// for atomic in [false, true] {
+    ///     let opts = WriteOptions { atomic, backups: 2, ..Default::default() };
+    ///     assert!(Grid(HashMap::from([((0, 0), 1)])).to_path_with_options(&path, &opts).is_err());
+    ///     assert_eq!(std::fs::read_to_string(&path).unwrap(), "{}");
+    ///     assert!(!std::env::temp_dir().join("test_backups_failed.json.1").exists());
+    /// }
+    /// # }
+    /// ```
+    #[inline]
+    fn to_path_with_options(&self, path: impl AsRef<Path>, opts: &WriteOptions) -> Result<(), Error<T::Error>> {
+        let path: &Path = path.as_ref();
+        let compression: Option<Compression> = opts.compression.or_else(|| Compression::from_path(path));
+        let mut hasher: Option<Hasher> = opts.checksum.map(Hasher::new);
+        if opts.atomic {
+            write_atomic(path, opts.backups, |handle| {
+                write_compressed(Hashing::new(handle, hasher.as_mut()), path, compression, |w| self.to_writer_pretty_opt(w, opts.pretty))
+            })?;
+        } else {
+            // Serialize to memory first, so that we only touch the file (and its backups) if that succeeds
+            let mut raw: Vec<u8> = Vec::new();
+            write_compressed(Hashing::new(&mut raw, hasher.as_mut()), path, compression, |w| self.to_writer_pretty_opt(w, opts.pretty))?;
+            rotate_backups(path, opts.backups)?;
+
+            // Then write it
+            let mut handle: File = match File::create(path) {
+                Ok(handle) => handle,
+                Err(err) => {
+                    return Err(Error::FileCreate { path: path.into(), err });
+                },
+            };
+            if let Err(err) = handle.write_all(&raw) {
+                return Err(Error::FileWrite { path: path.into(), err });
+            }
+        }
+
+        // Finally, write its checksum file (or remove stale ones)
+        let sum: Option<String> = hasher.as_ref().map(Hasher::finalize);
+        write_checksum(path, opts.checksum.zip(sum.as_deref()), opts.atomic)
+    }
+
+    /// Convenience function for deserializing this object from a file,
+    /// falling back to its backups if that fails.
+    ///
+    /// The backups are those written by
+    /// [`Self::to_path_with_options()`](Serializable::to_path_with_options())
+    /// (i.e., `<path>.1` up to `<path>.<backups>`), and are tried newest
+    /// first. The first one that deserializes is returned.
+    ///
+    /// # Arguments
+    /// - `path`: The path to deserialize this object from.
+    /// - `backups`: The number of backups to try.
+    ///
+    /// # Returns
+    /// A new Self that represents the deserialized object.
+    ///
+    /// # Errors
+    /// This function errors if neither the file nor any of its backups could
+    /// be deserialized. In that case, the error of the file itself is
+    /// returned (see [`Self::from_path()`](Serializable::from_path())).
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use std::path::PathBuf;
+    /// use serializable::dummy::Serializer;
+    /// use serializable::Serializable;
+    ///
+    /// #[derive(Debug, Default, Eq, PartialEq)]
+    /// struct HelloWorld {
+    ///     hello: String,
+    ///     world: String,
+    /// }
+    /// impl Serializable<Serializer<HelloWorld>> for HelloWorld {}
+    ///
+    /// // Note: the dummy serializer doesn't actually serialize/deserialize any content. Check the features for proper ones!
+    /// let path: PathBuf = std::env::temp_dir().join("test_fallback.txt");
+    /// let _ = std::fs::remove_file(&path);
+    /// std::fs::write(std::env::temp_dir().join("test_fallback.txt.1"), "<dummy_text>").unwrap();
+    /// assert!(HelloWorld::from_path(&path).is_err());
+    /// assert_eq!(HelloWorld::from_path_with_fallback(&path, 1).unwrap(), HelloWorld::default());
+    /// ```
+    #[inline]
+    fn from_path_with_fallback(path: impl AsRef<Path>, backups: usize) -> Result<Self, Error<T::Error>>
+    where
+        Self: Sized,
+    {
+        let path: &Path = path.as_ref();
+        let err: Error<T::Error> = match Self::from_path(path) {
+            Ok(res) => return Ok(res),
+            Err(err) => err,
+        };
+        for n in 1..=backups {
+            if let Ok(res) = Self::from_path(backup_path(path, n)) {
+                return Ok(res);
+            }
+        }
+        Err(err)
+    }
+
+
+    // Locked access
+    /// Convenience function for serializing this object to a file while
+    /// holding an exclusive lock on it.
+    ///
+    /// The file is opened without truncating it, then locked, and only then
+    /// truncated and written. Together with
+    /// [`Self::from_path_locked()`](Serializable::from_path_locked()), which
+    /// takes a shared lock, this prevents concurrent processes from reading
+    /// a half-written file.
+    ///
+    /// Note that the lock is advisory (e.g., `flock()` on Unix), so it's only
+    /// respected by others who lock the file too. The lock is released when
+    /// this function returns.
+    ///
+    /// # Arguments
+    /// - `path`: The path to serialize this object to.
+    /// - `timeout`: The maximum time to wait for others to release the file,
+    ///   or [`None`] to wait indefinitely.
+    ///
+    /// # Errors
+    /// This function may error with an [`Error::FileCreate`] if it failed to
+    /// open (or truncate) the file, an [`Error::FileLock`] if it failed to
+    /// lock it (or timed out), or an [`Error::SerializeFile`] if the backend
+    /// serializer failed to serialize. This may also be because it failed to
+    /// write to the file.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use std::path::PathBuf;
+    /// # use std::time::Duration;
+    /// use serializable::dummy::Serializer;
+    /// use serializable::Serializable;
+    ///
+    /// #[derive(Default)]
+    /// struct HelloWorld {
+    ///     hello: String,
+    ///     world: String,
+    /// }
+    /// impl Serializable<Serializer<HelloWorld>> for HelloWorld {}
+    ///
+    /// // Note: the dummy serializer doesn't actually serialize/deserialize any content. Check the features for proper ones!
+    /// let path: PathBuf = std::env::temp_dir().join("test_locked.txt");
+    /// HelloWorld { hello: "Hello".into(), world: "World".into() }.to_path_locked(&path, Some(Duration::from_secs(1))).unwrap();
+    /// assert_eq!(std::fs::read_to_string(path).unwrap(), "<dummy_text>");
+    /// ```
+    #[inline]
+    fn to_path_locked(&self, path: impl AsRef<Path>, timeout: Option<Duration>) -> Result<(), Error<T::Error>> {
+        self.to_path_pretty_opt_locked(path, false, timeout)
+    }
+    /// Convenience function for serializing this object to a file while
+    /// holding an exclusive lock on it, using a pretty backend if it's
+    /// available.
+    ///
+    /// If not, then this is equivalent to calling
+    /// [`Self::to_path_locked()`](Serializable::to_path_locked()). See that
+    /// function for how the file is locked.
+    ///
+    /// # Arguments
+    /// - `path`: The path to serialize this object to.
+    /// - `timeout`: The maximum time to wait for others to release the file,
+    ///   or [`None`] to wait indefinitely.
+    ///
+    /// # Errors
+    /// This function may error with an [`Error::FileCreate`] if it failed to
+    /// open (or truncate) the file, an [`Error::FileLock`] if it failed to
+    /// lock it (or timed out), or an [`Error::SerializeFile`] if the backend
+    /// serializer failed to serialize. This may also be because it failed to
+    /// write to the file.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use std::path::PathBuf;
+    /// # use std::time::Duration;
+    /// use serializable::dummy::Serializer;
+    /// use serializable::Serializable;
+    ///
+    /// #[derive(Default)]
+    /// struct HelloWorld {
+    ///     hello: String,
+    ///     world: String,
+    /// }
+    /// impl Serializable<Serializer<HelloWorld>> for HelloWorld {}
+    ///
+    /// // Note: the dummy serializer doesn't actually serialize/deserialize any content. Check the features for proper ones!
+    /// let path: PathBuf = std::env::temp_dir().join("test_locked.txt");
+    /// HelloWorld { hello: "Hello".into(), world: "World".into() }.to_path_pretty_locked(&path, None).unwrap();
+    /// assert_eq!(std::fs::read_to_string(path).unwrap(), "Dummy Text");
+    /// ```
+    #[inline]
+    fn to_path_pretty_locked(&self, path: impl AsRef<Path>, timeout: Option<Duration>) -> Result<(), Error<T::Error>> {
+        self.to_path_pretty_opt_locked(path, true, timeout)
+    }
+    /// Convenience function for serializing this object to a file while
+    /// holding an exclusive lock on it, using dynamic prettyness.
+    ///
+    /// See [`Self::to_path_locked()`](Serializable::to_path_locked()) for how
+    /// the file is locked.
+    ///
+    /// # Arguments
+    /// - `path`: The path to serialize this object to.
+    /// - `pretty`: Whether to use the pretty formatter or not.
+    /// - `timeout`: The maximum time to wait for others to release the file,
+    ///   or [`None`] to wait indefinitely.
+    ///
+    /// # Errors
+    /// This function may error with an [`Error::FileCreate`] if it failed to
+    /// open (or truncate) the file, an [`Error::FileLock`] if it failed to
+    /// lock it (or timed out), or an [`Error::SerializeFile`] if the backend
+    /// serializer failed to serialize. This may also be because it failed to
+    /// write to the file.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use std::path::PathBuf;
+    /// # use std::time::Duration;
+    /// use serializable::dummy::Serializer;
+    /// use serializable::Serializable;
+    ///
+    /// #[derive(Default)]
+    /// struct HelloWorld {
+    ///     hello: String,
+    ///     world: String,
+    /// }
+    /// impl Serializable<Serializer<HelloWorld>> for HelloWorld {}
+    ///
+    /// // Note: the dummy serializer doesn't actually serialize/deserialize any content. Check the features for proper ones!
+    /// let path: PathBuf = std::env::temp_dir().join("test_locked.txt");
+    /// HelloWorld { hello: "Hello".into(), world: "World".into() }.to_path_pretty_opt_locked(&path, true, None).unwrap();
+    /// assert_eq!(std::fs::read_to_string(path).unwrap(), "Dummy Text");
+    /// ```
+    #[inline]
+    fn to_path_pretty_opt_locked(&self, path: impl AsRef<Path>, pretty: bool, timeout: Option<Duration>) -> Result<(), Error<T::Error>> {
+        // Open the file without truncating it, as others may still be reading it
+        let path: &Path = path.as_ref();
+        let handle: File = match OpenOptions::new().write(true).create(true).truncate(false).open(path) {
+            Ok(handle) => handle,
+            Err(err) => {
+                return Err(Error::FileCreate { path: path.into(), err });
+            },
+        };
+
+        // Only once we have it to ourselves, we clear it
+        lock_file(&handle, path, false, timeout)?;
+        if let Err(err) = handle.set_len(0) {
+            return Err(Error::FileCreate { path: path.into(), err });
+        }
+
+        // Pass to the writer impl, then remove any checksum files (while we still hold the lock)
+        write_compressed(&handle, path, Compression::from_path(path), |w| self.to_writer_pretty_opt(w, pretty))?;
+        write_checksum(path, None, false)
+    }
+
+    /// Convenience function for deserializing this object from a file while
+    /// holding a shared lock on it.
+    ///
+    /// See [`Self::to_path_locked()`](Serializable::to_path_locked()) for the
+    /// writing counterpart. Any number of readers can hold the lock at the
+    /// same time, but not while a writer does.
+    ///
+    /// # Arguments
+    /// - `path`: The path to deserialize this object from.
+    /// - `timeout`: The maximum time to wait for writers to release the file,
+    ///   or [`None`] to wait indefinitely.
+    ///
+    /// # Returns
+    /// A new Self that represents the deserialized object.
+    ///
+    /// # Errors
+    /// This function may error with an [`Error::FileOpen`] if it failed to
+    /// open the file, an [`Error::FileLock`] if it failed to lock it (or
+    /// timed out), or an [`Error::DeserializeFile`] if the backend serializer
+    /// failed to deserialize. This may also be because it failed to read from
+    /// the file.
+    ///
+    /// If the file has a checksum file (see [`WriteOptions::checksum`]), then
+    /// it is verified before it's deserialized, and this function errors with
+    /// an [`Error::ChecksumMismatch`] if it doesn't match.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use std::fs::File;
+    /// # use std::path::PathBuf;
+    /// # use std::time::Duration;
+    /// use serializable::dummy::Serializer;
+    /// use serializable::{Error, Serializable};
+    ///
+    /// #[derive(Debug, Default, Eq, PartialEq)]
+    /// struct HelloWorld {
+    ///     hello: String,
+    ///     world: String,
+    /// }
+    /// impl Serializable<Serializer<HelloWorld>> for HelloWorld {}
+    ///
+    /// // Note: the dummy serializer doesn't actually serialize/deserialize any content. Check the features for proper ones!
+    /// let path: PathBuf = std::env::temp_dir().join("test_locked_read.txt");
+    /// std::fs::write(&path, "<dummy_text>").unwrap();
+    /// assert_eq!(HelloWorld::from_path_locked(&path, None).unwrap(), HelloWorld::default());
+    ///
+    /// // While someone else is writing, we can't read
+    /// let writer = File::options().write(true).open(&path).unwrap();
+    /// writer.lock().unwrap();
+    /// assert!(matches!(HelloWorld::from_path_locked(&path, Some(Duration::from_millis(10))), Err(Error::FileLock { .. })));
+    /// ```
+    #[inline]
+    fn from_path_locked(path: impl AsRef<Path>, timeout: Option<Duration>) -> Result<Self, Error<T::Error>>
+    where
+        Self: Sized,
+    {
+        // Open the file as a reader
+        let path: &Path = path.as_ref();
+        let handle: File = match File::open(path) {
+            Ok(handle) => handle,
+            Err(err) => {
+                return Err(Error::FileOpen { path: path.into(), err });
+            },
+        };
+        lock_file(&handle, path, true, timeout)?;
+
+        // Verify the file if it has a checksum, then pass to the reader impl (decompressing if needed)
+        let res: Result<Self, Error<T::Error>> = match read_verified(&handle, path)? {
+            Some(raw) => read_decoded(raw.as_slice(), path, |dec| Self::from_reader(dec))?,
+            None => read_decoded(&handle, path, |dec| Self::from_reader(dec))?,
+        };
+        match res {
+            Ok(res) => Ok(res),
+            Err(Error::DeserializeReader { what, ctx, err }) => {
+                // Read the file again (while still locked) to find the offending line
+                let raw: Option<String> = read_raw(path);
+                let loc: Option<Location> = T::location(&err, raw.as_deref()).or(ctx.loc);
+                Err(Error::DeserializeFile { what, path: path.into(), ctx: context(ctx.field_path, loc, raw.as_deref()), err })
+            },
+            Err(err) => Err(err),
+        }
+    }
+
+
+    // Signing
+    /// Convenience function for serializing this object to a file, and
+    /// signing it with the given Ed25519 key.
+    ///
+    /// The object is serialized in the backend's canonical form (see
+    /// [`Serializer::to_bytes_canonical()`]), and those bytes are both written
+    /// and signed. For the JSON backend, that's canonical JSON (regardless of
+    /// its [`Options`](crate::json::Options)), so semantically equal objects
+    /// get the same signature. Backends without a canonical form serialize
+    /// like [`Self::to_bytes()`](Serializable::to_bytes()) (i.e., not
+    /// pretty), in which case serializing the same object with another
+    /// version of the backend may change the signature.
+    ///
+    /// The signature is written as hexadecimal to a file next to it (see
+    /// [`signature::path()`](crate::signature::path())), so the file itself
+    /// stays readable by anyone. Because the signature covers the serialized
+    /// bytes, the file may be compressed (if its extension says so) without
+    /// affecting it. Both files are written atomically (see
+    /// [`Self::to_path_atomic()`](Serializable::to_path_atomic())), the file
+    /// before its signature file. Any change to the (decompressed) file
+    /// invalidates the signature, even if it doesn't change the object.
+    ///
+    /// Use [`Self::from_path_verified()`](Serializable::from_path_verified())
+    /// to read it back.
+    ///
+    /// # Arguments
+    /// - `path`: The path to serialize this object to.
+    /// - `key`: The [`SigningKey`](crate::signature::SigningKey) to sign with.
+    ///
+    /// # Errors
+    /// This function may error with an [`Error::FileCreate`] if it failed to
+    /// create a new file, an [`Error::SerializeFile`] if the backend
+    /// serializer failed to serialize, an [`Error::FileCompress`] if it
+    /// failed to compress the output, an [`Error::FileWrite`] if it failed
+    /// to write the file or its signature file, or an [`Error::FileSync`],
+    /// [`Error::FileRename`] or [`Error::DirSync`] if it failed to move
+    /// either into place.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use std::path::PathBuf;
+    /// use serializable::dummy::Serializer;
+    /// use serializable::signature::{self, SigningKey};
+    /// use serializable::Serializable;
+    ///
+    /// #[derive(Default)]
+    /// struct HelloWorld {
+    ///     hello: String,
+    ///     world: String,
+    /// }
+    /// impl Serializable<Serializer<HelloWorld>> for HelloWorld {}
+    ///
+    /// // Note: the dummy serializer doesn't actually serialize/deserialize any content. Check the features for proper ones!
+    /// let key = SigningKey::from_bytes(&[42; 32]);
+    /// let path: PathBuf = std::env::temp_dir().join("test_signed.txt");
+    /// HelloWorld { hello: "Hello".into(), world: "World".into() }.to_path_signed(&path, &key).unwrap();
+    /// assert_eq!(std::fs::read_to_string(&path).unwrap(), "<dummy_text>");
+    /// assert_eq!(std::fs::read_to_string(signature::path(&path)).unwrap().trim().len(), 128);
+    /// ```
+    ///
+    /// With a backend that has a canonical form, semantically equal objects
+    /// get the same signature:
+    /// ```rust
+    /// # #[cfg(feature = "serde-json")] {
+    /// use std::collections::HashMap;
+    /// use std::path::PathBuf;
+    ///
+    /// use serde::{Deserialize, Serialize};
+    /// use serializable::json::Serializer;
+    /// use serializable::signature::{self, SigningKey};
+    /// use serializable::Serializable;
+    ///
+    /// #[derive(Debug, Deserialize, Eq, PartialEq, Serialize)]
+    /// struct Policy(HashMap<String, bool>);
+    /// impl Serializable<Serializer<Policy>> for Policy {}
+    ///
+    /// let key = SigningKey::from_bytes(&[42; 32]);
+    /// let path: PathBuf = std::env::temp_dir().join("test_signed_canonical.json");
+    /// let mut sigs: Vec<String> = Vec::new();
+    /// for rules in [[("write", false), ("read", true)], [("read", true), ("write", false)]] {
+    ///     let policy = Policy(rules.into_iter().map(|(k, v)| (k.to_string(), v)).collect());
+    ///     policy.to_path_signed(&path, &key).unwrap();
+    ///     assert_eq!(std::fs::read_to_string(&path).unwrap(), "{\"read\":true,\"write\":false}");
+    ///     assert_eq!(Policy::from_path_verified(&path, &[key.verifying_key()]).unwrap(), policy);
+    ///     sigs.push(std::fs::read_to_string(signature::path(&path)).unwrap());
+    /// }
+    /// assert_eq!(sigs[0], sigs[1]);
+    /// # }
+    /// ```
+    #[cfg(feature = "sign-ed25519")]
+    #[inline]
+    fn to_path_signed(&self, path: impl AsRef<Path>, key: &crate::signature::SigningKey) -> Result<(), Error<T::Error>> {
+        // Serialize to memory first (in the backend's canonical form), as that's what we sign
+        let path: &Path = path.as_ref();
+        let raw: Vec<u8> = match T::to_bytes_canonical(self) {
+            Ok(raw) => raw,
+            Err(err) => return Err(Error::SerializeFile { what: type_name::<T::Target>(), path: path.into(), err }),
+        };
+
+        // Write the file atomically (compressing if the extension says so)
+        write_atomic(path, 0, |handle| {
+            write_compressed(handle, path, Compression::from_path(path), |w| w.write_all(&raw).map_err(|err| Error::FileWrite { path: path.into(), err }))
+        })?;
+
+        // Only then replace its signature file (atomically too, so it's never torn)
+        let sig_path: PathBuf = crate::signature::path(path);
+        let sig: String = format!("{}\n", crate::signature::sign(key, &raw));
+        write_atomic(&sig_path, 0, |handle| handle.write_all(sig.as_bytes()).map_err(|err| Error::FileWrite { path: sig_path.clone(), err }))?;
+
+        // Remove any checksum files, as they no longer match
+        write_checksum(path, None, false)
+    }
+
+    /// Convenience function for deserializing this object from a file, after
+    /// verifying that it's signed by one of the given Ed25519 keys.
+    ///
+    /// The file's signature is read from the file next to it (see
+    /// [`signature::path()`](crate::signature::path())), as written by
+    /// [`Self::to_path_signed()`](Serializable::to_path_signed()). The file
+    /// is only deserialized if the signature is valid for any of the trusted
+    /// keys.
+    ///
+    /// # Arguments
+    /// - `path`: The path to deserialize this object from.
+    /// - `trusted`: The [`VerifyingKey`](crate::signature::VerifyingKey)s of
+    ///   the signers to trust.
     ///
     /// # Returns
-    /// A string representing this object.
+    /// A new Self that represents the deserialized object.
     ///
     /// # Errors
-    /// This function may error with an [`Error::SerializeString`] if the
-    /// backend serializer failed to serialize.
+    /// This function may error with an [`Error::FileOpen`] if it failed to
+    /// open the file, an [`Error::FileRead`] if it failed to read it or its
+    /// signature file (e.g., because it isn't signed), an
+    /// [`Error::FileDecompress`] if it failed to decompress it, or an
+    /// [`Error::DeserializeFile`] if the backend serializer failed to
+    /// deserialize.
+    ///
+    /// If the signature file doesn't contain a signature, this function
+    /// errors with an [`Error::SignatureMalformed`]. If the file isn't signed
+    /// by any of the `trusted` keys (e.g., because it's been modified), it
+    /// errors with an [`Error::SignatureUntrusted`]. Like
+    /// [`Self::from_path()`](Serializable::from_path()), it may also error
+    /// with an [`Error::ChecksumMismatch`].
     ///
     /// # Examples
     /// ```rust
+    /// # use std::path::PathBuf;
     /// use serializable::dummy::Serializer;
-    /// use serializable::Serializable;
+    /// use serializable::signature::SigningKey;
+    /// use serializable::{Error, Serializable};
     ///
-    /// #[derive(Default)]
+    /// #[derive(Debug, Default, Eq, PartialEq)]
     /// struct HelloWorld {
     ///     hello: String,
     ///     world: String,
@@ -150,53 +3001,318 @@ pub trait Serializable<T: Serializer<Target = Self>> {
     /// impl Serializable<Serializer<HelloWorld>> for HelloWorld {}
     ///
     /// // Note: the dummy serializer doesn't actually serialize/deserialize any content. Check the features for proper ones!
-    /// assert_eq!(HelloWorld { hello: "Hello".into(), world: "World".into() }.to_string().unwrap(), "<dummy_text>");
+    /// let key = SigningKey::from_bytes(&[42; 32]);
+    /// let path: PathBuf = std::env::temp_dir().join("test_verified.txt");
+    /// HelloWorld::default().to_path_signed(&path, &key).unwrap();
+    /// assert_eq!(HelloWorld::from_path_verified(&path, &[key.verifying_key()]).unwrap(), HelloWorld::default());
+    ///
+    /// // Files signed by someone else (or modified afterwards) are rejected
+    /// let other = SigningKey::from_bytes(&[7; 32]);
+    /// assert!(matches!(HelloWorld::from_path_verified(&path, &[other.verifying_key()]), Err(Error::SignatureUntrusted { .. })));
+    /// std::fs::write(&path, "<dummy_text>\n").unwrap();
+    /// assert!(matches!(HelloWorld::from_path_verified(&path, &[key.verifying_key()]), Err(Error::SignatureUntrusted { .. })));
     /// ```
+    #[cfg(feature = "sign-ed25519")]
     #[inline]
-    fn to_string(&self) -> Result<String, Error<T::Error>> {
-        match T::to_string(self) {
-            Ok(res) => Ok(res),
-            Err(err) => Err(Error::SerializeString { what: type_name::<T::Target>(), err }),
-        }
+    fn from_path_verified(path: impl AsRef<Path>, trusted: &[crate::signature::VerifyingKey]) -> Result<Self, Error<T::Error>>
+    where
+        Self: Sized,
+    {
+        // Open the file as a reader
+        let path: &Path = path.as_ref();
+        let handle: File = match File::open(path) {
+            Ok(handle) => handle,
+            Err(err) => {
+                return Err(Error::FileOpen { path: path.into(), err });
+            },
+        };
+
+        // Read it (decompressing if needed) and verify it before deserializing anything
+        let raw: Vec<u8> = read_decompressed(handle, path)?;
+        let sig_path: PathBuf = crate::signature::path(path);
+        let sig: String = match std::fs::read_to_string(&sig_path) {
+            Ok(sig) => sig,
+            Err(err) => return Err(Error::FileRead { path: sig_path, err }),
+        };
+        check_signature(path, &sig, trusted, &raw)?;
+        from_verified(&raw, path)
     }
 
-    /// Serializes this object to a string, using a pretty backend if it's
-    /// available.
+
+    // Hashing
+    /// Computes the SHA-256 hash of this object's canonical JSON.
     ///
-    /// If not, then this is equivalent to calling
-    /// [`Self::to_string()`](Serializable::to_string()).
+    /// The object is serialized as canonical JSON (see
+    /// [`json::Options::CANONICAL`](crate::json::Options::CANONICAL)) regardless
+    /// of the backend of this `Serializable`, so two semantically equal
+    /// objects always have the same hash (e.g., regardless of the iteration
+    /// order of a `HashMap`).
     ///
     /// # Returns
-    /// A string representing this object.
+    /// The hash, in lowercase hexadecimal.
     ///
     /// # Errors
-    /// This function may error with an [`Error::SerializeString`] if the
-    /// backend serializer failed to serialize.
+    /// This function may error with an [`Error::SerializeBytes`] if the
+    /// object failed to serialize to JSON.
     ///
     /// # Examples
     /// ```rust
-    /// use serializable::dummy::Serializer;
+    /// use std::collections::HashMap;
+    ///
+    /// use serde::{Deserialize, Serialize};
+    /// use serializable::json::Serializer;
     /// use serializable::Serializable;
     ///
-    /// #[derive(Default)]
+    /// #[derive(Debug, Deserialize, Eq, PartialEq, Serialize)]
     /// struct HelloWorld {
     ///     hello: String,
     ///     world: String,
     /// }
     /// impl Serializable<Serializer<HelloWorld>> for HelloWorld {}
     ///
-    /// // Note: the dummy serializer doesn't actually serialize/deserialize any content. Check the features for proper ones!
-    /// assert_eq!(HelloWorld { hello: "Hello".into(), world: "World".into() }.to_string_pretty().unwrap(), "Dummy Text");
+    /// #[derive(Debug, Deserialize, Eq, PartialEq, Serialize)]
+    /// struct Greetings(HashMap<String, String>);
+    /// impl Serializable<Serializer<Greetings>> for Greetings {}
+    ///
+    /// let hello_world = HelloWorld { hello: "Hello".into(), world: "World".into() };
+    /// assert_eq!(hello_world.canonical_hash().unwrap(), "a38fe707b61b1f5dac9a8069e8629dd57e1956e7d349a79c7fce356e743d9fe7");
+    ///
+    /// let greetings = Greetings(HashMap::from([("world".into(), "World".into()), ("hello".into(), "Hello".into())]));
+    /// assert_eq!(greetings.canonical_hash().unwrap(), hello_world.canonical_hash().unwrap());
     /// ```
+    #[cfg(feature = "hash-sha256")]
     #[inline]
-    fn to_string_pretty(&self) -> Result<String, Error<T::Error>> {
-        match T::to_string_pretty(self) {
-            Ok(res) => Ok(res),
-            Err(err) => Err(Error::SerializeString { what: type_name::<T::Target>(), err }),
-        }
+    fn canonical_hash(&self) -> Result<String, Error<crate::json::Error>>
+    where
+        Self: serde::Serialize,
+    {
+        use sha2::Digest as _;
+
+        let raw: String = match crate::json::to_canonical_string(self) {
+            Ok(raw) => raw,
+            Err(err) => return Err(Error::SerializeBytes { what: type_name::<Self>(), err }),
+        };
+        Ok(sha2::Sha256::digest(raw.as_bytes()).iter().map(|b| format!("{b:02x}")).collect())
     }
 
-    /// Serializes this object to the given writer.
+}
+
+
+
+/// Provides the functions of [`Serializable`] with an explicitly chosen [`Backend`].
+///
+/// This is useful for types that implement [`Serializable`] for multiple backends, because calling,
+/// e.g., [`Serializable::to_string()`] on those fails type inference. Instead of having to write
+/// `<HelloWorld as Serializable<json::Serializer<HelloWorld>>>::to_string(&value)`, you can write
+/// `value.to_string_as::<Json>()`.
+///
+/// It is automatically implemented for all types.
+///
+/// # Examples
+/// ```rust
+/// use serializable::dummy::{Dummy, Serializer};
+/// use serializable::{Serializable, SerializableAs as _};
+///
+/// #[derive(Debug, Default, Eq, PartialEq)]
+/// struct HelloWorld {
+///     hello: String,
+///     world: String,
+/// }
+/// impl Serializable<Serializer<HelloWorld>> for HelloWorld {}
+///
+/// // Note: the dummy serializer doesn't actually serialize/deserialize any content. Check the features for proper ones!
+/// assert_eq!(HelloWorld { hello: "Hello".into(), world: "World".into() }.to_string_as::<Dummy>().unwrap(), "<dummy_text>");
+/// assert_eq!(HelloWorld { hello: "Hello".into(), world: "World".into() }.to_string_pretty_as::<Dummy>().unwrap(), "Dummy Text");
+/// assert_eq!(HelloWorld::from_str_as::<Dummy>("<dummy_text>").unwrap(), HelloWorld::default());
+/// ```
+pub trait SerializableAs: Sized {
+    /// Serializes this object to a string using the given backend.
+    ///
+    /// See [`Serializable::to_string()`] for more information.
+    #[inline]
+    fn to_string_as<B: Backend>(&self) -> Result<String, Error<<B::Serializer<Self> as Serializer>::Error>>
+    where
+        B::Serializer<Self>: Serializer<Target = Self>,
+        Self: Serializable<B::Serializer<Self>>,
+    {
+        <Self as Serializable<B::Serializer<Self>>>::to_string(self)
+    }
+
+    /// Serializes this object to a string using the given backend's pretty serialization.
+    ///
+    /// See [`Serializable::to_string_pretty()`] for more information.
+    #[inline]
+    fn to_string_pretty_as<B: Backend>(&self) -> Result<String, Error<<B::Serializer<Self> as Serializer>::Error>>
+    where
+        B::Serializer<Self>: Serializer<Target = Self>,
+        Self: Serializable<B::Serializer<Self>>,
+    {
+        <Self as Serializable<B::Serializer<Self>>>::to_string_pretty(self)
+    }
+
+    /// Serializes this object to a byte buffer using the given backend.
+    ///
+    /// See [`Serializable::to_bytes()`] for more information.
+    #[inline]
+    fn to_bytes_as<B: Backend>(&self) -> Result<Vec<u8>, Error<<B::Serializer<Self> as Serializer>::Error>>
+    where
+        B::Serializer<Self>: Serializer<Target = Self>,
+        Self: Serializable<B::Serializer<Self>>,
+    {
+        <Self as Serializable<B::Serializer<Self>>>::to_bytes(self)
+    }
+
+    /// Serializes this object to a byte buffer using the given backend's pretty serialization.
+    ///
+    /// See [`Serializable::to_bytes_pretty()`] for more information.
+    #[inline]
+    fn to_bytes_pretty_as<B: Backend>(&self) -> Result<Vec<u8>, Error<<B::Serializer<Self> as Serializer>::Error>>
+    where
+        B::Serializer<Self>: Serializer<Target = Self>,
+        Self: Serializable<B::Serializer<Self>>,
+    {
+        <Self as Serializable<B::Serializer<Self>>>::to_bytes_pretty(self)
+    }
+
+    /// Serializes this object to the given writer using the given backend.
+    ///
+    /// See [`Serializable::to_writer()`] for more information.
+    #[inline]
+    fn to_writer_as<B: Backend>(&self, writer: impl Write) -> Result<(), Error<<B::Serializer<Self> as Serializer>::Error>>
+    where
+        B::Serializer<Self>: Serializer<Target = Self>,
+        Self: Serializable<B::Serializer<Self>>,
+    {
+        <Self as Serializable<B::Serializer<Self>>>::to_writer(self, writer)
+    }
+
+    /// Serializes this object to the given writer using the given backend's pretty serialization.
+    ///
+    /// See [`Serializable::to_writer_pretty()`] for more information.
+    #[inline]
+    fn to_writer_pretty_as<B: Backend>(&self, writer: impl Write) -> Result<(), Error<<B::Serializer<Self> as Serializer>::Error>>
+    where
+        B::Serializer<Self>: Serializer<Target = Self>,
+        Self: Serializable<B::Serializer<Self>>,
+    {
+        <Self as Serializable<B::Serializer<Self>>>::to_writer_pretty(self, writer)
+    }
+
+    /// Serializes this object to a file using the given backend.
+    ///
+    /// See [`Serializable::to_path()`] for more information.
+    #[inline]
+    fn to_path_as<B: Backend>(&self, path: impl AsRef<Path>) -> Result<(), Error<<B::Serializer<Self> as Serializer>::Error>>
+    where
+        B::Serializer<Self>: Serializer<Target = Self>,
+        Self: Serializable<B::Serializer<Self>>,
+    {
+        <Self as Serializable<B::Serializer<Self>>>::to_path(self, path)
+    }
+
+    /// Serializes this object to a file using the given backend's pretty serialization.
+    ///
+    /// See [`Serializable::to_path_pretty()`] for more information.
+    #[inline]
+    fn to_path_pretty_as<B: Backend>(&self, path: impl AsRef<Path>) -> Result<(), Error<<B::Serializer<Self> as Serializer>::Error>>
+    where
+        B::Serializer<Self>: Serializer<Target = Self>,
+        Self: Serializable<B::Serializer<Self>>,
+    {
+        <Self as Serializable<B::Serializer<Self>>>::to_path_pretty(self, path)
+    }
+
+    /// Deserializes this object from the given string using the given backend.
+    ///
+    /// See [`Serializable::from_str()`] for more information.
+    #[inline]
+    fn from_str_as<B: Backend>(raw: impl AsRef<str>) -> Result<Self, Error<<B::Serializer<Self> as Serializer>::Error>>
+    where
+        B::Serializer<Self>: Serializer<Target = Self>,
+        Self: Serializable<B::Serializer<Self>>,
+    {
+        <Self as Serializable<B::Serializer<Self>>>::from_str(raw)
+    }
+
+    /// Deserializes this object from the given byte buffer using the given backend.
+    ///
+    /// See [`Serializable::from_bytes()`] for more information.
+    #[inline]
+    fn from_bytes_as<B: Backend>(raw: impl AsRef<[u8]>) -> Result<Self, Error<<B::Serializer<Self> as Serializer>::Error>>
+    where
+        B::Serializer<Self>: Serializer<Target = Self>,
+        Self: Serializable<B::Serializer<Self>>,
+    {
+        <Self as Serializable<B::Serializer<Self>>>::from_bytes(raw)
+    }
+
+    /// Deserializes this object from the given reader using the given backend.
+    ///
+    /// See [`Serializable::from_reader()`] for more information.
+    #[inline]
+    fn from_reader_as<B: Backend>(reader: impl Read) -> Result<Self, Error<<B::Serializer<Self> as Serializer>::Error>>
+    where
+        B::Serializer<Self>: Serializer<Target = Self>,
+        Self: Serializable<B::Serializer<Self>>,
+    {
+        <Self as Serializable<B::Serializer<Self>>>::from_reader(reader)
+    }
+
+    /// Deserializes this object from a file using the given backend.
+    ///
+    /// See [`Serializable::from_path()`] for more information.
+    #[inline]
+    fn from_path_as<B: Backend>(path: impl AsRef<Path>) -> Result<Self, Error<<B::Serializer<Self> as Serializer>::Error>>
+    where
+        B::Serializer<Self>: Serializer<Target = Self>,
+        Self: Serializable<B::Serializer<Self>>,
+    {
+        <Self as Serializable<B::Serializer<Self>>>::from_path(path)
+    }
+}
+impl<T> SerializableAs for T {}
+
+
+
+/// Implements functions serialize- or deserialize a struct asynchronously.
+///
+/// This is only defined for serializing to- and from writers and readers (including files).
+///
+/// Note that not all backends have an optimal asynchronous implementation. [`serde`](https://serde.rs)-related backends, for example,
+/// fallback to reading the entire file or reader asynchronously before parsing it as a string.
+///
+/// # Examples
+/// ```rust
+/// use serializable::dummy::Serializer;
+/// use serializable::{Serializable, SerializableAsync as _};
+///
+/// #[derive(Debug, Default, Eq, PartialEq)]
+/// struct HelloWorld {
+///     hello: String,
+///     world: String,
+/// }
+/// impl Serializable<Serializer<HelloWorld>> for HelloWorld {}
+///
+/// # tokio_test::block_on(async {
+/// // Note: the dummy serializer doesn't actually serialize/deserialize any content. Check the features for proper ones!
+/// let mut buf: Vec<u8> = vec![];
+/// HelloWorld { hello: "Hello".into(), world: "World".into() }.to_writer_async(&mut buf).await.unwrap();
+/// assert_eq!(
+///     String::from_utf8_lossy(&buf),
+///     "<dummy_text>"
+/// );
+///
+/// let mut buf: Vec<u8> = (*b"<dummy_text>").into();
+/// assert_eq!(HelloWorld::from_reader_async(&buf[..]).await.unwrap(), HelloWorld::default());
+/// # });
+/// ```
+#[cfg(feature = "async-tokio")]
+#[async_trait::async_trait]
+pub trait SerializableAsync<T: Send + Sync + Serializer<Target = Self> + crate::serializer::SerializerAsync>: Serializable<T>
+where
+    T::Target: Send + Sync,
+{
+    // Serializes this object to the given writer asynchronously.
     ///
     /// # Arguments
     /// - `writer`: A [`Write`]r that we will serialize to.
@@ -209,7 +3325,7 @@ pub trait Serializable<T: Serializer<Target = Self>> {
     /// # Examples
     /// ```rust
     /// use serializable::dummy::Serializer;
-    /// use serializable::Serializable;
+    /// use serializable::{Serializable, SerializableAsync as _};
     ///
     /// #[derive(Default)]
     /// struct HelloWorld {
@@ -218,27 +3334,28 @@ pub trait Serializable<T: Serializer<Target = Self>> {
     /// }
     /// impl Serializable<Serializer<HelloWorld>> for HelloWorld {}
     ///
+    /// # tokio_test::block_on(async {
     /// // Note: the dummy serializer doesn't actually serialize/deserialize any content. Check the features for proper ones!
-    /// let mut buf: [u8; 12] = [0; 12];
-    /// HelloWorld { hello: "Hello".into(), world: "World".into() }.to_writer(&mut buf[..]).unwrap();
+    /// let mut buf: Vec<u8> = Vec::new();
+    /// HelloWorld { hello: "Hello".into(), world: "World".into() }.to_writer_async(&mut buf).await.unwrap();
     /// assert_eq!(String::from_utf8_lossy(&buf), "<dummy_text>");
-    ///
-    /// let mut buf: [u8; 0] = [];
-    /// assert!(matches!(HelloWorld { hello: "Hello".into(), world: "World".into() }.to_writer(&mut buf[..]), Err(serializable::Error::SerializeWriter { .. })));
+    /// # });
     /// ```
     #[inline]
-    fn to_writer(&self, writer: impl Write) -> Result<(), Error<T::Error>> {
-        match T::to_writer(self, writer) {
+    async fn to_writer_async(&self, writer: impl Send + std::marker::Unpin + tokio::io::AsyncWrite) -> Result<(), Error<T::Error>> {
+        match T::to_writer_async(self, writer).await {
             Ok(_) => Ok(()),
             Err(err) => Err(Error::SerializeWriter { what: type_name::<T::Target>(), err }),
         }
     }
 
-    /// Serializes this object to the given writer, using a pretty backend if
+    /// Serializes this object to the given writer asynchronously, using a pretty backend if
     /// it's available.
     ///
     /// If not, then this is equivalent to calling
-    /// [`Self::to_writer()`](Serializable::to_writer()).
+    /// [`Self::to_writer()`](Serializable::to_writer()). The backend honours
+    /// [`Self::PRETTY_OPTIONS`](Serializable::PRETTY_OPTIONS) as far as it
+    /// can.
     ///
     /// # Arguments
     /// - `writer`: A [`Write`]r that we will serialize to.
@@ -251,7 +3368,7 @@ pub trait Serializable<T: Serializer<Target = Self>> {
     /// # Examples
     /// ```rust
     /// use serializable::dummy::Serializer;
-    /// use serializable::Serializable;
+    /// use serializable::{Serializable, SerializableAsync as _};
     ///
     /// #[derive(Default)]
     /// struct HelloWorld {
@@ -260,63 +3377,65 @@ pub trait Serializable<T: Serializer<Target = Self>> {
     /// }
     /// impl Serializable<Serializer<HelloWorld>> for HelloWorld {}
     ///
+    /// # tokio_test::block_on(async {
     /// // Note: the dummy serializer doesn't actually serialize/deserialize any content. Check the features for proper ones!
-    /// let mut buf: [u8; 10] = [0; 10];
-    /// HelloWorld { hello: "Hello".into(), world: "World".into() }.to_writer_pretty(&mut buf[..]).unwrap();
-    /// assert_eq!(String::from_utf8_lossy(&buf), "Dummy Text");
-    ///
-    /// let mut buf: [u8; 0] = [];
-    /// assert!(matches!(HelloWorld { hello: "Hello".into(), world: "World".into() }.to_writer_pretty(&mut buf[..]), Err(serializable::Error::SerializeWriter { .. })));
+    /// let mut buf: Vec<u8> = Vec::new();
+    /// HelloWorld { hello: "Hello".into(), world: "World".into() }.to_writer_pretty_async(&mut buf).await.unwrap();
+    /// assert_eq!(String::from_utf8_lossy(&buf), "Dummy Text");
+    /// # });
     /// ```
     #[inline]
-    fn to_writer_pretty(&self, writer: impl Write) -> Result<(), Error<T::Error>> {
-        match T::to_writer_pretty(self, writer) {
-            Ok(_) => Ok(()),
-            Err(err) => Err(Error::SerializeWriter { what: type_name::<T::Target>(), err }),
-        }
+    async fn to_writer_pretty_async(&self, writer: impl Send + std::marker::Unpin + tokio::io::AsyncWrite) -> Result<(), Error<T::Error>> {
+        self.to_writer_pretty_with_async(&<Self as Serializable<T>>::PRETTY_OPTIONS, writer).await
     }
 
-    /// Deserializes this object from the given string.
+    /// Serializes this object to the given writer asynchronously, using a
+    /// pretty backend if it's available and honouring the given
+    /// [`PrettyOptions`] as far as it can.
     ///
     /// # Arguments
-    /// - `raw`: The raw string that provides a serialized instantiation of
-    ///   Self.
-    ///
-    /// # Returns
-    /// A new Self that represents the deserialized counterpart.
+    /// - `opts`: The [`PrettyOptions`] to use instead of
+    ///   [`Serializable::PRETTY_OPTIONS`].
+    /// - `writer`: A [`Write`]r that we will serialize to.
     ///
     /// # Errors
-    /// This function may error with an [`Error::DeserializeString`] if the
-    /// backend deserializer failed to deserialize. This may be because the
-    /// serialized representation was illegal for this type and backend.
+    /// This function may error with an [`Error::SerializeWriter`] if the
+    /// backend serializer failed to serialize. This may also be because it
+    /// failed to write to the `writer`.
     ///
     /// # Examples
     /// ```rust
     /// use serializable::dummy::Serializer;
-    /// use serializable::Serializable;
+    /// use serializable::{PrettyOptions, Serializable, SerializableAsync as _};
     ///
-    /// #[derive(Debug, Default, Eq, PartialEq)]
+    /// #[derive(Default)]
     /// struct HelloWorld {
     ///     hello: String,
     ///     world: String,
     /// }
     /// impl Serializable<Serializer<HelloWorld>> for HelloWorld {}
     ///
+    /// # tokio_test::block_on(async {
     /// // Note: the dummy serializer doesn't actually serialize/deserialize any content. Check the features for proper ones!
-    /// assert_eq!(HelloWorld::from_str("<dummy_text>").unwrap(), HelloWorld::default());
+    /// let opts = PrettyOptions { sort_keys: true, ..Default::default() };
+    /// let mut buf: Vec<u8> = Vec::new();
+    /// HelloWorld { hello: "Hello".into(), world: "World".into() }.to_writer_pretty_with_async(&opts, &mut buf).await.unwrap();
+    /// assert_eq!(String::from_utf8_lossy(&buf), "Dummy Text");
+    /// # });
     /// ```
     #[inline]
-    fn from_str(raw: impl AsRef<str>) -> Result<Self, Error<T::Error>>
-    where
-        Self: Sized,
-    {
-        match T::from_str(raw) {
-            Ok(res) => Ok(res),
-            Err(err) => Err(Error::DeserializeString { what: type_name::<T::Target>(), err }),
+    async fn to_writer_pretty_with_async(
+        &self,
+        opts: &PrettyOptions,
+        writer: impl Send + std::marker::Unpin + tokio::io::AsyncWrite,
+    ) -> Result<(), Error<T::Error>> {
+        match T::to_writer_pretty_with_async(self, opts, writer).await {
+            Ok(_) => Ok(()),
+            Err(err) => Err(Error::SerializeWriter { what: type_name::<T::Target>(), err }),
         }
     }
 
-    /// Deserializes this object from the given reader.
+    /// Deserializes this object from the given reader asynchronously.
     ///
     /// # Arguments
     /// - `reader`: The [`Read`]er that provides a serialized instantiation of
@@ -326,7 +3445,7 @@ pub trait Serializable<T: Serializer<Target = Self>> {
     /// A new Self that represents the deserialized counterpart.
     ///
     /// # Errors
-    /// This function may error with an [`Error::DeserializeString`] if the
+    /// This function may error with an [`Error::DeserializeReader`] if the
     /// backend deserializer failed to deserialize. This may be because the
     /// backend failed to read from the given `reader`, or because the
     /// serialized representation was illegal for this type and backend.
@@ -334,7 +3453,7 @@ pub trait Serializable<T: Serializer<Target = Self>> {
     /// # Examples
     /// ```rust
     /// use serializable::dummy::Serializer;
-    /// use serializable::Serializable;
+    /// use serializable::{Serializable, SerializableAsync as _};
     ///
     /// #[derive(Debug, Default, Eq, PartialEq)]
     /// struct HelloWorld {
@@ -343,24 +3462,28 @@ pub trait Serializable<T: Serializer<Target = Self>> {
     /// }
     /// impl Serializable<Serializer<HelloWorld>> for HelloWorld {}
     ///
+    /// # tokio_test::block_on(async {
     /// // Note: the dummy serializer doesn't actually serialize/deserialize any content. Check the features for proper ones!
-    /// assert_eq!(HelloWorld::from_reader("<dummy_text>".as_bytes()).unwrap(), HelloWorld::default());
+    /// assert_eq!(HelloWorld::from_reader_async("<dummy_text>".as_bytes()).await.unwrap(), HelloWorld::default());
+    /// # });
     /// ```
     #[inline]
-    fn from_reader(reader: impl Read) -> Result<Self, Error<T::Error>>
+    async fn from_reader_async(reader: impl Send + std::marker::Unpin + tokio::io::AsyncRead) -> Result<Self, Error<T::Error>>
     where
         Self: Sized,
     {
-        match T::from_reader(reader) {
+        match T::from_reader_async(reader).await {
             Ok(res) => Ok(res),
-            Err(err) => Err(Error::DeserializeString { what: type_name::<T::Target>(), err }),
+            Err(err) => {
+                Err(Error::DeserializeReader { what: type_name::<T::Target>(), ctx: context(T::field_path(&err), T::location(&err, None), None), err })
+            },
         }
     }
 
 
 
     // Convenience functions
-    /// Convenience function for serializing this object to a file.
+    /// Convenience function for serializing this object to a file asynchronously.
     ///
     /// # Arguments
     /// - `path`: The path to serialize this object to.
@@ -375,7 +3498,7 @@ pub trait Serializable<T: Serializer<Target = Self>> {
     /// ```rust
     /// # use std::path::PathBuf;
     /// use serializable::dummy::Serializer;
-    /// use serializable::Serializable;
+    /// use serializable::{Serializable, SerializableAsync as _};
     ///
     /// #[derive(Default)]
     /// struct HelloWorld {
@@ -384,30 +3507,31 @@ pub trait Serializable<T: Serializer<Target = Self>> {
     /// }
     /// impl Serializable<Serializer<HelloWorld>> for HelloWorld {}
     ///
+    /// # tokio_test::block_on(async {
     /// // Note: the dummy serializer doesn't actually serialize/deserialize any content. Check the features for proper ones!
     /// let path: PathBuf = std::env::temp_dir().join("test.txt");
-    /// HelloWorld { hello: "Hello".into(), world: "World".into() }.to_path(&path).unwrap();
-    /// assert_eq!(std::fs::read_to_string(path).unwrap(), "<dummy_text>");
+    /// HelloWorld { hello: "Hello".into(), world: "World".into() }.to_path_async(&path).await.unwrap();
+    /// assert_eq!(tokio::fs::read_to_string(path).await.unwrap(), "<dummy_text>");
+    /// # });
     /// ```
     #[inline]
-    fn to_path(&self, path: impl AsRef<Path>) -> Result<(), Error<T::Error>> {
+    async fn to_path_async(&self, path: impl Send + AsRef<Path>) -> Result<(), Error<T::Error>> {
         // Open the file as a writer
         let path: &Path = path.as_ref();
-        let handle: File = match File::create(path) {
+        let handle: tokio::fs::File = match tokio::fs::File::create(path).await {
             Ok(handle) => handle,
             Err(err) => {
                 return Err(Error::FileCreate { path: path.into(), err });
             },
         };
 
-        // Pass to the writer impl
-        match self.to_writer(handle) {
-            Ok(_) => Ok(()),
-            Err(Error::SerializeWriter { what, err }) => Err(Error::SerializeFile { what, path: path.into(), err }),
-            Err(err) => Err(err),
-        }
+        // Pass to the writer impl (compressing if the extension says so)
+        write_compressed_async(self, handle, path, false, Compression::from_path(path), None).await?;
+
+        // Remove any checksum files, as they no longer match
+        write_checksum_async(path, None, false).await
     }
-    /// Convenience function for serializing this object to a file, using a pretty backend if it's available.
+    /// Convenience function for serializing this object to a file asynchronously, using a pretty backend if it's available.
     ///
     /// If not, then this is equivalent to calling
     /// [`Self::to_path()`](Serializable::to_path()).
@@ -425,7 +3549,7 @@ pub trait Serializable<T: Serializer<Target = Self>> {
     /// ```rust
     /// # use std::path::PathBuf;
     /// use serializable::dummy::Serializer;
-    /// use serializable::Serializable;
+    /// use serializable::{Serializable, SerializableAsync as _};
     ///
     /// #[derive(Default)]
     /// struct HelloWorld {
@@ -434,30 +3558,31 @@ pub trait Serializable<T: Serializer<Target = Self>> {
     /// }
     /// impl Serializable<Serializer<HelloWorld>> for HelloWorld {}
     ///
+    /// # tokio_test::block_on(async {
     /// // Note: the dummy serializer doesn't actually serialize/deserialize any content. Check the features for proper ones!
     /// let path: PathBuf = std::env::temp_dir().join("test.txt");
-    /// HelloWorld { hello: "Hello".into(), world: "World".into() }.to_path_pretty(&path).unwrap();
-    /// assert_eq!(std::fs::read_to_string(path).unwrap(), "Dummy Text");
+    /// HelloWorld { hello: "Hello".into(), world: "World".into() }.to_path_pretty_async(&path).await.unwrap();
+    /// assert_eq!(tokio::fs::read_to_string(path).await.unwrap(), "Dummy Text");
+    /// # });
     /// ```
     #[inline]
-    fn to_path_pretty(&self, path: impl AsRef<Path>) -> Result<(), Error<T::Error>> {
+    async fn to_path_pretty_async(&self, path: impl Send + AsRef<Path>) -> Result<(), Error<T::Error>> {
         // Open the file as a writer
         let path: &Path = path.as_ref();
-        let handle: File = match File::create(path) {
+        let handle: tokio::fs::File = match tokio::fs::File::create(path).await {
             Ok(handle) => handle,
             Err(err) => {
                 return Err(Error::FileCreate { path: path.into(), err });
             },
         };
 
-        // Pass to the writer impl
-        match self.to_writer_pretty(handle) {
-            Ok(_) => Ok(()),
-            Err(Error::SerializeWriter { what, err }) => Err(Error::SerializeFile { what, path: path.into(), err }),
-            Err(err) => Err(err),
-        }
+        // Pass to the writer impl (compressing if the extension says so)
+        write_compressed_async(self, handle, path, true, Compression::from_path(path), None).await?;
+
+        // Remove any checksum files, as they no longer match
+        write_checksum_async(path, None, false).await
     }
-    /// Convenience function for deserializing this object from a file.
+    /// Convenience function for deserializing this object from a file asynchronously.
     ///
     /// # Arguments
     /// - `path`: The path to deserialize this object from.
@@ -471,11 +3596,15 @@ pub trait Serializable<T: Serializer<Target = Self>> {
     /// backend serializer failed to serialize. This may also be because it
     /// failed to write to the file.
     ///
+    /// If the file has a checksum file (see [`WriteOptions::checksum`]), then
+    /// it is verified before it's deserialized, and this function errors with
+    /// an [`Error::ChecksumMismatch`] if it doesn't match.
+    ///
     /// # Examples
     /// ```rust
     /// # use std::path::PathBuf;
     /// use serializable::dummy::Serializer;
-    /// use serializable::Serializable;
+    /// use serializable::{Serializable, SerializableAsync as _};
     ///
     /// #[derive(Debug, Default, Eq, PartialEq)]
     /// struct HelloWorld {
@@ -484,50 +3613,61 @@ pub trait Serializable<T: Serializer<Target = Self>> {
     /// }
     /// impl Serializable<Serializer<HelloWorld>> for HelloWorld {}
     ///
+    /// # tokio_test::block_on(async {
     /// // Note: the dummy serializer doesn't actually serialize/deserialize any content. Check the features for proper ones!
     /// let path: PathBuf = std::env::temp_dir().join("test.txt");
-    /// std::fs::write(&path, "<dummy_text>").unwrap();
-    /// assert_eq!(HelloWorld::from_path(path).unwrap(), HelloWorld::default());
+    /// tokio::fs::write(&path, "<dummy_text>").await.unwrap();
+    /// assert_eq!(HelloWorld::from_path_async(path).await.unwrap(), HelloWorld::default());
+    /// # });
     /// ```
     #[inline]
-    fn from_path(path: impl AsRef<Path>) -> Result<Self, Error<T::Error>>
+    async fn from_path_async(path: impl Send + AsRef<Path>) -> Result<Self, Error<T::Error>>
     where
         Self: Sized,
+        T::Error: Send,
     {
         // Open the file as a writer
         let path: &Path = path.as_ref();
-        let handle: File = match File::open(path) {
+        let handle: tokio::fs::File = match tokio::fs::File::open(path).await {
             Ok(handle) => handle,
             Err(err) => {
                 return Err(Error::FileOpen { path: path.into(), err });
             },
         };
 
-        // Pass to the reader impl
-        match Self::from_reader(handle) {
+        // Read it (decompressing if needed), then pass to the reader impl
+        let raw: Vec<u8> = read_decompressed_async(handle, path).await?;
+        match Self::from_reader_async(raw.as_slice()).await {
             Ok(res) => Ok(res),
-            Err(Error::DeserializeReader { what, err }) => Err(Error::DeserializeFile { what, path: path.into(), err }),
+            Err(Error::DeserializeReader { what, ctx, err }) => {
+                // Use the input to find the offending line (and the location, for backends that need the input for that)
+                let raw: Option<&str> = std::str::from_utf8(&raw).ok();
+                let loc: Option<Location> = T::location(&err, raw).or(ctx.loc);
+                Err(Error::DeserializeFile { what, path: path.into(), ctx: context(ctx.field_path, loc, raw), err })
+            },
             Err(err) => Err(err),
         }
     }
 
-    /// Convenience function for serializing this object to a string using
+    /// Convenience function for serializing this object to a writer using
     /// dynamic prettyness.
     ///
     /// # Arguments
+    /// - `writer`: A [`Write`]r that we will serialize to.
     /// - `pretty`: Whether to use the pretty formatter or not.
     ///
-    /// # Returns
-    /// A string representing this object.
-    ///
     /// # Errors
-    /// This function may error with an [`Error::SerializeString`] if the
-    /// backend serializer failed to serialize.
+    /// This function may error with an [`Error::SerializeWriter`] if the
+    /// backend serializer failed to serialize. This may also be because it
+    /// failed to write to the `writer`.
     ///
     /// # Examples
     /// ```rust
+    /// # use std::io::Write;
+    /// # use std::marker::Unpin;
+    /// # use tokio::io::AsyncWrite;
     /// use serializable::dummy::Serializer;
-    /// use serializable::Serializable;
+    /// use serializable::{Serializable, SerializableAsync as _};
     ///
     /// #[derive(Default)]
     /// struct HelloWorld {
@@ -537,34 +3677,46 @@ pub trait Serializable<T: Serializer<Target = Self>> {
     /// impl Serializable<Serializer<HelloWorld>> for HelloWorld {}
     ///
     /// // Note: the dummy serializer doesn't actually serialize/deserialize any content. Check the features for proper ones!
-    /// fn to_string(hello_world: HelloWorld, pretty: bool) -> String {
-    ///     hello_world.to_string_pretty_opt(pretty).unwrap()
+    /// async fn to_writer_async(hello_world: HelloWorld, writer: impl Send + Unpin + AsyncWrite, pretty: bool) -> Result<(), serializable::Error<serializable::dummy::Error>> {
+    ///     hello_world.to_writer_pretty_opt_async(writer, pretty).await
     /// }
     ///
-    /// assert_eq!(to_string(HelloWorld { hello: "Hello".into(), world: "World".into() }, false), "<dummy_text>");
-    /// assert_eq!(to_string(HelloWorld { hello: "Hello".into(), world: "World".into() }, true), "Dummy Text");
+    /// # tokio_test::block_on(async {
+    /// let mut buf: Vec<u8> = Vec::new();
+    /// to_writer_async(HelloWorld { hello: "Hello".into(), world: "World".into() }, &mut buf, false).await.unwrap();
+    /// assert_eq!(String::from_utf8_lossy(&buf), "<dummy_text>");
+    ///
+    /// let mut buf: Vec<u8> = Vec::new();
+    /// to_writer_async(HelloWorld { hello: "Hello".into(), world: "World".into() }, &mut buf, true).await.unwrap();
+    /// assert_eq!(String::from_utf8_lossy(&buf), "Dummy Text");
+    /// # });
     /// ```
     #[inline]
-    fn to_string_pretty_opt(&self, pretty: bool) -> Result<String, Error<T::Error>> {
-        if pretty { self.to_string_pretty() } else { self.to_string() }
+    async fn to_writer_pretty_opt_async(
+        &self,
+        writer: impl Send + std::marker::Unpin + tokio::io::AsyncWrite,
+        pretty: bool,
+    ) -> Result<(), Error<T::Error>> {
+        if pretty { self.to_writer_pretty_async(writer).await } else { self.to_writer_async(writer).await }
     }
-    /// Convenience function for serializing this object to a writer using
+    /// Convenience function for serializing this object to a path using
     /// dynamic prettyness.
     ///
     /// # Arguments
-    /// - `writer`: A [`Write`]r that we will serialize to.
+    /// - `path`: The path to serialize this object to.
     /// - `pretty`: Whether to use the pretty formatter or not.
     ///
     /// # Errors
-    /// This function may error with an [`Error::SerializeWriter`] if the
+    /// This function may error with an [`Error::FileCreate`] if it failed to
+    /// create a new file, or an [`Error::SerializeFile`] if the
     /// backend serializer failed to serialize. This may also be because it
-    /// failed to write to the `writer`.
+    /// failed to write to the file.
     ///
     /// # Examples
     /// ```rust
-    /// # use std::io::Write;
+    /// # use std::path::PathBuf;
     /// use serializable::dummy::Serializer;
-    /// use serializable::Serializable;
+    /// use serializable::{Serializable, SerializableAsync as _};
     ///
     /// #[derive(Default)]
     /// struct HelloWorld {
@@ -574,44 +3726,45 @@ pub trait Serializable<T: Serializer<Target = Self>> {
     /// impl Serializable<Serializer<HelloWorld>> for HelloWorld {}
     ///
     /// // Note: the dummy serializer doesn't actually serialize/deserialize any content. Check the features for proper ones!
-    /// fn to_writer(hello_world: HelloWorld, writer: impl Write, pretty: bool) -> Result<(), serializable::Error<serializable::dummy::Error>> {
-    ///     hello_world.to_writer_pretty_opt(writer, pretty)
+    /// async fn to_path_async(hello_world: HelloWorld, pretty: bool) -> String {
+    ///     let path: PathBuf = std::env::temp_dir().join(format!("test_{}.txt", if pretty { "pretty" } else { "ugly" }));
+    ///     hello_world.to_path_pretty_opt_async(&path, pretty).await.unwrap();
+    ///     tokio::fs::read_to_string(path).await.unwrap()
     /// }
     ///
-    /// let mut buf: [u8; 12] = [0; 12];
-    /// to_writer(HelloWorld { hello: "Hello".into(), world: "World".into() }, &mut buf[..], false).unwrap();
-    /// assert_eq!(String::from_utf8_lossy(&buf), "<dummy_text>");
-    ///
-    /// let mut buf: [u8; 10] = [0; 10];
-    /// to_writer(HelloWorld { hello: "Hello".into(), world: "World".into() }, &mut buf[..], true).unwrap();
-    /// assert_eq!(String::from_utf8_lossy(&buf), "Dummy Text");
-    ///
-    /// let mut buf: [u8; 0] = [];
-    /// assert!(matches!(to_writer(HelloWorld { hello: "Hello".into(), world: "World".into() }, &mut buf[..], false), Err(serializable::Error::SerializeWriter { .. })));
-    /// assert!(matches!(to_writer(HelloWorld { hello: "Hello".into(), world: "World".into() }, &mut buf[..], true), Err(serializable::Error::SerializeWriter { .. })));
+    /// # tokio_test::block_on(async {
+    /// assert_eq!(to_path_async(HelloWorld { hello: "Hello".into(), world: "World".into() }, false).await, "<dummy_text>");
+    /// assert_eq!(to_path_async(HelloWorld { hello: "Hello".into(), world: "World".into() }, true).await, "Dummy Text");
+    /// # });
     /// ```
     #[inline]
-    fn to_writer_pretty_opt(&self, writer: impl Write, pretty: bool) -> Result<(), Error<T::Error>> {
-        if pretty { self.to_writer_pretty(writer) } else { self.to_writer(writer) }
+    async fn to_path_pretty_opt_async(&self, path: impl Send + AsRef<Path>, pretty: bool) -> Result<(), Error<T::Error>> {
+        if pretty { self.to_path_pretty_async(path).await } else { self.to_path_async(path).await }
     }
-    /// Convenience function for serializing this object to a path using
-    /// dynamic prettyness.
+
+    /// Convenience function for serializing this object to a file atomically
+    /// and asynchronously.
+    ///
+    /// See [`Serializable::to_path_atomic()`] for how the file is written
+    /// atomically.
     ///
     /// # Arguments
     /// - `path`: The path to serialize this object to.
-    /// - `pretty`: Whether to use the pretty formatter or not.
     ///
     /// # Errors
     /// This function may error with an [`Error::FileCreate`] if it failed to
-    /// create a new file, or an [`Error::SerializeFile`] if the
-    /// backend serializer failed to serialize. This may also be because it
-    /// failed to write to the file.
+    /// create the temporary file, an [`Error::SerializeFile`] if the backend
+    /// serializer failed to serialize (or to write to the file), an
+    /// [`Error::FileFlush`] or [`Error::FileSync`] if it failed to flush or
+    /// sync the temporary file, an [`Error::FileRename`] if it failed to move
+    /// it over `path`, or an [`Error::DirSync`] if it failed to sync the
+    /// directory. In all but the last case, `path` is left untouched.
     ///
     /// # Examples
     /// ```rust
     /// # use std::path::PathBuf;
     /// use serializable::dummy::Serializer;
-    /// use serializable::Serializable;
+    /// use serializable::{Serializable, SerializableAsync as _};
     ///
     /// #[derive(Default)]
     /// struct HelloWorld {
@@ -620,74 +3773,43 @@ pub trait Serializable<T: Serializer<Target = Self>> {
     /// }
     /// impl Serializable<Serializer<HelloWorld>> for HelloWorld {}
     ///
+    /// # tokio_test::block_on(async {
     /// // Note: the dummy serializer doesn't actually serialize/deserialize any content. Check the features for proper ones!
-    /// fn to_path(hello_world: HelloWorld, pretty: bool) -> String {
-    ///     let path: PathBuf = std::env::temp_dir().join(format!("test_{}.txt", if pretty { "pretty" } else { "ugly" }));
-    ///     hello_world.to_path_pretty_opt(&path, pretty).unwrap();
-    ///     std::fs::read_to_string(path).unwrap()
-    /// }
-    ///
-    /// assert_eq!(to_path(HelloWorld { hello: "Hello".into(), world: "World".into() }, false), "<dummy_text>");
-    /// assert_eq!(to_path(HelloWorld { hello: "Hello".into(), world: "World".into() }, true), "Dummy Text");
+    /// let path: PathBuf = std::env::temp_dir().join("test_atomic_async.txt");
+    /// HelloWorld { hello: "Hello".into(), world: "World".into() }.to_path_atomic_async(&path).await.unwrap();
+    /// assert_eq!(tokio::fs::read_to_string(path).await.unwrap(), "<dummy_text>");
+    /// # });
     /// ```
     #[inline]
-    fn to_path_pretty_opt(&self, path: impl AsRef<Path>, pretty: bool) -> Result<(), Error<T::Error>> {
-        if pretty { self.to_path_pretty(path) } else { self.to_path(path) }
+    async fn to_path_atomic_async(&self, path: impl Send + AsRef<Path>) -> Result<(), Error<T::Error>>
+    where
+        T::Error: Send,
+    {
+        self.to_path_pretty_opt_atomic_async(path, false).await
     }
-}
-
-
-
-/// Implements functions serialize- or deserialize a struct asynchronously.
-///
-/// This is only defined for serializing to- and from writers and readers (including files).
-///
-/// Note that not all backends have an optimal asynchronous implementation. [`serde`](https://serde.rs)-related backends, for example,
-/// fallback to reading the entire file or reader asynchronously before parsing it as a string.
-///
-/// # Examples
-/// ```rust
-/// use serializable::dummy::Serializer;
-/// use serializable::{Serializable, SerializableAsync as _};
-///
-/// #[derive(Debug, Default, Eq, PartialEq)]
-/// struct HelloWorld {
-///     hello: String,
-///     world: String,
-/// }
-/// impl Serializable<Serializer<HelloWorld>> for HelloWorld {}
-///
-/// # tokio_test::block_on(async {
-/// // Note: the dummy serializer doesn't actually serialize/deserialize any content. Check the features for proper ones!
-/// let mut buf: Vec<u8> = vec![];
-/// HelloWorld { hello: "Hello".into(), world: "World".into() }.to_writer_async(&mut buf).await.unwrap();
-/// assert_eq!(
-///     String::from_utf8_lossy(&buf),
-///     "<dummy_text>"
-/// );
-///
-/// let mut buf: Vec<u8> = (*b"<dummy_text>").into();
-/// assert_eq!(HelloWorld::from_reader_async(&buf[..]).await.unwrap(), HelloWorld::default());
-/// # });
-/// ```
-#[cfg(feature = "async-tokio")]
-#[async_trait::async_trait]
-pub trait SerializableAsync<T: Send + Sync + Serializer<Target = Self> + crate::serializer::SerializerAsync>: Serializable<T>
-where
-    T::Target: Send + Sync,
-{
-    // Serializes this object to the given writer asynchronously.
+    /// Convenience function for serializing this object to a file atomically
+    /// and asynchronously, using a pretty backend if it's available.
+    ///
+    /// If not, then this is equivalent to calling
+    /// [`Self::to_path_atomic_async()`](SerializableAsync::to_path_atomic_async()).
+    /// See [`Serializable::to_path_atomic()`] for how the file is written
+    /// atomically.
     ///
     /// # Arguments
-    /// - `writer`: A [`Write`]r that we will serialize to.
+    /// - `path`: The path to serialize this object to.
     ///
     /// # Errors
-    /// This function may error with an [`Error::SerializeWriter`] if the
-    /// backend serializer failed to serialize. This may also be because it
-    /// failed to write to the `writer`.
+    /// This function may error with an [`Error::FileCreate`] if it failed to
+    /// create the temporary file, an [`Error::SerializeFile`] if the backend
+    /// serializer failed to serialize (or to write to the file), an
+    /// [`Error::FileFlush`] or [`Error::FileSync`] if it failed to flush or
+    /// sync the temporary file, an [`Error::FileRename`] if it failed to move
+    /// it over `path`, or an [`Error::DirSync`] if it failed to sync the
+    /// directory. In all but the last case, `path` is left untouched.
     ///
     /// # Examples
     /// ```rust
+    /// # use std::path::PathBuf;
     /// use serializable::dummy::Serializer;
     /// use serializable::{Serializable, SerializableAsync as _};
     ///
@@ -700,35 +3822,40 @@ where
     ///
     /// # tokio_test::block_on(async {
     /// // Note: the dummy serializer doesn't actually serialize/deserialize any content. Check the features for proper ones!
-    /// let mut buf: Vec<u8> = Vec::new();
-    /// HelloWorld { hello: "Hello".into(), world: "World".into() }.to_writer_async(&mut buf).await.unwrap();
-    /// assert_eq!(String::from_utf8_lossy(&buf), "<dummy_text>");
+    /// let path: PathBuf = std::env::temp_dir().join("test_atomic_async.txt");
+    /// HelloWorld { hello: "Hello".into(), world: "World".into() }.to_path_pretty_atomic_async(&path).await.unwrap();
+    /// assert_eq!(tokio::fs::read_to_string(path).await.unwrap(), "Dummy Text");
     /// # });
     /// ```
     #[inline]
-    async fn to_writer_async(&self, writer: impl Send + std::marker::Unpin + tokio::io::AsyncWrite) -> Result<(), Error<T::Error>> {
-        match T::to_writer_async(self, writer).await {
-            Ok(_) => Ok(()),
-            Err(err) => Err(Error::SerializeWriter { what: type_name::<T::Target>(), err }),
-        }
+    async fn to_path_pretty_atomic_async(&self, path: impl Send + AsRef<Path>) -> Result<(), Error<T::Error>>
+    where
+        T::Error: Send,
+    {
+        self.to_path_pretty_opt_atomic_async(path, true).await
     }
-
-    /// Serializes this object to the given writer asynchronously, using a pretty backend if
-    /// it's available.
+    /// Convenience function for serializing this object to a file atomically
+    /// and asynchronously, using dynamic prettyness.
     ///
-    /// If not, then this is equivalent to calling
-    /// [`Self::to_writer()`](Serializable::to_writer()).
+    /// See [`Serializable::to_path_atomic()`] for how the file is written
+    /// atomically.
     ///
     /// # Arguments
-    /// - `writer`: A [`Write`]r that we will serialize to.
+    /// - `path`: The path to serialize this object to.
+    /// - `pretty`: Whether to use the pretty formatter or not.
     ///
     /// # Errors
-    /// This function may error with an [`Error::SerializeWriter`] if the
-    /// backend serializer failed to serialize. This may also be because it
-    /// failed to write to the `writer`.
+    /// This function may error with an [`Error::FileCreate`] if it failed to
+    /// create the temporary file, an [`Error::SerializeFile`] if the backend
+    /// serializer failed to serialize (or to write to the file), an
+    /// [`Error::FileFlush`] or [`Error::FileSync`] if it failed to flush or
+    /// sync the temporary file, an [`Error::FileRename`] if it failed to move
+    /// it over `path`, or an [`Error::DirSync`] if it failed to sync the
+    /// directory. In all but the last case, `path` is left untouched.
     ///
     /// # Examples
     /// ```rust
+    /// # use std::path::PathBuf;
     /// use serializable::dummy::Serializer;
     /// use serializable::{Serializable, SerializableAsync as _};
     ///
@@ -741,36 +3868,120 @@ where
     ///
     /// # tokio_test::block_on(async {
     /// // Note: the dummy serializer doesn't actually serialize/deserialize any content. Check the features for proper ones!
-    /// let mut buf: Vec<u8> = Vec::new();
-    /// HelloWorld { hello: "Hello".into(), world: "World".into() }.to_writer_pretty_async(&mut buf).await.unwrap();
-    /// assert_eq!(String::from_utf8_lossy(&buf), "Dummy Text");
+    /// let path: PathBuf = std::env::temp_dir().join("test_atomic_async.txt");
+    /// HelloWorld { hello: "Hello".into(), world: "World".into() }.to_path_pretty_opt_atomic_async(&path, true).await.unwrap();
+    /// assert_eq!(tokio::fs::read_to_string(path).await.unwrap(), "Dummy Text");
     /// # });
     /// ```
     #[inline]
-    async fn to_writer_pretty_async(&self, writer: impl Send + std::marker::Unpin + tokio::io::AsyncWrite) -> Result<(), Error<T::Error>> {
-        match T::to_writer_pretty_async(self, writer).await {
-            Ok(_) => Ok(()),
-            Err(err) => Err(Error::SerializeWriter { what: type_name::<T::Target>(), err }),
+    async fn to_path_pretty_opt_atomic_async(&self, path: impl Send + AsRef<Path>, pretty: bool) -> Result<(), Error<T::Error>>
+    where
+        T::Error: Send,
+    {
+        let path: &Path = path.as_ref();
+        write_atomic_async(self, path, 0, pretty, Compression::from_path(path), None).await?;
+        write_checksum_async(path, None, true).await
+    }
+
+    /// Convenience function for serializing this object to a file
+    /// asynchronously, using the given [`WriteOptions`].
+    ///
+    /// See [`Serializable::to_path_with_options()`] for how the options are
+    /// applied.
+    ///
+    /// # Arguments
+    /// - `path`: The path to serialize this object to.
+    /// - `opts`: The [`WriteOptions`] that determine how to write it.
+    ///
+    /// # Errors
+    /// This function may error with an [`Error::FileBackup`] if it failed to
+    /// rotate the backups of the file, or an [`Error::FileWrite`] or
+    /// [`Error::FileRemove`] if it failed to write or remove its checksum
+    /// files. Otherwise, it errors like the function it uses to write the
+    /// file.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use std::path::PathBuf;
+    /// use serializable::dummy::Serializer;
+    /// use serializable::{Serializable, SerializableAsync as _, WriteOptions};
+    ///
+    /// #[derive(Default)]
+    /// struct HelloWorld {
+    ///     hello: String,
+    ///     world: String,
+    /// }
+    /// impl Serializable<Serializer<HelloWorld>> for HelloWorld {}
+    ///
+    /// # tokio_test::block_on(async {
+    /// // Note: the dummy serializer doesn't actually serialize/deserialize any content. Check the features for proper ones!
+    /// let path: PathBuf = std::env::temp_dir().join("test_backups_async.txt");
+    /// tokio::fs::write(&path, "Old Text").await.unwrap();
+    /// let opts = WriteOptions { atomic: true, backups: 2, ..Default::default() };
+    /// HelloWorld { hello: "Hello".into(), world: "World".into() }.to_path_with_options_async(&path, &opts).await.unwrap();
+    /// assert_eq!(tokio::fs::read_to_string(&path).await.unwrap(), "<dummy_text>");
+    /// assert_eq!(tokio::fs::read_to_string(std::env::temp_dir().join("test_backups_async.txt.1")).await.unwrap(), "Old Text");
+    /// # });
+    /// ```
+    #[inline]
+    async fn to_path_with_options_async(&self, path: impl Send + AsRef<Path>, opts: &WriteOptions) -> Result<(), Error<T::Error>>
+    where
+        T::Error: Send,
+    {
+        let path: &Path = path.as_ref();
+        let compression: Option<Compression> = opts.compression.or_else(|| Compression::from_path(path));
+        let mut hasher: Option<Hasher> = opts.checksum.map(Hasher::new);
+        if opts.atomic {
+            write_atomic_async(self, path, opts.backups, opts.pretty, compression, hasher.as_mut()).await?;
+        } else {
+            use tokio::io::AsyncWriteExt as _;
+
+            // Serialize to memory first, so that we only touch the file (and its backups) if that succeeds
+            let mut raw: Vec<u8> = Vec::new();
+            write_compressed_async(self, &mut raw, path, opts.pretty, compression, hasher.as_mut()).await?;
+            rotate_backups_async(path, opts.backups).await?;
+
+            // Then write it
+            let mut handle: tokio::fs::File = match tokio::fs::File::create(path).await {
+                Ok(handle) => handle,
+                Err(err) => {
+                    return Err(Error::FileCreate { path: path.into(), err });
+                },
+            };
+            if let Err(err) = handle.write_all(&raw).await {
+                return Err(Error::FileWrite { path: path.into(), err });
+            }
+            if let Err(err) = handle.flush().await {
+                return Err(Error::FileFlush { path: path.into(), err });
+            }
         }
+
+        // Finally, write its checksum file (or remove stale ones)
+        let sum: Option<String> = hasher.as_ref().map(Hasher::finalize);
+        write_checksum_async(path, opts.checksum.zip(sum.as_deref()), opts.atomic).await
     }
 
-    /// Deserializes this object from the given reader asynchronously.
+    /// Convenience function for deserializing this object from a file
+    /// asynchronously, falling back to its backups if that fails.
+    ///
+    /// See [`Serializable::from_path_with_fallback()`] for which backups are
+    /// tried.
     ///
     /// # Arguments
-    /// - `reader`: The [`Read`]er that provides a serialized instantiation of
-    ///   Self.
+    /// - `path`: The path to deserialize this object from.
+    /// - `backups`: The number of backups to try.
     ///
     /// # Returns
-    /// A new Self that represents the deserialized counterpart.
+    /// A new Self that represents the deserialized object.
     ///
     /// # Errors
-    /// This function may error with an [`Error::DeserializeString`] if the
-    /// backend deserializer failed to deserialize. This may be because the
-    /// backend failed to read from the given `reader`, or because the
-    /// serialized representation was illegal for this type and backend.
+    /// This function errors if neither the file nor any of its backups could
+    /// be deserialized. In that case, the error of the file itself is
+    /// returned (see [`Self::from_path_async()`](SerializableAsync::from_path_async())).
     ///
     /// # Examples
     /// ```rust
+    /// # use std::path::PathBuf;
     /// use serializable::dummy::Serializer;
     /// use serializable::{Serializable, SerializableAsync as _};
     ///
@@ -783,37 +3994,53 @@ where
     ///
     /// # tokio_test::block_on(async {
     /// // Note: the dummy serializer doesn't actually serialize/deserialize any content. Check the features for proper ones!
-    /// assert_eq!(HelloWorld::from_reader_async("<dummy_text>".as_bytes()).await.unwrap(), HelloWorld::default());
+    /// let path: PathBuf = std::env::temp_dir().join("test_fallback_async.txt");
+    /// let _ = tokio::fs::remove_file(&path).await;
+    /// tokio::fs::write(std::env::temp_dir().join("test_fallback_async.txt.1"), "<dummy_text>").await.unwrap();
+    /// assert_eq!(HelloWorld::from_path_with_fallback_async(&path, 1).await.unwrap(), HelloWorld::default());
     /// # });
     /// ```
     #[inline]
-    async fn from_reader_async(reader: impl Send + std::marker::Unpin + tokio::io::AsyncRead) -> Result<Self, Error<T::Error>>
+    async fn from_path_with_fallback_async(path: impl Send + AsRef<Path>, backups: usize) -> Result<Self, Error<T::Error>>
     where
         Self: Sized,
+        T::Error: Send,
     {
-        match T::from_reader_async(reader).await {
-            Ok(res) => Ok(res),
-            Err(err) => Err(Error::DeserializeString { what: type_name::<T::Target>(), err }),
+        let path: &Path = path.as_ref();
+        let err: Error<T::Error> = match Self::from_path_async(path).await {
+            Ok(res) => return Ok(res),
+            Err(err) => err,
+        };
+        for n in 1..=backups {
+            if let Ok(res) = Self::from_path_async(backup_path(path, n)).await {
+                return Ok(res);
+            }
         }
+        Err(err)
     }
 
-
-
-    // Convenience functions
-    /// Convenience function for serializing this object to a file asynchronously.
+    /// Convenience function for serializing this object to a file while
+    /// holding an exclusive lock on it, asynchronously.
+    ///
+    /// See [`Serializable::to_path_locked()`] for how the file is locked.
+    /// Instead of blocking, this function polls the lock until it gets it.
     ///
     /// # Arguments
     /// - `path`: The path to serialize this object to.
+    /// - `timeout`: The maximum time to wait for others to release the file,
+    ///   or [`None`] to wait indefinitely.
     ///
     /// # Errors
     /// This function may error with an [`Error::FileCreate`] if it failed to
-    /// create a new file, or an [`Error::SerializeFile`] if the
-    /// backend serializer failed to serialize. This may also be because it
-    /// failed to write to the file.
+    /// open (or truncate) the file, an [`Error::FileLock`] if it failed to
+    /// lock it (or timed out), an [`Error::SerializeFile`] if the backend
+    /// serializer failed to serialize (or to write to the file), or an
+    /// [`Error::FileFlush`] if it failed to flush the file.
     ///
     /// # Examples
     /// ```rust
     /// # use std::path::PathBuf;
+    /// # use std::time::Duration;
     /// use serializable::dummy::Serializer;
     /// use serializable::{Serializable, SerializableAsync as _};
     ///
@@ -826,46 +4053,42 @@ where
     ///
     /// # tokio_test::block_on(async {
     /// // Note: the dummy serializer doesn't actually serialize/deserialize any content. Check the features for proper ones!
-    /// let path: PathBuf = std::env::temp_dir().join("test.txt");
-    /// HelloWorld { hello: "Hello".into(), world: "World".into() }.to_path_async(&path).await.unwrap();
+    /// let path: PathBuf = std::env::temp_dir().join("test_locked_async.txt");
+    /// HelloWorld { hello: "Hello".into(), world: "World".into() }.to_path_locked_async(&path, Some(Duration::from_secs(1))).await.unwrap();
     /// assert_eq!(tokio::fs::read_to_string(path).await.unwrap(), "<dummy_text>");
     /// # });
     /// ```
     #[inline]
-    async fn to_path_async(&self, path: impl Send + AsRef<Path>) -> Result<(), Error<T::Error>> {
-        // Open the file as a writer
-        let path: &Path = path.as_ref();
-        let mut handle: tokio::fs::File = match tokio::fs::File::create(path).await {
-            Ok(handle) => handle,
-            Err(err) => {
-                return Err(Error::FileCreate { path: path.into(), err });
-            },
-        };
-
-        // Pass to the writer impl
-        match self.to_writer_async(&mut handle).await {
-            Ok(_) => Ok(()),
-            Err(Error::SerializeWriter { what, err }) => Err(Error::SerializeFile { what, path: path.into(), err }),
-            Err(err) => Err(err),
-        }
+    async fn to_path_locked_async(&self, path: impl Send + AsRef<Path>, timeout: Option<Duration>) -> Result<(), Error<T::Error>>
+    where
+        T::Error: Send,
+    {
+        self.to_path_pretty_opt_locked_async(path, false, timeout).await
     }
-    /// Convenience function for serializing this object to a file asynchronously, using a pretty backend if it's available.
+    /// Convenience function for serializing this object to a file while
+    /// holding an exclusive lock on it, asynchronously and using a pretty
+    /// backend if it's available.
     ///
     /// If not, then this is equivalent to calling
-    /// [`Self::to_path()`](Serializable::to_path()).
+    /// [`Self::to_path_locked_async()`](SerializableAsync::to_path_locked_async()).
+    /// See [`Serializable::to_path_locked()`] for how the file is locked.
     ///
     /// # Arguments
     /// - `path`: The path to serialize this object to.
+    /// - `timeout`: The maximum time to wait for others to release the file,
+    ///   or [`None`] to wait indefinitely.
     ///
     /// # Errors
     /// This function may error with an [`Error::FileCreate`] if it failed to
-    /// create a new file, or an [`Error::SerializeFile`] if the
-    /// backend serializer failed to serialize. This may also be because it
-    /// failed to write to the file.
+    /// open (or truncate) the file, an [`Error::FileLock`] if it failed to
+    /// lock it (or timed out), an [`Error::SerializeFile`] if the backend
+    /// serializer failed to serialize (or to write to the file), or an
+    /// [`Error::FileFlush`] if it failed to flush the file.
     ///
     /// # Examples
     /// ```rust
     /// # use std::path::PathBuf;
+    /// # use std::time::Duration;
     /// use serializable::dummy::Serializer;
     /// use serializable::{Serializable, SerializableAsync as _};
     ///
@@ -878,46 +4101,118 @@ where
     ///
     /// # tokio_test::block_on(async {
     /// // Note: the dummy serializer doesn't actually serialize/deserialize any content. Check the features for proper ones!
-    /// let path: PathBuf = std::env::temp_dir().join("test.txt");
-    /// HelloWorld { hello: "Hello".into(), world: "World".into() }.to_path_pretty_async(&path).await.unwrap();
+    /// let path: PathBuf = std::env::temp_dir().join("test_locked_async.txt");
+    /// HelloWorld { hello: "Hello".into(), world: "World".into() }.to_path_pretty_locked_async(&path, None).await.unwrap();
     /// assert_eq!(tokio::fs::read_to_string(path).await.unwrap(), "Dummy Text");
     /// # });
     /// ```
     #[inline]
-    async fn to_path_pretty_async(&self, path: impl Send + AsRef<Path>) -> Result<(), Error<T::Error>> {
-        // Open the file as a writer
+    async fn to_path_pretty_locked_async(&self, path: impl Send + AsRef<Path>, timeout: Option<Duration>) -> Result<(), Error<T::Error>>
+    where
+        T::Error: Send,
+    {
+        self.to_path_pretty_opt_locked_async(path, true, timeout).await
+    }
+    /// Convenience function for serializing this object to a file while
+    /// holding an exclusive lock on it, asynchronously and using dynamic
+    /// prettyness.
+    ///
+    /// See [`Serializable::to_path_locked()`] for how the file is locked.
+    ///
+    /// # Arguments
+    /// - `path`: The path to serialize this object to.
+    /// - `pretty`: Whether to use the pretty formatter or not.
+    /// - `timeout`: The maximum time to wait for others to release the file,
+    ///   or [`None`] to wait indefinitely.
+    ///
+    /// # Errors
+    /// This function may error with an [`Error::FileCreate`] if it failed to
+    /// open (or truncate) the file, an [`Error::FileLock`] if it failed to
+    /// lock it (or timed out), an [`Error::SerializeFile`] if the backend
+    /// serializer failed to serialize (or to write to the file), or an
+    /// [`Error::FileFlush`] if it failed to flush the file.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use std::path::PathBuf;
+    /// # use std::time::Duration;
+    /// use serializable::dummy::Serializer;
+    /// use serializable::{Serializable, SerializableAsync as _};
+    ///
+    /// #[derive(Default)]
+    /// struct HelloWorld {
+    ///     hello: String,
+    ///     world: String,
+    /// }
+    /// impl Serializable<Serializer<HelloWorld>> for HelloWorld {}
+    ///
+    /// # tokio_test::block_on(async {
+    /// // Note: the dummy serializer doesn't actually serialize/deserialize any content. Check the features for proper ones!
+    /// let path: PathBuf = std::env::temp_dir().join("test_locked_async.txt");
+    /// HelloWorld { hello: "Hello".into(), world: "World".into() }.to_path_pretty_opt_locked_async(&path, true, None).await.unwrap();
+    /// assert_eq!(tokio::fs::read_to_string(path).await.unwrap(), "Dummy Text");
+    /// # });
+    /// ```
+    #[inline]
+    async fn to_path_pretty_opt_locked_async(&self, path: impl Send + AsRef<Path>, pretty: bool, timeout: Option<Duration>) -> Result<(), Error<T::Error>>
+    where
+        T::Error: Send,
+    {
+        use tokio::io::AsyncWriteExt as _;
+
+        // Open the file without truncating it, as others may still be reading it
         let path: &Path = path.as_ref();
-        let handle: tokio::fs::File = match tokio::fs::File::create(path).await {
+        let handle: tokio::fs::File = match tokio::fs::OpenOptions::new().write(true).create(true).truncate(false).open(path).await {
             Ok(handle) => handle,
             Err(err) => {
                 return Err(Error::FileCreate { path: path.into(), err });
             },
         };
 
+        // Only once we have it to ourselves, we clear it
+        let mut handle: tokio::fs::File = lock_file_async(handle, path, false, timeout).await?;
+        if let Err(err) = handle.set_len(0).await {
+            return Err(Error::FileCreate { path: path.into(), err });
+        }
+
         // Pass to the writer impl
-        match self.to_writer_pretty_async(handle).await {
-            Ok(_) => Ok(()),
-            Err(Error::SerializeWriter { what, err }) => Err(Error::SerializeFile { what, path: path.into(), err }),
-            Err(err) => Err(err),
+        write_compressed_async(self, &mut handle, path, pretty, Compression::from_path(path), None).await?;
+        // Make sure everything is written before we release the lock, and remove any checksum files
+        if let Err(err) = handle.flush().await {
+            return Err(Error::FileFlush { path: path.into(), err });
         }
+        write_checksum_async(path, None, false).await
     }
-    /// Convenience function for deserializing this object from a file asynchronously.
+
+    /// Convenience function for deserializing this object from a file while
+    /// holding a shared lock on it, asynchronously.
+    ///
+    /// See [`Serializable::from_path_locked()`] for how the file is locked.
+    /// Instead of blocking, this function polls the lock until it gets it.
     ///
     /// # Arguments
     /// - `path`: The path to deserialize this object from.
+    /// - `timeout`: The maximum time to wait for writers to release the file,
+    ///   or [`None`] to wait indefinitely.
     ///
     /// # Returns
     /// A new Self that represents the deserialized object.
     ///
     /// # Errors
     /// This function may error with an [`Error::FileOpen`] if it failed to
-    /// create a new file, or an [`Error::DeserializeFile`] if the
-    /// backend serializer failed to serialize. This may also be because it
-    /// failed to write to the file.
+    /// open the file, an [`Error::FileLock`] if it failed to lock it (or
+    /// timed out), or an [`Error::DeserializeFile`] if the backend serializer
+    /// failed to deserialize. This may also be because it failed to read from
+    /// the file.
+    ///
+    /// If the file has a checksum file (see [`WriteOptions::checksum`]), then
+    /// it is verified before it's deserialized, and this function errors with
+    /// an [`Error::ChecksumMismatch`] if it doesn't match.
     ///
     /// # Examples
     /// ```rust
     /// # use std::path::PathBuf;
+    /// # use std::time::Duration;
     /// use serializable::dummy::Serializer;
     /// use serializable::{Serializable, SerializableAsync as _};
     ///
@@ -930,17 +4225,18 @@ where
     ///
     /// # tokio_test::block_on(async {
     /// // Note: the dummy serializer doesn't actually serialize/deserialize any content. Check the features for proper ones!
-    /// let path: PathBuf = std::env::temp_dir().join("test.txt");
+    /// let path: PathBuf = std::env::temp_dir().join("test_locked_read_async.txt");
     /// tokio::fs::write(&path, "<dummy_text>").await.unwrap();
-    /// assert_eq!(HelloWorld::from_path_async(path).await.unwrap(), HelloWorld::default());
+    /// assert_eq!(HelloWorld::from_path_locked_async(&path, Some(Duration::from_secs(1))).await.unwrap(), HelloWorld::default());
     /// # });
     /// ```
     #[inline]
-    async fn from_path_async(path: impl Send + AsRef<Path>) -> Result<Self, Error<T::Error>>
+    async fn from_path_locked_async(path: impl Send + AsRef<Path>, timeout: Option<Duration>) -> Result<Self, Error<T::Error>>
     where
         Self: Sized,
+        T::Error: Send,
     {
-        // Open the file as a writer
+        // Open the file as a reader
         let path: &Path = path.as_ref();
         let handle: tokio::fs::File = match tokio::fs::File::open(path).await {
             Ok(handle) => handle,
@@ -948,33 +4244,46 @@ where
                 return Err(Error::FileOpen { path: path.into(), err });
             },
         };
+        let handle: tokio::fs::File = lock_file_async(handle, path, true, timeout).await?;
 
-        // Pass to the reader impl
-        match Self::from_reader_async(handle).await {
+        // Read it (decompressing if needed), then pass to the reader impl
+        let raw: Vec<u8> = read_decompressed_async(handle, path).await?;
+        match Self::from_reader_async(raw.as_slice()).await {
             Ok(res) => Ok(res),
-            Err(Error::DeserializeReader { what, err }) => Err(Error::DeserializeFile { what, path: path.into(), err }),
+            Err(Error::DeserializeReader { what, ctx, err }) => {
+                // Use the input to find the offending line (and the location, for backends that need the input for that)
+                let raw: Option<&str> = std::str::from_utf8(&raw).ok();
+                let loc: Option<Location> = T::location(&err, raw).or(ctx.loc);
+                Err(Error::DeserializeFile { what, path: path.into(), ctx: context(ctx.field_path, loc, raw), err })
+            },
             Err(err) => Err(err),
         }
     }
 
-    /// Convenience function for serializing this object to a writer using
-    /// dynamic prettyness.
+    /// Convenience function for serializing this object to a file
+    /// asynchronously, and signing it with the given Ed25519 key.
+    ///
+    /// This is the `async` counterpart of
+    /// [`Self::to_path_signed()`](Serializable::to_path_signed()).
     ///
     /// # Arguments
-    /// - `writer`: A [`Write`]r that we will serialize to.
-    /// - `pretty`: Whether to use the pretty formatter or not.
+    /// - `path`: The path to serialize this object to.
+    /// - `key`: The [`SigningKey`](crate::signature::SigningKey) to sign with.
     ///
     /// # Errors
-    /// This function may error with an [`Error::SerializeWriter`] if the
-    /// backend serializer failed to serialize. This may also be because it
-    /// failed to write to the `writer`.
+    /// This function may error with an [`Error::FileCreate`] if it failed to
+    /// create a new file, an [`Error::SerializeFile`] if the backend
+    /// serializer failed to serialize, an [`Error::FileCompress`] if it
+    /// failed to compress the output, an [`Error::FileWrite`] if it failed to
+    /// write the file or its signature file, or an [`Error::FileSync`],
+    /// [`Error::FileRename`] or [`Error::DirSync`] if it failed to move either
+    /// into place.
     ///
     /// # Examples
     /// ```rust
-    /// # use std::io::Write;
-    /// # use std::marker::Unpin;
-    /// # use tokio::io::AsyncWrite;
+    /// # use std::path::PathBuf;
     /// use serializable::dummy::Serializer;
+    /// use serializable::signature::{self, SigningKey};
     /// use serializable::{Serializable, SerializableAsync as _};
     ///
     /// #[derive(Default)]
@@ -984,70 +4293,110 @@ where
     /// }
     /// impl Serializable<Serializer<HelloWorld>> for HelloWorld {}
     ///
-    /// // Note: the dummy serializer doesn't actually serialize/deserialize any content. Check the features for proper ones!
-    /// async fn to_writer_async(hello_world: HelloWorld, writer: impl Send + Unpin + AsyncWrite, pretty: bool) -> Result<(), serializable::Error<serializable::dummy::Error>> {
-    ///     hello_world.to_writer_pretty_opt_async(writer, pretty).await
-    /// }
-    ///
     /// # tokio_test::block_on(async {
-    /// let mut buf: Vec<u8> = Vec::new();
-    /// to_writer_async(HelloWorld { hello: "Hello".into(), world: "World".into() }, &mut buf, false).await.unwrap();
-    /// assert_eq!(String::from_utf8_lossy(&buf), "<dummy_text>");
-    ///
-    /// let mut buf: Vec<u8> = Vec::new();
-    /// to_writer_async(HelloWorld { hello: "Hello".into(), world: "World".into() }, &mut buf, true).await.unwrap();
-    /// assert_eq!(String::from_utf8_lossy(&buf), "Dummy Text");
+    /// // Note: the dummy serializer doesn't actually serialize/deserialize any content. Check the features for proper ones!
+    /// let key = SigningKey::from_bytes(&[42; 32]);
+    /// let path: PathBuf = std::env::temp_dir().join("test_signed_async.txt");
+    /// HelloWorld { hello: "Hello".into(), world: "World".into() }.to_path_signed_async(&path, &key).await.unwrap();
+    /// assert_eq!(tokio::fs::read_to_string(&path).await.unwrap(), "<dummy_text>");
+    /// assert_eq!(tokio::fs::read_to_string(signature::path(&path)).await.unwrap().trim().len(), 128);
     /// # });
     /// ```
+    #[cfg(feature = "sign-ed25519")]
     #[inline]
-    async fn to_writer_pretty_opt_async(
-        &self,
-        writer: impl Send + std::marker::Unpin + tokio::io::AsyncWrite,
-        pretty: bool,
-    ) -> Result<(), Error<T::Error>> {
-        if pretty { self.to_writer_pretty_async(writer).await } else { self.to_writer_async(writer).await }
+    async fn to_path_signed_async(&self, path: impl Send + AsRef<Path>, key: &crate::signature::SigningKey) -> Result<(), Error<T::Error>> {
+        // Serialize to memory first (in the backend's canonical form), as that's what we sign
+        let path: &Path = path.as_ref();
+        let raw: Vec<u8> = match T::to_bytes_canonical(self) {
+            Ok(raw) => raw,
+            Err(err) => return Err(Error::SerializeFile { what: type_name::<T::Target>(), path: path.into(), err }),
+        };
+        let sig: String = crate::signature::sign(key, &raw);
+
+        // Write the file atomically (compressing if the extension says so)
+        let raw: Vec<u8> = match crate::compression::compress(raw, Compression::from_path(path)) {
+            Ok(raw) => raw,
+            Err(err) => return Err(Error::FileCompress { path: path.into(), err }),
+        };
+        write_bytes_atomic_async(path, &raw).await?;
+
+        // Only then replace its signature file
+        let sig_path: PathBuf = crate::signature::path(path);
+        write_bytes_atomic_async(&sig_path, format!("{sig}\n").as_bytes()).await?;
+
+        // Remove any checksum files, as they no longer match
+        write_checksum_async(path, None, false).await
     }
-    /// Convenience function for serializing this object to a path using
-    /// dynamic prettyness.
+
+    /// Convenience function for deserializing this object from a file
+    /// asynchronously, after verifying that it's signed by one of the given
+    /// Ed25519 keys.
+    ///
+    /// This is the `async` counterpart of
+    /// [`Self::from_path_verified()`](Serializable::from_path_verified()).
     ///
     /// # Arguments
-    /// - `path`: The path to serialize this object to.
-    /// - `pretty`: Whether to use the pretty formatter or not.
+    /// - `path`: The path to deserialize this object from.
+    /// - `trusted`: The [`VerifyingKey`](crate::signature::VerifyingKey)s of
+    ///   the signers to trust.
+    ///
+    /// # Returns
+    /// A new Self that represents the deserialized object.
     ///
     /// # Errors
-    /// This function may error with an [`Error::FileCreate`] if it failed to
-    /// create a new file, or an [`Error::SerializeFile`] if the
-    /// backend serializer failed to serialize. This may also be because it
-    /// failed to write to the file.
+    /// This function errors like
+    /// [`Self::from_path_verified()`](Serializable::from_path_verified()).
     ///
     /// # Examples
     /// ```rust
     /// # use std::path::PathBuf;
     /// use serializable::dummy::Serializer;
-    /// use serializable::{Serializable, SerializableAsync as _};
+    /// use serializable::signature::SigningKey;
+    /// use serializable::{Error, Serializable, SerializableAsync as _};
     ///
-    /// #[derive(Default)]
+    /// #[derive(Debug, Default, Eq, PartialEq)]
     /// struct HelloWorld {
     ///     hello: String,
     ///     world: String,
     /// }
     /// impl Serializable<Serializer<HelloWorld>> for HelloWorld {}
     ///
+    /// # tokio_test::block_on(async {
     /// // Note: the dummy serializer doesn't actually serialize/deserialize any content. Check the features for proper ones!
-    /// async fn to_path_async(hello_world: HelloWorld, pretty: bool) -> String {
-    ///     let path: PathBuf = std::env::temp_dir().join(format!("test_{}.txt", if pretty { "pretty" } else { "ugly" }));
-    ///     hello_world.to_path_pretty_opt_async(&path, pretty).await.unwrap();
-    ///     tokio::fs::read_to_string(path).await.unwrap()
-    /// }
+    /// let key = SigningKey::from_bytes(&[42; 32]);
+    /// let path: PathBuf = std::env::temp_dir().join("test_verified_async.txt");
+    /// HelloWorld::default().to_path_signed_async(&path, &key).await.unwrap();
+    /// assert_eq!(HelloWorld::from_path_verified_async(&path, &[key.verifying_key()]).await.unwrap(), HelloWorld::default());
     ///
-    /// # tokio_test::block_on(async {
-    /// assert_eq!(to_path_async(HelloWorld { hello: "Hello".into(), world: "World".into() }, false).await, "<dummy_text>");
-    /// assert_eq!(to_path_async(HelloWorld { hello: "Hello".into(), world: "World".into() }, true).await, "Dummy Text");
+    /// let other = SigningKey::from_bytes(&[7; 32]);
+    /// assert!(matches!(HelloWorld::from_path_verified_async(&path, &[other.verifying_key()]).await, Err(Error::SignatureUntrusted { .. })));
     /// # });
     /// ```
+    #[cfg(feature = "sign-ed25519")]
     #[inline]
-    async fn to_path_pretty_opt_async(&self, path: impl Send + AsRef<Path>, pretty: bool) -> Result<(), Error<T::Error>> {
-        if pretty { self.to_path_pretty_async(path).await } else { self.to_path_async(path).await }
+    async fn from_path_verified_async(path: impl Send + AsRef<Path>, trusted: &[crate::signature::VerifyingKey]) -> Result<Self, Error<T::Error>>
+    where
+        Self: Sized,
+        T::Error: Send,
+    {
+        // Open the file as a reader
+        let path: &Path = path.as_ref();
+        let handle: tokio::fs::File = match tokio::fs::File::open(path).await {
+            Ok(handle) => handle,
+            Err(err) => {
+                return Err(Error::FileOpen { path: path.into(), err });
+            },
+        };
+
+        // Read it (decompressing if needed) and verify it before deserializing anything
+        let raw: Vec<u8> = read_decompressed_async(handle, path).await?;
+        let sig_path: PathBuf = crate::signature::path(path);
+        let sig: String = match tokio::fs::read_to_string(&sig_path).await {
+            Ok(sig) => sig,
+            Err(err) => return Err(Error::FileRead { path: sig_path, err }),
+        };
+        check_signature(path, &sig, trusted, &raw)?;
+        from_verified(&raw, path)
     }
 }
 #[cfg(feature = "async-tokio")]
@@ -4,7 +4,7 @@
 //  Created:
 //    28 Oct 2023, 10:21:11
 //  Last edited:
-//    29 Oct 2023, 18:03:35
+//    17 Oct 2026, 12:08:15
 //  Auto updated?
 //    Yes
 //
@@ -17,6 +17,9 @@
 use std::error::Error;
 use std::io::{Read, Write};
 
+use crate::location::{Location, PathSegment};
+use crate::pretty::PrettyOptions;
+
 
 /***** LIBRARY **** */
 /// Defines a trait that abstracts over the possible serializers.
@@ -81,6 +84,168 @@ pub trait Serializer {
     /// assert_eq!(Serializer::to_string_pretty(&true).unwrap(), "Dummy Text");
     #[inline]
     fn to_string_pretty(value: &Self::Target) -> Result<String, Self::Error> { Self::to_string(value) }
+    /// Serializes the given value to a string in accordance with the backend
+    /// implementation's pretty serialization, honouring the given
+    /// [`PrettyOptions`] as far as the backend can.
+    ///
+    /// Backends that don't support any of the options can use the default
+    /// implementation, which is simply an alias for
+    /// [`Self::to_string_pretty()`](Serializer::to_string_pretty()). Given
+    /// [`PrettyOptions::DEFAULT`], backends should produce the same as that
+    /// function.
+    ///
+    /// # Arguments
+    /// - `value`: The value to serialize.
+    /// - `opts`: The [`PrettyOptions`] to honour.
+    ///
+    /// # Returns
+    /// A string with the serialized value.
+    ///
+    /// # Errors
+    /// This function may error if the given value was not serializable in its
+    /// current state.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use serializable::dummy::Serializer;
+    /// use serializable::{PrettyOptions, Serializer as _};
+    ///
+    /// // Note: the dummy parser actually doesn't serialize or deserialize, see the features for proper ones
+    /// let opts = PrettyOptions { indent: Some("    "), ..Default::default() };
+    /// assert_eq!(Serializer::to_string_pretty_with(&42u8, &opts).unwrap(), "Dummy Text");
+    /// ```
+    #[inline]
+    fn to_string_pretty_with(value: &Self::Target, _opts: &PrettyOptions) -> Result<String, Self::Error> { Self::to_string_pretty(value) }
+
+    /// Serializes the given value to a byte buffer in accordance with the
+    /// backend implementation.
+    ///
+    /// By default, this is implemented by serializing to an in-memory buffer
+    /// using [`Self::to_writer()`](Serializer::to_writer()). Backends may
+    /// override this if they have a more efficient way of doing so.
+    ///
+    /// # Arguments
+    /// - `value`: The value to serialize.
+    ///
+    /// # Returns
+    /// A buffer with the serialized value.
+    ///
+    /// # Errors
+    /// This function may error if the given value was not serializable in its
+    /// current state.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use serializable::dummy::Serializer;
+    /// use serializable::Serializer as _;
+    ///
+    /// // Note: the dummy parser actually doesn't serialize or deserialize, see the features for proper ones
+    /// assert_eq!(Serializer::to_bytes(&42u8).unwrap(), b"<dummy_text>");
+    /// assert_eq!(Serializer::to_bytes(&String::from("42")).unwrap(), b"<dummy_text>");
+    /// assert_eq!(Serializer::to_bytes(&true).unwrap(), b"<dummy_text>");
+    /// ```
+    #[inline]
+    fn to_bytes(value: &Self::Target) -> Result<Vec<u8>, Self::Error> {
+        let mut buf: Vec<u8> = Vec::new();
+        Self::to_writer(value, &mut buf)?;
+        Ok(buf)
+    }
+    /// Serializes the given value to a byte buffer in accordance with the
+    /// backend implementation's pretty serialization.
+    ///
+    /// By default, this is implemented by serializing to an in-memory buffer
+    /// using [`Self::to_writer_pretty()`](Serializer::to_writer_pretty()).
+    /// Backends may override this if they have a more efficient way of doing
+    /// so.
+    ///
+    /// # Arguments
+    /// - `value`: The value to serialize.
+    ///
+    /// # Returns
+    /// A buffer with the serialized value.
+    ///
+    /// # Errors
+    /// This function may error if the given value was not serializable in its
+    /// current state.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use serializable::dummy::Serializer;
+    /// use serializable::Serializer as _;
+    ///
+    /// // Note: the dummy parser actually doesn't serialize or deserialize, see the features for proper ones
+    /// assert_eq!(Serializer::to_bytes_pretty(&42u8).unwrap(), b"Dummy Text");
+    /// assert_eq!(Serializer::to_bytes_pretty(&String::from("42")).unwrap(), b"Dummy Text");
+    /// assert_eq!(Serializer::to_bytes_pretty(&true).unwrap(), b"Dummy Text");
+    /// ```
+    #[inline]
+    fn to_bytes_pretty(value: &Self::Target) -> Result<Vec<u8>, Self::Error> {
+        let mut buf: Vec<u8> = Vec::new();
+        Self::to_writer_pretty(value, &mut buf)?;
+        Ok(buf)
+    }
+    /// Serializes the given value to a byte buffer in accordance with the
+    /// backend implementation's pretty serialization, honouring the given
+    /// [`PrettyOptions`] as far as the backend can.
+    ///
+    /// Backends that don't support any of the options can use the default
+    /// implementation, which is simply an alias for
+    /// [`Self::to_bytes_pretty()`](Serializer::to_bytes_pretty()).
+    ///
+    /// # Arguments
+    /// - `value`: The value to serialize.
+    /// - `opts`: The [`PrettyOptions`] to honour.
+    ///
+    /// # Returns
+    /// A buffer with the serialized value.
+    ///
+    /// # Errors
+    /// This function may error if the given value was not serializable in its
+    /// current state.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use serializable::dummy::Serializer;
+    /// use serializable::{PrettyOptions, Serializer as _};
+    ///
+    /// // Note: the dummy parser actually doesn't serialize or deserialize, see the features for proper ones
+    /// let opts = PrettyOptions { indent: Some("    "), ..Default::default() };
+    /// assert_eq!(Serializer::to_bytes_pretty_with(&42u8, &opts).unwrap(), b"Dummy Text");
+    /// ```
+    #[inline]
+    fn to_bytes_pretty_with(value: &Self::Target, _opts: &PrettyOptions) -> Result<Vec<u8>, Self::Error> { Self::to_bytes_pretty(value) }
+    /// Serializes the given value to a byte buffer in the backend's
+    /// canonical form, if it has one.
+    ///
+    /// A canonical form is one in which two semantically equal values always
+    /// serialize to the same bytes (e.g., regardless of the iteration order of
+    /// a `HashMap`), which makes it suitable for signing (see
+    /// [`Serializable::to_path_signed()`](crate::Serializable::to_path_signed())).
+    /// Its output must still be deserializable by the backend.
+    ///
+    /// Backends without a canonical form can use the default implementation,
+    /// which is simply an alias for [`Self::to_bytes()`](Serializer::to_bytes()).
+    ///
+    /// # Arguments
+    /// - `value`: The value to serialize.
+    ///
+    /// # Returns
+    /// A buffer with the serialized value.
+    ///
+    /// # Errors
+    /// This function may error if the given value was not serializable in its
+    /// current state.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use serializable::dummy::Serializer;
+    /// use serializable::Serializer as _;
+    ///
+    /// // Note: the dummy parser actually doesn't serialize or deserialize, see the features for proper ones
+    /// assert_eq!(Serializer::to_bytes_canonical(&42u8).unwrap(), b"<dummy_text>");
+    /// ```
+    #[inline]
+    fn to_bytes_canonical(value: &Self::Target) -> Result<Vec<u8>, Self::Error> { Self::to_bytes(value) }
 
     /// Serializes the given value to the given writer in accordance with the
     /// backend implementation.
@@ -162,6 +327,39 @@ pub trait Serializer {
     /// ```
     #[inline]
     fn to_writer_pretty(value: &Self::Target, writer: impl Write) -> Result<(), Self::Error> { Self::to_writer(value, writer) }
+    /// Serializes the given value to the given writer in accordance with the
+    /// backend implementation's pretty serialization, honouring the given
+    /// [`PrettyOptions`] as far as the backend can.
+    ///
+    /// Backends that don't support any of the options can use the default
+    /// implementation, which is simply an alias for
+    /// [`Self::to_writer_pretty()`](Serializer::to_writer_pretty()).
+    ///
+    /// # Arguments
+    /// - `value`: The value to serialize.
+    /// - `opts`: The [`PrettyOptions`] to honour.
+    /// - `writer`: The [`Write`]r to serialize to.
+    ///
+    /// # Errors
+    /// This function may error if the given value was not serializable in its
+    /// current state, or if it failed to write to the given `writer`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use serializable::dummy::Serializer;
+    /// use serializable::{PrettyOptions, Serializer as _};
+    ///
+    /// let mut buf: [u8; 10] = [0; 10];
+    ///
+    /// // Note: the dummy parser actually doesn't serialize or deserialize, see the features for proper ones
+    /// let opts = PrettyOptions { indent: Some("    "), ..Default::default() };
+    /// Serializer::to_writer_pretty_with(&42u8, &opts, &mut buf[..]).unwrap();
+    /// assert_eq!(String::from_utf8_lossy(&buf), "Dummy Text");
+    /// ```
+    #[inline]
+    fn to_writer_pretty_with(value: &Self::Target, _opts: &PrettyOptions, writer: impl Write) -> Result<(), Self::Error> {
+        Self::to_writer_pretty(value, writer)
+    }
 
     /// Deserializes the given string as a representation for the target type in
     /// the backend format.
@@ -188,6 +386,36 @@ pub trait Serializer {
     /// assert_eq!(Serializer::<bool>::from_str("true").unwrap(), false);
     /// ```
     fn from_str(raw: impl AsRef<str>) -> Result<Self::Target, Self::Error>;
+    /// Deserializes the given byte buffer as a representation for the target
+    /// type in the backend format.
+    ///
+    /// By default, this is implemented by reading from the buffer using
+    /// [`Self::from_reader()`](Serializer::from_reader()). Backends may
+    /// override this if they have a more efficient way of doing so.
+    ///
+    /// # Arguments
+    /// - `raw`: The buffer that contains the serialized representation of the
+    ///   target.
+    ///
+    /// # Returns
+    /// The deserialized target.
+    ///
+    /// # Errors
+    /// This function may error if the given `raw` is not a valid representation
+    /// for a target in the backend format.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use serializable::dummy::Serializer;
+    /// use serializable::Serializer as _;
+    ///
+    /// // Note: the dummy parser actually doesn't serialize or deserialize, see the features for proper ones
+    /// assert_eq!(Serializer::<u8>::from_bytes(b"42").unwrap(), 0);
+    /// assert_eq!(Serializer::<String>::from_bytes(b"42").unwrap(), "");
+    /// assert_eq!(Serializer::<bool>::from_bytes(b"true").unwrap(), false);
+    /// ```
+    #[inline]
+    fn from_bytes(raw: impl AsRef<[u8]>) -> Result<Self::Target, Self::Error> { Self::from_reader(raw.as_ref()) }
     /// Deserializes the contents of the given reader as a representation for
     /// the target type in the backend format.
     ///
@@ -214,6 +442,81 @@ pub trait Serializer {
     /// assert_eq!(Serializer::<bool>::from_reader("true".as_bytes()).unwrap(), false);
     /// ```
     fn from_reader(reader: impl Read) -> Result<Self::Target, Self::Error>;
+
+    /// Finds where in the input the given deserialization error occurred.
+    ///
+    /// Not all backends track this. If not, then the default implementation
+    /// can be used, which simply always returns [`None`].
+    ///
+    /// # Arguments
+    /// - `err`: The error returned by one of the deserialization functions.
+    /// - `raw`: The input that was being deserialized, if it's available.
+    ///   Some backends need it to compute the full [`Location`].
+    ///
+    /// # Returns
+    /// The [`Location`] of the error, or [`None`] if the error has no
+    /// location (e.g., it's an I/O error) or the backend doesn't know it.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use serializable::dummy::{Error, Serializer};
+    /// use serializable::Serializer as _;
+    ///
+    /// // Note: the dummy parser doesn't track locations, see the features for proper ones
+    /// let err = Error::Read { err: std::io::ErrorKind::UnexpectedEof.into() };
+    /// assert_eq!(Serializer::<u8>::location(&err, Some("42")), None);
+    /// ```
+    #[inline]
+    fn location(_err: &Self::Error, _raw: Option<&str>) -> Option<Location> { None }
+
+    /// Finds the path to the field at which the given deserialization error
+    /// occurred.
+    ///
+    /// Not all backends track this. If not, then the default implementation
+    /// can be used, which simply always returns an empty path.
+    ///
+    /// # Arguments
+    /// - `err`: The error returned by one of the deserialization functions.
+    ///
+    /// # Returns
+    /// A list of [`PathSegment`]s leading to the field that failed (e.g.,
+    /// `servers[3].tls.cert_path`). It is empty if the error has no path or
+    /// the backend doesn't know it.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use serializable::dummy::{Error, Serializer};
+    /// use serializable::Serializer as _;
+    ///
+    /// // Note: the dummy parser doesn't track paths, see the features for proper ones
+    /// let err = Error::Read { err: std::io::ErrorKind::UnexpectedEof.into() };
+    /// assert!(Serializer::<u8>::field_path(&err).is_empty());
+    /// ```
+    #[inline]
+    fn field_path(_err: &Self::Error) -> Vec<PathSegment> { Vec::new() }
+}
+
+
+
+/// Defines a marker for a family of [`Serializer`]s, which can be used to select a backend without
+/// having to name the target type.
+///
+/// Every library-provided backend has one (e.g., [`dummy::Dummy`](crate::dummy::Dummy) for the
+/// [dummy serializer](crate::dummy::Serializer)). It is mostly used to select a backend when
+/// calling functions on [`SerializableAs`](crate::SerializableAs), which helps types that implement
+/// [`Serializable`](crate::Serializable) for multiple backends.
+///
+/// # Example
+/// ```rust
+/// use serializable::dummy::Dummy;
+/// use serializable::{Backend, Serializer};
+///
+/// // Note: the dummy parser actually doesn't serialize or deserialize, see the features for proper ones
+/// assert_eq!(<<Dummy as Backend>::Serializer<u8> as Serializer>::to_string(&42).unwrap(), "<dummy_text>");
+/// ```
+pub trait Backend {
+    /// The [`Serializer`] of this backend for the given target type.
+    type Serializer<T>;
 }
 
 
@@ -301,6 +604,43 @@ where
     async fn to_writer_pretty_async(value: &Self::Target, writer: impl Send + std::marker::Unpin + tokio::io::AsyncWrite) -> Result<(), Self::Error> {
         Self::to_writer_async(value, writer).await
     }
+    /// Serializes the given value to the given writer asynchronously in accordance with the
+    /// backend implementation's pretty serialization, honouring the given [`PrettyOptions`] as
+    /// far as the backend can.
+    ///
+    /// Backends that don't support any of the options can use the default implementation, which
+    /// is simply an alias for [`Self::to_writer_pretty_async()`](SerializerAsync::to_writer_pretty_async()).
+    ///
+    /// # Arguments
+    /// - `value`: The value to serialize.
+    /// - `opts`: The [`PrettyOptions`] to honour.
+    /// - `writer`: The [`Write`]r to serialize to.
+    ///
+    /// # Errors
+    /// This function may error if the given value was not serializable in its
+    /// current state, or if it failed to write to the given `writer`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use serializable::dummy::Serializer;
+    /// use serializable::{PrettyOptions, SerializerAsync as _};
+    ///
+    /// # tokio_test::block_on(async {
+    /// // Note: the dummy parser actually doesn't serialize or deserialize, see the features for proper ones
+    /// let opts = PrettyOptions { indent: Some("    "), ..Default::default() };
+    /// let mut buf: Vec<u8> = Vec::new();
+    /// Serializer::to_writer_pretty_with_async(&42u8, &opts, &mut buf).await.unwrap();
+    /// assert_eq!(String::from_utf8_lossy(&buf), "Dummy Text");
+    /// # });
+    /// ```
+    #[inline]
+    async fn to_writer_pretty_with_async(
+        value: &Self::Target,
+        _opts: &PrettyOptions,
+        writer: impl Send + std::marker::Unpin + tokio::io::AsyncWrite,
+    ) -> Result<(), Self::Error> {
+        Self::to_writer_pretty_async(value, writer).await
+    }
 
     /// Deserializes the contents of the given reader asynchronously as a representation for
     /// the target type in the backend format.
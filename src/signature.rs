@@ -0,0 +1,100 @@
+//  SIGNATURE.rs
+//    by Lut99
+//
+//  Created:
+//    17 Oct 2026, 01:02:51
+//  Last edited:
+//    17 Oct 2026, 11:19:30
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Defines the detached [Ed25519](https://ed25519.cr.yp.to/) signatures
+//!   with which files can be signed when they're written, and verified
+//!   against a set of trusted keys when they're read back.
+//
+
+use std::ffi::OsString;
+use std::path::{Path, PathBuf};
+
+pub use ed25519_dalek::{Signature, SigningKey, VerifyingKey};
+
+
+/***** CONSTANTS *****/
+/// The extension (without leading dot) that is appended to a file's path to get the path of its
+/// signature file.
+pub const EXTENSION: &str = "sig";
+
+
+
+
+
+/***** LIBRARY *****/
+/// Returns the path of the signature file of the given file.
+///
+/// # Arguments
+/// - `path`: The path of the file to return the signature file of.
+///
+/// # Returns
+/// The given path with `.sig` appended to it (e.g., `policy.json.sig`).
+///
+/// # Examples
+/// ```rust
+/// use serializable::signature;
+///
+/// assert_eq!(signature::path("policy.json").to_str(), Some("policy.json.sig"));
+/// ```
+#[inline]
+pub fn path(path: impl AsRef<Path>) -> PathBuf {
+    let mut sig: OsString = path.as_ref().as_os_str().to_owned();
+    sig.push(".");
+    sig.push(EXTENSION);
+    sig.into()
+}
+
+
+
+/// Signs the given input.
+///
+/// # Arguments
+/// - `key`: The [`SigningKey`] to sign with.
+/// - `raw`: The input to sign.
+///
+/// # Returns
+/// The signature, in lowercase hexadecimal.
+#[inline]
+pub(crate) fn sign(key: &SigningKey, raw: &[u8]) -> String {
+    use ed25519_dalek::Signer as _;
+    key.sign(raw).to_bytes().iter().map(|b| format!("{b:02x}")).collect()
+}
+
+/// Parses a signature as written by [`sign()`].
+///
+/// # Arguments
+/// - `raw`: The signature, in hexadecimal (surrounding whitespace is ignored).
+///
+/// # Returns
+/// The parsed [`Signature`], or [`None`] if `raw` isn't one.
+pub(crate) fn parse(raw: &str) -> Option<Signature> {
+    let raw: &[u8] = raw.trim().as_bytes();
+    if raw.len() != 2 * Signature::BYTE_SIZE || !raw.iter().all(u8::is_ascii_hexdigit) {
+        return None;
+    }
+    let mut sig: [u8; Signature::BYTE_SIZE] = [0; Signature::BYTE_SIZE];
+    for (byte, pair) in sig.iter_mut().zip(raw.chunks_exact(2)) {
+        *byte = u8::from_str_radix(std::str::from_utf8(pair).ok()?, 16).ok()?;
+    }
+    Some(Signature::from_bytes(&sig))
+}
+
+/// Verifies the given input against the given signature.
+///
+/// # Arguments
+/// - `trusted`: The [`VerifyingKey`]s of the signers to trust.
+/// - `raw`: The input that was signed.
+/// - `sig`: The [`Signature`] to verify.
+///
+/// # Returns
+/// True if `sig` is a valid signature of `raw` by any of the `trusted` keys, or false otherwise.
+#[inline]
+pub(crate) fn verify(trusted: &[VerifyingKey], raw: &[u8], sig: &Signature) -> bool { trusted.iter().any(|key| key.verify_strict(raw, sig).is_ok()) }
@@ -4,7 +4,7 @@
 //  Created:
 //    28 Oct 2023, 13:05:57
 //  Last edited:
-//    30 Oct 2023, 12:32:57
+//    17 Oct 2026, 02:06:18
 //  Auto updated?
 //    Yes
 //
@@ -19,6 +19,8 @@ use std::marker::PhantomData;
 
 use serde::{Deserialize, Serialize};
 
+use crate::location::{deserialize_tracked, Location, PathSegment};
+use crate::pretty::{self, PrettyOptions};
 use crate::serializer;
 
 
@@ -36,7 +38,42 @@ pub enum Error {
     /// Failed to serialize the object to TOML.
     Serialize { err: toml::ser::Error },
     /// Failed to deserialize the object from TOML.
-    Deserialize { err: toml::de::Error },
+    ///
+    /// `field_path` is the path to the field that failed (see [`Error::field_path()`]).
+    Deserialize { field_path: Vec<PathSegment>, err: toml::de::Error },
+}
+impl Error {
+    /// Finds where in the input this error occurred.
+    ///
+    /// The location is only available for errors that occurred while deserializing, and only if
+    /// `raw` is given, as TOML errors only carry a byte range.
+    ///
+    /// # Arguments
+    /// - `raw`: The input that was being deserialized, if it's available.
+    ///
+    /// # Returns
+    /// The [`Location`] of the error, or [`None`] if it has none.
+    #[inline]
+    pub fn location(&self, raw: Option<&str>) -> Option<Location> {
+        match self {
+            Self::Deserialize { err, .. } => Some(Location::from_offset(raw?, err.span()?.start)),
+            _ => None,
+        }
+    }
+
+    /// Returns the path to the field at which this error occurred.
+    ///
+    /// # Returns
+    /// A list of [`PathSegment`]s leading to the field (e.g., `servers[3].tls.cert_path`). It is
+    /// empty if this error has no path (e.g., it didn't occur while deserializing, or it occurred
+    /// at the root).
+    #[inline]
+    pub fn field_path(&self) -> Vec<PathSegment> {
+        match self {
+            Self::Deserialize { field_path, .. } => field_path.clone(),
+            _ => Vec::new(),
+        }
+    }
 }
 #[cfg(feature = "serde-toml")]
 impl Display for Error {
@@ -62,7 +99,7 @@ impl error::Error for Error {
             #[cfg(feature = "async-tokio")]
             Flush { err } => Some(err),
             Serialize { err } => Some(err),
-            Deserialize { err } => Some(err),
+            Deserialize { err, .. } => Some(err),
         }
     }
 }
@@ -71,9 +108,63 @@ impl error::Error for Error {
 
 
 
+/***** HELPERS *****/
+/// Recursively sorts the keys of all tables in the given TOML value.
+///
+/// # Arguments
+/// - `value`: The [`toml::Value`] to sort.
+///
+/// # Returns
+/// The same value, but with the keys of all its tables in lexicographical order.
+fn sort_keys(value: toml::Value) -> toml::Value {
+    use toml::Value;
+    match value {
+        Value::Table(values) => {
+            // NOTE: Tables are not necessarily sorted already, as `toml/preserve_order` may be enabled
+            let mut entries: Vec<(String, Value)> = values.into_iter().map(|(key, value)| (key, sort_keys(value))).collect();
+            entries.sort_by(|(lhs, _), (rhs, _)| lhs.cmp(rhs));
+            Value::Table(entries.into_iter().collect())
+        },
+        Value::Array(values) => Value::Array(values.into_iter().map(sort_keys).collect()),
+        value => value,
+    }
+}
+
+/// Serializes the given value to pretty TOML, honouring the given [`PrettyOptions`].
+///
+/// Only [`PrettyOptions::sort_keys`] and [`PrettyOptions::trailing_newline`] are supported, as
+/// [`toml`] doesn't allow tweaking its layout.
+///
+/// # Arguments
+/// - `value`: The value to serialize.
+/// - `opts`: The [`PrettyOptions`] to honour.
+///
+/// # Returns
+/// The serialized value.
+///
+/// # Errors
+/// This function errors if the value failed to serialize to TOML.
+fn serialize_pretty<T: Serialize>(value: &T, opts: &PrettyOptions) -> Result<String, Error> {
+    let raw: String = if opts.sort_keys {
+        let value: toml::Value = toml::Value::try_from(value).map_err(|err| Error::Serialize { err })?;
+        toml::to_string_pretty(&sort_keys(value))
+    } else {
+        toml::to_string_pretty(value)
+    }
+    .map_err(|err| Error::Serialize { err })?;
+    Ok(pretty::finish(raw, opts))
+}
+
+
+
+
+
 /***** LIBRARY *****/
 /// Implements a [`serializer::Serializer`] for [`toml`].
 ///
+/// Of the [`PrettyOptions`], only [`PrettyOptions::sort_keys`] and
+/// [`PrettyOptions::trailing_newline`] are honoured.
+///
 /// # Examples
 /// ```rust
 /// use serde::{Deserialize, Serialize};
@@ -100,6 +191,16 @@ impl error::Error for Error {
 #[derive(Clone, Copy, Debug)]
 pub struct Serializer<T>(PhantomData<T>);
 
+/// Marks the TOML [`Serializer`] as a [`Backend`](crate::Backend).
+///
+/// Use it to select this backend in [`SerializableAs`](crate::SerializableAs) (e.g.,
+/// `value.to_string_as::<Toml>()`).
+#[derive(Clone, Copy, Debug)]
+pub struct Toml;
+impl serializer::Backend for Toml {
+    type Serializer<T> = Serializer<T>;
+}
+
 impl<T: for<'de> Deserialize<'de> + Serialize> serializer::Serializer for Serializer<T> {
     type Error = Error;
     type Target = T;
@@ -110,6 +211,14 @@ impl<T: for<'de> Deserialize<'de> + Serialize> serializer::Serializer for Serial
     #[inline]
     fn to_string_pretty(value: &Self::Target) -> Result<String, Self::Error> { toml::to_string_pretty(value).map_err(|err| Error::Serialize { err }) }
 
+    #[inline]
+    fn to_string_pretty_with(value: &Self::Target, opts: &PrettyOptions) -> Result<String, Self::Error> { serialize_pretty(value, opts) }
+
+    #[inline]
+    fn to_bytes_pretty_with(value: &Self::Target, opts: &PrettyOptions) -> Result<Vec<u8>, Self::Error> {
+        serialize_pretty(value, opts).map(String::into_bytes)
+    }
+
     #[inline]
     fn to_writer(value: &Self::Target, mut writer: impl Write) -> Result<(), Self::Error> {
         // Write to string first
@@ -141,7 +250,19 @@ impl<T: for<'de> Deserialize<'de> + Serialize> serializer::Serializer for Serial
     }
 
     #[inline]
-    fn from_str(raw: impl AsRef<str>) -> Result<Self::Target, Self::Error> { toml::from_str(raw.as_ref()).map_err(|err| Error::Deserialize { err }) }
+    fn to_writer_pretty_with(value: &Self::Target, opts: &PrettyOptions, mut writer: impl Write) -> Result<(), Self::Error> {
+        // Write to string first
+        let raw: String = serialize_pretty(value, opts)?;
+
+        // Then write to the writer
+        match writer.write_all(raw.as_bytes()) {
+            Ok(_) => Ok(()),
+            Err(err) => Err(Error::Write { err }),
+        }
+    }
+
+    #[inline]
+    fn from_str(raw: impl AsRef<str>) -> Result<Self::Target, Self::Error> { deserialize_tracked(toml::Deserializer::new(raw.as_ref())).map_err(|(field_path, err)| Error::Deserialize { field_path, err }) }
 
     #[inline]
     fn from_reader(mut reader: impl Read) -> Result<Self::Target, Self::Error> {
@@ -152,11 +273,17 @@ impl<T: for<'de> Deserialize<'de> + Serialize> serializer::Serializer for Serial
         }
 
         // Now deserialize using the string edition
-        match toml::from_str(&raw) {
+        match deserialize_tracked(toml::Deserializer::new(&raw)) {
             Ok(res) => Ok(res),
-            Err(err) => Err(Error::Deserialize { err }),
+            Err((field_path, err)) => Err(Error::Deserialize { field_path, err }),
         }
     }
+
+    #[inline]
+    fn location(err: &Self::Error, raw: Option<&str>) -> Option<Location> { err.location(raw) }
+
+    #[inline]
+    fn field_path(err: &Self::Error) -> Vec<PathSegment> { err.field_path() }
 }
 
 #[cfg(feature = "async-tokio")]
@@ -201,6 +328,28 @@ impl<T: Send + Sync + for<'de> Deserialize<'de> + Serialize> serializer::Seriali
         }
     }
 
+    #[inline]
+    async fn to_writer_pretty_with_async(
+        value: &Self::Target,
+        opts: &PrettyOptions,
+        mut writer: impl Send + std::marker::Unpin + tokio::io::AsyncWrite,
+    ) -> Result<(), Self::Error> {
+        use tokio::io::AsyncWriteExt;
+
+        // Serialize ourselves to a string first
+        let raw: String = <Self as serializer::Serializer>::to_string_pretty_with(value, opts)?;
+
+        // Now write to the writer
+        if let Err(err) = writer.write_all(raw.as_bytes()).await {
+            return Err(Error::Write { err });
+        }
+        // ...making sure its flushed
+        match writer.flush().await {
+            Ok(_) => Ok(()),
+            Err(err) => Err(Error::Flush { err }),
+        }
+    }
+
     #[inline]
     async fn from_reader_async(mut reader: impl Send + std::marker::Unpin + tokio::io::AsyncRead) -> Result<T, Self::Error> {
         use tokio::io::AsyncReadExt;
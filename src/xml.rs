@@ -0,0 +1,360 @@
+//  XML.rs
+//    by Lut99
+//
+//  Created:
+//    16 Oct 2026, 21:15:04
+//  Last edited:
+//    17 Oct 2026, 02:06:18
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Implements [`serializer::Serializer`] and cohorts for [`quick_xml`].
+//
+
+use std::any::type_name;
+use std::error;
+use std::fmt::{Display, Formatter, Result as FResult};
+use std::io::{BufReader, Read, Write};
+use std::marker::PhantomData;
+
+use serde::{Deserialize, Serialize};
+
+use crate::pretty::{self, PrettyOptions};
+use crate::serializer;
+
+
+/***** ERRORS *****/
+/// Defines errors that occur when using the XML [`Serializer`].
+#[derive(Debug)]
+pub enum Error {
+    /// Failed to write to the given writer.
+    Write { err: std::io::Error },
+    /// Failed to read from the given reader.
+    #[cfg(feature = "async-tokio")]
+    Read { err: std::io::Error },
+    /// Failed to flush the given writer.
+    #[cfg(feature = "async-tokio")]
+    Flush { err: std::io::Error },
+    /// Failed to serialize the object to XML.
+    Serialize { err: quick_xml::SeError },
+    /// Failed to deserialize the object from XML.
+    Deserialize { err: quick_xml::DeError },
+}
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FResult {
+        use Error::*;
+        match self {
+            Write { .. } => write!(f, "Failed to write to given writer"),
+            #[cfg(feature = "async-tokio")]
+            Read { .. } => write!(f, "Failed to read from given reader"),
+            #[cfg(feature = "async-tokio")]
+            Flush { .. } => write!(f, "Failed to flush the given writer"),
+            Serialize { .. } => write!(f, "Failed to serialize to XML"),
+            Deserialize { .. } => write!(f, "Failed to deserialize from XML"),
+        }
+    }
+}
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        use Error::*;
+        match self {
+            Write { err } => Some(err),
+            #[cfg(feature = "async-tokio")]
+            Read { err } => Some(err),
+            #[cfg(feature = "async-tokio")]
+            Flush { err } => Some(err),
+            Serialize { err } => Some(err),
+            Deserialize { err } => Some(err),
+        }
+    }
+}
+
+
+
+
+
+/***** HELPER FUNCTIONS *****/
+/// Serializes a value to an XML string.
+///
+/// # Arguments
+/// - `value`: The value to serialize.
+/// - `pretty`: The [`PrettyOptions`] to indent the output with, or [`None`] to not indent it. The
+///   indentation of the given [`Options`] is used, unless [`PrettyOptions::indent`] is given and
+///   consists of a single (repeated) character.
+///
+/// # Returns
+/// The serialized XML.
+///
+/// # Errors
+/// This function errors if the root element name was invalid or if the value failed to serialize.
+fn serialize<T: Serialize, O: Options>(value: &T, pretty: Option<&PrettyOptions>) -> Result<String, Error> {
+    // Resolve the name of the root element
+    let root: &'static str = match O::ROOT {
+        Some(root) => root,
+        None => {
+            // Use the last segment of the type name, without generics
+            let name: &'static str = type_name::<T>();
+            let name: &'static str = name.split('<').next().unwrap_or(name);
+            name.rsplit("::").next().unwrap_or(name)
+        },
+    };
+
+    // Serialize with it
+    let mut res: String = String::new();
+    let mut ser = quick_xml::se::Serializer::with_root(&mut res, Some(root)).map_err(|err| Error::Serialize { err })?;
+    if let Some(opts) = pretty {
+        // quick-xml only supports repeating a single character
+        let (indent_char, indent_size): (char, usize) = match opts.indent.map(|indent| (indent, indent.chars().next())) {
+            Some((indent, Some(c))) if indent.chars().all(|d| d == c) => (c, indent.chars().count()),
+            Some((_, None)) => (O::INDENT_CHAR, 0),
+            _ => (O::INDENT_CHAR, O::INDENT_SIZE),
+        };
+        ser.indent(indent_char, indent_size);
+    }
+    value.serialize(ser).map_err(|err| Error::Serialize { err })?;
+    Ok(match pretty {
+        Some(opts) => pretty::finish(res, opts),
+        None => res,
+    })
+}
+
+
+
+
+
+/***** LIBRARY *****/
+/// Configures the XML [`Serializer`].
+///
+/// All items have defaults, so implementations only have to override what they need.
+///
+/// # Examples
+/// ```rust
+/// use serde::{Deserialize, Serialize};
+/// use serializable::xml::{Options, Serializer};
+/// use serializable::Serializable;
+///
+/// #[derive(Debug, Deserialize, Eq, PartialEq, Serialize)]
+/// struct HelloWorld {
+///     hello: String,
+///     world: String,
+/// }
+///
+/// struct Greeting;
+/// impl Options for Greeting {
+///     const ROOT: Option<&'static str> = Some("greeting");
+///     const INDENT_SIZE: usize = 4;
+/// }
+/// impl Serializable<Serializer<HelloWorld, Greeting>> for HelloWorld {}
+///
+/// assert_eq!(
+///     HelloWorld { hello: "Hello".into(), world: "World".into() }.to_string_pretty().unwrap(),
+///     "<greeting>\n    <hello>Hello</hello>\n    <world>World</world>\n</greeting>"
+/// );
+/// ```
+pub trait Options {
+    /// The name of the root element. If [`None`], defaults to the name of the serialized type
+    /// (i.e., the last segment of its [`type_name()`], without generics).
+    const ROOT: Option<&'static str> = None;
+    /// The character to indent pretty output with.
+    const INDENT_CHAR: char = ' ';
+    /// The number of [`Self::INDENT_CHAR`]s per level of indentation in pretty output.
+    const INDENT_SIZE: usize = 2;
+}
+
+/// The default [`Options`] for the XML [`Serializer`].
+#[derive(Clone, Copy, Debug)]
+pub struct DefaultOptions;
+impl Options for DefaultOptions {}
+
+
+
+/// Implements a [`serializer::Serializer`] for [`quick_xml`].
+///
+/// The serializer can be configured (e.g., its root element name or indentation) by giving it
+/// custom [`Options`]. Of the [`PrettyOptions`], only [`PrettyOptions::trailing_newline`] and
+/// [`PrettyOptions::indent`] (if it repeats a single character) are honoured.
+///
+/// # Examples
+/// ```rust
+/// use serde::{Deserialize, Serialize};
+/// use serializable::xml::Serializer;
+/// use serializable::Serializable;
+///
+/// #[derive(Debug, Deserialize, Eq, PartialEq, Serialize)]
+/// struct HelloWorld {
+///     hello: String,
+///     world: String,
+/// }
+/// impl Serializable<Serializer<HelloWorld>> for HelloWorld {}
+///
+/// assert_eq!(
+///     HelloWorld { hello: "Hello".into(), world: "World".into() }.to_string().unwrap(),
+///     "<HelloWorld><hello>Hello</hello><world>World</world></HelloWorld>"
+/// );
+/// assert_eq!(
+///     HelloWorld { hello: "Hello".into(), world: "World".into() }.to_string_pretty().unwrap(),
+///     "<HelloWorld>\n  <hello>Hello</hello>\n  <world>World</world>\n</HelloWorld>"
+/// );
+///
+/// assert_eq!(
+///     HelloWorld::from_str("<HelloWorld><hello>Goodbye</hello><world>Planet</world></HelloWorld>").unwrap(),
+///     HelloWorld { hello: "Goodbye".into(), world: "Planet".into() }
+/// )
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct Serializer<T, O = DefaultOptions>(PhantomData<(T, O)>);
+
+/// Marks the XML [`Serializer`] with its default options as a [`Backend`](crate::Backend).
+///
+/// Use it to select this backend in [`SerializableAs`](crate::SerializableAs) (e.g.,
+/// `value.to_string_as::<Xml>()`).
+#[derive(Clone, Copy, Debug)]
+pub struct Xml;
+impl serializer::Backend for Xml {
+    type Serializer<T> = Serializer<T>;
+}
+
+impl<T: for<'de> Deserialize<'de> + Serialize, O: Options> serializer::Serializer for Serializer<T, O> {
+    type Error = Error;
+    type Target = T;
+
+    #[inline]
+    fn to_string(value: &Self::Target) -> Result<String, Self::Error> { serialize::<T, O>(value, None) }
+
+    #[inline]
+    fn to_string_pretty(value: &Self::Target) -> Result<String, Self::Error> { serialize::<T, O>(value, Some(&PrettyOptions::DEFAULT)) }
+
+    #[inline]
+    fn to_string_pretty_with(value: &Self::Target, opts: &PrettyOptions) -> Result<String, Self::Error> { serialize::<T, O>(value, Some(opts)) }
+
+    #[inline]
+    fn to_bytes_pretty_with(value: &Self::Target, opts: &PrettyOptions) -> Result<Vec<u8>, Self::Error> {
+        serialize::<T, O>(value, Some(opts)).map(String::into_bytes)
+    }
+
+    #[inline]
+    fn to_writer(value: &Self::Target, mut writer: impl Write) -> Result<(), Self::Error> {
+        // Write to string first
+        let raw: String = serialize::<T, O>(value, None)?;
+
+        // Then write to the writer
+        match writer.write_all(raw.as_bytes()) {
+            Ok(_) => Ok(()),
+            Err(err) => Err(Error::Write { err }),
+        }
+    }
+
+    #[inline]
+    fn to_writer_pretty(value: &Self::Target, mut writer: impl Write) -> Result<(), Self::Error> {
+        // Write to string first
+        let raw: String = serialize::<T, O>(value, Some(&PrettyOptions::DEFAULT))?;
+
+        // Then write to the writer
+        match writer.write_all(raw.as_bytes()) {
+            Ok(_) => Ok(()),
+            Err(err) => Err(Error::Write { err }),
+        }
+    }
+
+    #[inline]
+    fn to_writer_pretty_with(value: &Self::Target, opts: &PrettyOptions, mut writer: impl Write) -> Result<(), Self::Error> {
+        // Write to string first
+        let raw: String = serialize::<T, O>(value, Some(opts))?;
+
+        // Then write to the writer
+        match writer.write_all(raw.as_bytes()) {
+            Ok(_) => Ok(()),
+            Err(err) => Err(Error::Write { err }),
+        }
+    }
+
+    #[inline]
+    fn from_str(raw: impl AsRef<str>) -> Result<Self::Target, Self::Error> {
+        quick_xml::de::from_str(raw.as_ref()).map_err(|err| Error::Deserialize { err })
+    }
+
+    #[inline]
+    fn from_reader(reader: impl Read) -> Result<Self::Target, Self::Error> {
+        quick_xml::de::from_reader(BufReader::new(reader)).map_err(|err| Error::Deserialize { err })
+    }
+}
+
+#[cfg(feature = "async-tokio")]
+#[async_trait::async_trait]
+impl<T: Send + Sync + for<'de> Deserialize<'de> + Serialize, O: Send + Sync + Options> serializer::SerializerAsync for Serializer<T, O> {
+    #[inline]
+    async fn to_writer_async(value: &Self::Target, mut writer: impl Send + std::marker::Unpin + tokio::io::AsyncWrite) -> Result<(), Self::Error> {
+        use tokio::io::AsyncWriteExt;
+
+        // Serialize ourselves to a string first
+        let raw: String = <Self as serializer::Serializer>::to_string(value)?;
+
+        // Now write to the writer
+        if let Err(err) = writer.write_all(raw.as_bytes()).await {
+            return Err(Error::Write { err });
+        }
+        // ...making sure its flushed
+        match writer.flush().await {
+            Ok(_) => Ok(()),
+            Err(err) => Err(Error::Flush { err }),
+        }
+    }
+
+    #[inline]
+    async fn to_writer_pretty_async(
+        value: &Self::Target,
+        mut writer: impl Send + std::marker::Unpin + tokio::io::AsyncWrite,
+    ) -> Result<(), Self::Error> {
+        use tokio::io::AsyncWriteExt;
+
+        // Serialize ourselves to a string first
+        let raw: String = <Self as serializer::Serializer>::to_string_pretty(value)?;
+
+        // Now write to the writer
+        if let Err(err) = writer.write_all(raw.as_bytes()).await {
+            return Err(Error::Write { err });
+        }
+        // ...making sure its flushed
+        match writer.flush().await {
+            Ok(_) => Ok(()),
+            Err(err) => Err(Error::Flush { err }),
+        }
+    }
+
+    #[inline]
+    async fn to_writer_pretty_with_async(
+        value: &Self::Target,
+        opts: &PrettyOptions,
+        mut writer: impl Send + std::marker::Unpin + tokio::io::AsyncWrite,
+    ) -> Result<(), Self::Error> {
+        use tokio::io::AsyncWriteExt;
+
+        // Serialize ourselves to a string first
+        let raw: String = <Self as serializer::Serializer>::to_string_pretty_with(value, opts)?;
+
+        // Now write to the writer
+        if let Err(err) = writer.write_all(raw.as_bytes()).await {
+            return Err(Error::Write { err });
+        }
+        // ...making sure its flushed
+        match writer.flush().await {
+            Ok(_) => Ok(()),
+            Err(err) => Err(Error::Flush { err }),
+        }
+    }
+
+    #[inline]
+    async fn from_reader_async(mut reader: impl Send + std::marker::Unpin + tokio::io::AsyncRead) -> Result<T, Self::Error> {
+        use tokio::io::AsyncReadExt;
+
+        // Read the entire buffer first
+        let mut raw: String = String::new();
+        if let Err(err) = reader.read_to_string(&mut raw).await {
+            return Err(Error::Read { err });
+        }
+
+        // Then deserialize as string
+        <Self as serializer::Serializer>::from_str(&raw)
+    }
+}
@@ -4,7 +4,7 @@
 //  Created:
 //    28 Oct 2023, 13:04:05
 //  Last edited:
-//    30 Oct 2023, 12:32:59
+//    17 Oct 2026, 02:06:18
 //  Auto updated?
 //    Yes
 //
@@ -13,12 +13,15 @@
 //
 
 use std::error;
+use std::cmp::Ordering;
 use std::fmt::{Display, Formatter, Result as FResult};
 use std::io::{Read, Write};
 use std::marker::PhantomData;
 
 use serde::{Deserialize, Serialize};
 
+use crate::location::{deserialize_tracked, Location, PathSegment};
+use crate::pretty::{self, PrettyOptions};
 use crate::serializer;
 
 
@@ -27,7 +30,6 @@ use crate::serializer;
 #[derive(Debug)]
 pub enum Error {
     /// Failed to write to the given writer.
-    #[cfg(feature = "async-tokio")]
     Write { err: std::io::Error },
     /// Failed to read from the given reader.
     #[cfg(feature = "async-tokio")]
@@ -38,13 +40,49 @@ pub enum Error {
     /// Failed to serialize the object to YAML.
     Serialize { err: serde_yaml::Error },
     /// Failed to deserialize the object from YAML.
-    Deserialize { err: serde_yaml::Error },
+    ///
+    /// `field_path` is the path to the field that failed (see [`Error::field_path()`]).
+    Deserialize { field_path: Vec<PathSegment>, err: serde_yaml::Error },
+}
+impl Error {
+    /// Finds where in the input this error occurred.
+    ///
+    /// The location is only available for errors that occurred while deserializing.
+    ///
+    /// # Arguments
+    /// - `_raw`: The input that was being deserialized, if it's available. This is not needed for
+    ///   YAML, but accepted for consistency with the other backends.
+    ///
+    /// # Returns
+    /// The [`Location`] of the error, or [`None`] if it has none.
+    #[inline]
+    pub fn location(&self, _raw: Option<&str>) -> Option<Location> {
+        match self {
+            Self::Deserialize { err, .. } => {
+                err.location().map(|loc| Location { line: loc.line(), column: loc.column(), byte_offset: Some(loc.index()) })
+            },
+            _ => None,
+        }
+    }
+
+    /// Returns the path to the field at which this error occurred.
+    ///
+    /// # Returns
+    /// A list of [`PathSegment`]s leading to the field (e.g., `servers[3].tls.cert_path`). It is
+    /// empty if this error has no path (e.g., it didn't occur while deserializing, or it occurred
+    /// at the root).
+    #[inline]
+    pub fn field_path(&self) -> Vec<PathSegment> {
+        match self {
+            Self::Deserialize { field_path, .. } => field_path.clone(),
+            _ => Vec::new(),
+        }
+    }
 }
 impl Display for Error {
     fn fmt(&self, f: &mut Formatter<'_>) -> FResult {
         use Error::*;
         match self {
-            #[cfg(feature = "async-tokio")]
             Write { .. } => write!(f, "Failed to write to given writer"),
             #[cfg(feature = "async-tokio")]
             Read { .. } => write!(f, "Failed to read from given reader"),
@@ -59,14 +97,13 @@ impl error::Error for Error {
     fn source(&self) -> Option<&(dyn error::Error + 'static)> {
         use Error::*;
         match self {
-            #[cfg(feature = "async-tokio")]
             Write { err } => Some(err),
             #[cfg(feature = "async-tokio")]
             Read { err } => Some(err),
             #[cfg(feature = "async-tokio")]
             Flush { err } => Some(err),
             Serialize { err } => Some(err),
-            Deserialize { err } => Some(err),
+            Deserialize { err, .. } => Some(err),
         }
     }
 }
@@ -75,13 +112,68 @@ impl error::Error for Error {
 
 
 
+/***** HELPERS *****/
+/// Recursively sorts the keys of all mappings in the given YAML value.
+///
+/// # Arguments
+/// - `value`: The [`serde_yaml::Value`] to sort.
+///
+/// # Returns
+/// The same value, but with the keys of all its mappings in order.
+fn sort_keys(value: serde_yaml::Value) -> serde_yaml::Value {
+    use serde_yaml::Value;
+    match value {
+        Value::Mapping(values) => {
+            let mut entries: Vec<(Value, Value)> = values.into_iter().map(|(key, value)| (key, sort_keys(value))).collect();
+            entries.sort_by(|(lhs, _), (rhs, _)| lhs.partial_cmp(rhs).unwrap_or(Ordering::Equal));
+            Value::Mapping(entries.into_iter().collect())
+        },
+        Value::Sequence(values) => Value::Sequence(values.into_iter().map(sort_keys).collect()),
+        Value::Tagged(mut tagged) => {
+            tagged.value = sort_keys(tagged.value);
+            Value::Tagged(tagged)
+        },
+        value => value,
+    }
+}
+
+/// Serializes the given value to YAML, honouring the given [`PrettyOptions`].
+///
+/// Only [`PrettyOptions::sort_keys`] and [`PrettyOptions::trailing_newline`] are supported, as
+/// [`serde_yaml`] doesn't allow tweaking its layout.
+///
+/// # Arguments
+/// - `value`: The value to serialize.
+/// - `opts`: The [`PrettyOptions`] to honour.
+///
+/// # Returns
+/// The serialized value.
+///
+/// # Errors
+/// This function errors if the value failed to serialize to YAML.
+fn serialize_pretty<T: Serialize>(value: &T, opts: &PrettyOptions) -> Result<String, Error> {
+    let raw: String = if opts.sort_keys {
+        let value: serde_yaml::Value = serde_yaml::to_value(value).map_err(|err| Error::Serialize { err })?;
+        serde_yaml::to_string(&sort_keys(value))
+    } else {
+        serde_yaml::to_string(value)
+    }
+    .map_err(|err| Error::Serialize { err })?;
+    Ok(pretty::finish(raw, opts))
+}
+
+
+
+
+
 /***** LIBRARY *****/
 /// Implements a [`serializer::Serializer`] for [`serde_yaml`].
 ///
 /// Note that this serializer has no pretty version available. As such,
 /// [`serializer::Serializer::to_string_pretty()`] and [`serializer::Serializer::to_writer_pretty()`]
 /// are simply aliases for [`serializer::Serializer::to_string()`] and
-/// [`serializer::Serializer::to_writer()`], respectively.
+/// [`serializer::Serializer::to_writer()`], respectively. Of the [`PrettyOptions`], only
+/// [`PrettyOptions::sort_keys`] and [`PrettyOptions::trailing_newline`] are honoured.
 ///
 /// # Examples
 /// ```rust
@@ -109,6 +201,16 @@ impl error::Error for Error {
 #[derive(Clone, Copy, Debug)]
 pub struct Serializer<T>(PhantomData<T>);
 
+/// Marks the YAML [`Serializer`] as a [`Backend`](crate::Backend).
+///
+/// Use it to select this backend in [`SerializableAs`](crate::SerializableAs) (e.g.,
+/// `value.to_string_as::<Yaml>()`).
+#[derive(Clone, Copy, Debug)]
+pub struct Yaml;
+impl serializer::Backend for Yaml {
+    type Serializer<T> = Serializer<T>;
+}
+
 impl<T: for<'de> Deserialize<'de> + Serialize> serializer::Serializer for Serializer<T> {
     type Error = Error;
     type Target = T;
@@ -116,20 +218,45 @@ impl<T: for<'de> Deserialize<'de> + Serialize> serializer::Serializer for Serial
     #[inline]
     fn to_string(value: &Self::Target) -> Result<String, Self::Error> { serde_yaml::to_string(value).map_err(|err| Error::Serialize { err }) }
 
+    #[inline]
+    fn to_string_pretty_with(value: &Self::Target, opts: &PrettyOptions) -> Result<String, Self::Error> { serialize_pretty(value, opts) }
+
+    #[inline]
+    fn to_bytes_pretty_with(value: &Self::Target, opts: &PrettyOptions) -> Result<Vec<u8>, Self::Error> {
+        serialize_pretty(value, opts).map(String::into_bytes)
+    }
+
     #[inline]
     fn to_writer(value: &Self::Target, writer: impl Write) -> Result<(), Self::Error> {
         serde_yaml::to_writer(writer, value).map_err(|err| Error::Serialize { err })
     }
 
+    #[inline]
+    fn to_writer_pretty_with(value: &Self::Target, opts: &PrettyOptions, mut writer: impl Write) -> Result<(), Self::Error> {
+        let raw: String = serialize_pretty(value, opts)?;
+        writer.write_all(raw.as_bytes()).map_err(|err| Error::Write { err })
+    }
+
     #[inline]
     fn from_str(raw: impl AsRef<str>) -> Result<Self::Target, Self::Error> {
-        serde_yaml::from_str(raw.as_ref()).map_err(|err| Error::Deserialize { err })
+        deserialize_tracked(serde_yaml::Deserializer::from_str(raw.as_ref())).map_err(|(field_path, err)| Error::Deserialize { field_path, err })
+    }
+
+    #[inline]
+    fn from_bytes(raw: impl AsRef<[u8]>) -> Result<Self::Target, Self::Error> {
+        deserialize_tracked(serde_yaml::Deserializer::from_slice(raw.as_ref())).map_err(|(field_path, err)| Error::Deserialize { field_path, err })
     }
 
     #[inline]
     fn from_reader(reader: impl Read) -> Result<Self::Target, Self::Error> {
-        serde_yaml::from_reader(reader).map_err(|err| Error::Deserialize { err })
+        deserialize_tracked(serde_yaml::Deserializer::from_reader(reader)).map_err(|(field_path, err)| Error::Deserialize { field_path, err })
     }
+
+    #[inline]
+    fn location(err: &Self::Error, raw: Option<&str>) -> Option<Location> { err.location(raw) }
+
+    #[inline]
+    fn field_path(err: &Self::Error) -> Vec<PathSegment> { err.field_path() }
 }
 
 #[cfg(feature = "async-tokio")]
@@ -174,6 +301,28 @@ impl<T: Send + Sync + for<'de> Deserialize<'de> + Serialize> serializer::Seriali
         }
     }
 
+    #[inline]
+    async fn to_writer_pretty_with_async(
+        value: &Self::Target,
+        opts: &PrettyOptions,
+        mut writer: impl Send + std::marker::Unpin + tokio::io::AsyncWrite,
+    ) -> Result<(), Self::Error> {
+        use tokio::io::AsyncWriteExt;
+
+        // Serialize ourselves to a string first
+        let raw: String = <Self as serializer::Serializer>::to_string_pretty_with(value, opts)?;
+
+        // Now write to the writer
+        if let Err(err) = writer.write_all(raw.as_bytes()).await {
+            return Err(Error::Write { err });
+        }
+        // ...making sure its flushed
+        match writer.flush().await {
+            Ok(_) => Ok(()),
+            Err(err) => Err(Error::Flush { err }),
+        }
+    }
+
     #[inline]
     async fn from_reader_async(mut reader: impl Send + std::marker::Unpin + tokio::io::AsyncRead) -> Result<T, Self::Error> {
         use tokio::io::AsyncReadExt;